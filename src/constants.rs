@@ -16,3 +16,10 @@ pub const BASEPOINT_ORDER: Scalar = Scalar([
     0xab5844f3, 0x2378c292, 0x8dc58f55, 0x216cc272, 0xaed63690, 0xc44edb49, 0x7cca23e9, 0xffffffff,
     0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0x3fffffff,
 ]);
+
+/// `FOUR_INVERSE` is the inverse of 4 modulo \ell, used to undo the factor of 4
+/// introduced by composing the 4-isogeny with its dual.
+pub const FOUR_INVERSE: Scalar = Scalar([
+    0xaad6113d, 0x48de30a4, 0xa37163d5, 0x085b309c, 0x6bb58da4, 0x7113b6d2, 0xdf3288fa, 0xffffffff,
+    0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0x0fffffff,
+]);
@@ -0,0 +1,352 @@
+//! COSE_Sign1 messages signed with Ed448 as specified in
+//! [RFC 9052](https://www.rfc-editor.org/rfc/rfc9052) and
+//! [RFC 9053](https://www.rfc-editor.org/rfc/rfc9053).
+//!
+//! Messages are created with a protected header of `{1: -8}`, the EdDSA algorithm,
+//! an empty unprotected header and an attached payload. The signature is a pure
+//! Ed448 signature with an empty context over the CBOR encoded `Sig_structure`
+//! `["Signature1", protected, external_aad, payload]`.
+use crate::sign::{Signature, SigningKey, VerifyingKey, SIGNATURE_LENGTH};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The COSE algorithm identifier of EdDSA
+pub const COSE_ALGORITHM_EDDSA: i64 = -8;
+
+/// The CBOR tag of COSE_Sign1 messages
+const COSE_SIGN1_TAG: u64 = 18;
+
+/// The header label of the algorithm
+const HEADER_ALG: u64 = 1;
+
+/// The header label of the critical headers
+const HEADER_CRIT: u64 = 2;
+
+/// The encoded protected header `{1: -8}`
+const PROTECTED_HEADER: [u8; 3] = [0xa1, 0x01, 0x27];
+
+/// How deeply nested the skipped header values may be
+const MAX_DEPTH: usize = 8;
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+
+/// The simple value `null`, which marks a detached payload
+const NULL: u8 = 0xf6;
+
+/// Write the head of a CBOR item with the shortest encoding of `value`
+fn write_head(out: &mut Vec<u8>, major: u8, value: u64) {
+    let major = major << 5;
+    match value {
+        0..=23 => out.push(major | value as u8),
+        24..=0xff => out.extend_from_slice(&[major | 24, value as u8]),
+        0x100..=0xffff => {
+            out.push(major | 25);
+            out.extend_from_slice(&(value as u16).to_be_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            out.push(major | 26);
+            out.extend_from_slice(&(value as u32).to_be_bytes());
+        }
+        _ => {
+            out.push(major | 27);
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+}
+
+fn write_bytes(out: &mut Vec<u8>, major: u8, bytes: &[u8]) {
+    write_head(out, major, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+/// Encode the `Sig_structure` that is signed for a COSE_Sign1 message
+fn sig_structure(protected: &[u8], external_aad: &[u8], payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(32 + protected.len() + external_aad.len() + payload.len());
+    write_head(&mut out, MAJOR_ARRAY, 4);
+    write_bytes(&mut out, MAJOR_TEXT, b"Signature1");
+    write_bytes(&mut out, MAJOR_BYTES, protected);
+    write_bytes(&mut out, MAJOR_BYTES, external_aad);
+    write_bytes(&mut out, MAJOR_BYTES, payload);
+    out
+}
+
+/// Reads definite length CBOR items from a buffer
+struct Decoder<'a>(&'a [u8]);
+
+impl<'a> Decoder<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.0.first().copied()
+    }
+
+    fn read_slice(&mut self, len: u64) -> Result<&'a [u8], &'static str> {
+        let len = usize::try_from(len).map_err(|_| "Unexpected end of input")?;
+        if self.0.len() < len {
+            return Err("Unexpected end of input");
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    /// Read the major type and argument of the next item
+    fn read_head(&mut self) -> Result<(u8, u64), &'static str> {
+        let initial = self.read_slice(1)?[0];
+        let major = initial >> 5;
+        let value = match initial & 0x1f {
+            info @ 0..=23 => u64::from(info),
+            24 => u64::from(self.read_slice(1)?[0]),
+            25 => self
+                .read_slice(2)?
+                .iter()
+                .fold(0, |acc, &b| (acc << 8) | u64::from(b)),
+            26 => self
+                .read_slice(4)?
+                .iter()
+                .fold(0, |acc, &b| (acc << 8) | u64::from(b)),
+            27 => self
+                .read_slice(8)?
+                .iter()
+                .fold(0, |acc, &b| (acc << 8) | u64::from(b)),
+            _ => return Err("Unsupported CBOR encoding"),
+        };
+        Ok((major, value))
+    }
+
+    fn read_expected(&mut self, expected_major: u8) -> Result<u64, &'static str> {
+        let (major, value) = self.read_head()?;
+        if major != expected_major {
+            return Err("Unexpected CBOR type");
+        }
+        Ok(value)
+    }
+
+    fn read_bytes(&mut self) -> Result<&'a [u8], &'static str> {
+        let len = self.read_expected(MAJOR_BYTES)?;
+        self.read_slice(len)
+    }
+
+    /// Skip over the next item, including everything nested in it
+    fn skip(&mut self, depth: usize) -> Result<(), &'static str> {
+        if depth > MAX_DEPTH {
+            return Err("CBOR nested too deeply");
+        }
+        let (major, value) = self.read_head()?;
+        match major {
+            MAJOR_BYTES | MAJOR_TEXT => {
+                self.read_slice(value)?;
+            }
+            MAJOR_ARRAY => {
+                for _ in 0..value {
+                    self.skip(depth + 1)?;
+                }
+            }
+            MAJOR_MAP => {
+                for _ in 0..value {
+                    self.skip(depth + 1)?;
+                    self.skip(depth + 1)?;
+                }
+            }
+            MAJOR_TAG => self.skip(depth + 1)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), &'static str> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err("Unexpected trailing data")
+        }
+    }
+}
+
+/// Check that a protected header selects EdDSA and has no critical headers
+fn check_protected_header(protected: &[u8]) -> Result<(), &'static str> {
+    let mut decoder = Decoder(protected);
+    let mut alg = None;
+    if !protected.is_empty() {
+        let entries = decoder.read_expected(MAJOR_MAP)?;
+        for _ in 0..entries {
+            match decoder.read_head()? {
+                (MAJOR_UNSIGNED, HEADER_ALG) => {
+                    if alg.is_some() {
+                        return Err("Duplicate alg");
+                    }
+                    alg = Some(decoder.read_head()?);
+                }
+                (MAJOR_UNSIGNED, HEADER_CRIT) => return Err("Unsupported critical header"),
+                (MAJOR_UNSIGNED | MAJOR_NEGATIVE, _) => decoder.skip(1)?,
+                (MAJOR_TEXT, len) => {
+                    decoder.read_slice(len)?;
+                    decoder.skip(1)?;
+                }
+                _ => return Err("Invalid header label"),
+            }
+        }
+    }
+    decoder.finish()?;
+
+    // -8 is encoded as the negative integer with argument 7
+    if alg != Some((MAJOR_NEGATIVE, (-1 - COSE_ALGORITHM_EDDSA) as u64)) {
+        return Err("Unsupported alg");
+    }
+    Ok(())
+}
+
+impl SigningKey {
+    /// Sign `payload` as a tagged COSE_Sign1 message using EdDSA.
+    ///
+    /// `external_aad` is authenticated but not included in the message, and is
+    /// usually empty.
+    pub fn sign_cose_sign1(&self, payload: &[u8], external_aad: &[u8]) -> Vec<u8> {
+        let signature = self.sign(&sig_structure(&PROTECTED_HEADER, external_aad, payload));
+
+        let mut out = Vec::with_capacity(16 + payload.len() + SIGNATURE_LENGTH);
+        write_head(&mut out, MAJOR_TAG, COSE_SIGN1_TAG);
+        write_head(&mut out, MAJOR_ARRAY, 4);
+        write_bytes(&mut out, MAJOR_BYTES, &PROTECTED_HEADER);
+        write_head(&mut out, MAJOR_MAP, 0);
+        write_bytes(&mut out, MAJOR_BYTES, payload);
+        write_bytes(&mut out, MAJOR_BYTES, &signature.to_bytes());
+        out
+    }
+}
+
+impl VerifyingKey {
+    /// Verify a COSE_Sign1 message, tagged or not, and return its payload.
+    ///
+    /// The protected header has to select EdDSA and must not list any critical
+    /// headers. Messages with a detached payload are not supported.
+    pub fn verify_cose_sign1(
+        &self,
+        message: &[u8],
+        external_aad: &[u8],
+    ) -> Result<Vec<u8>, &'static str> {
+        let mut decoder = Decoder(message);
+        if decoder.peek().map(|initial| initial >> 5) == Some(MAJOR_TAG)
+            && decoder.read_head()? != (MAJOR_TAG, COSE_SIGN1_TAG)
+        {
+            return Err("Not a COSE_Sign1 message");
+        }
+        if decoder.read_expected(MAJOR_ARRAY)? != 4 {
+            return Err("Not a COSE_Sign1 message");
+        }
+        let protected = decoder.read_bytes()?;
+        if decoder.peek().map(|initial| initial >> 5) != Some(MAJOR_MAP) {
+            return Err("Invalid unprotected header");
+        }
+        decoder.skip(0)?;
+        if decoder.peek() == Some(NULL) {
+            return Err("Detached payloads are not supported");
+        }
+        let payload = decoder.read_bytes()?;
+        let signature = <[u8; SIGNATURE_LENGTH]>::try_from(decoder.read_bytes()?)
+            .map_err(|_| "Invalid signature")?;
+        decoder.finish()?;
+
+        check_protected_header(protected)?;
+        self.verify(
+            &sig_structure(protected, external_aad, payload),
+            &Signature::from_bytes(&signature),
+        )
+        .map_err(|_| "Invalid signature")?;
+
+        Ok(payload.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    // The first key of RFC 8032 section 7.4
+    const SECRET_KEY: [u8; 57] = hex!("6c82a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3528c8a3fcc2f044e39a3fc5b94492f8f032e7549a20098f95b");
+
+    const PAYLOAD: &[u8] = b"This is the content.";
+    const MESSAGE: [u8; 144] = hex!("d28443a10127a054546869732069732074686520636f6e74656e742e5872988240a3a2f189bd486de14aa77f54686c576a09f2e7ed9bae910df9139c2ac3be7c27b7e10a20fa17c9d57d3510a2cf1f634bc0345ab9be00849842171d1e9e98b2674c0e38bfcf6c557a1692b01b71015a47ac9f7748840cad1da80cbb5b349309febb912672b377c8b2072af1598b3700");
+
+    #[test]
+    fn cose_sign1_known_answer() {
+        let signing_key = SigningKey::from_bytes(&SECRET_KEY);
+        let verifying_key = signing_key.verifying_key();
+        assert_eq!(signing_key.sign_cose_sign1(PAYLOAD, b""), MESSAGE);
+        assert_eq!(
+            verifying_key.verify_cose_sign1(&MESSAGE, b"").unwrap(),
+            PAYLOAD
+        );
+
+        // Untagged messages are accepted too
+        assert_eq!(
+            verifying_key.verify_cose_sign1(&MESSAGE[1..], b"").unwrap(),
+            PAYLOAD
+        );
+
+        // The external data is part of the signature
+        let message = signing_key.sign_cose_sign1(PAYLOAD, b"aad");
+        assert_eq!(message[message.len() - SIGNATURE_LENGTH..], hex!("03618f141c04bc0b9ffd01c4fe51efcba909542befe0a9a1e4c1809f5e0fd522edc88dc2ca59d038dd9477bc1ba6b5eea7264428d9c003e700f7a210d008fc3b37fce539f1d3a8383044f5e2cd3a7bfc5db06c54ec528feb392301c3b77d7a514cd34bad4888de6d1ee3cfa394a29daf0200"));
+        assert!(verifying_key.verify_cose_sign1(&message, b"aad").is_ok());
+        assert_eq!(
+            verifying_key.verify_cose_sign1(&message, b""),
+            Err("Invalid signature")
+        );
+    }
+
+    #[test]
+    fn cose_sign1_rejects_invalid_messages() {
+        let verifying_key = SigningKey::from_bytes(&SECRET_KEY).verifying_key();
+        let signature = &MESSAGE[MESSAGE.len() - SIGNATURE_LENGTH - 2..];
+
+        let with_headers = |protected: &[u8], unprotected: &[u8]| {
+            let mut message = vec![0xd2, 0x84];
+            write_bytes(&mut message, MAJOR_BYTES, protected);
+            message.extend_from_slice(unprotected);
+            write_bytes(&mut message, MAJOR_BYTES, PAYLOAD);
+            message.extend_from_slice(signature);
+            message
+        };
+        assert!(verifying_key
+            .verify_cose_sign1(&with_headers(&PROTECTED_HEADER, &[0xa0]), b"")
+            .is_ok());
+        // Unprotected headers such as a kid are not signed
+        assert!(verifying_key
+            .verify_cose_sign1(&with_headers(&PROTECTED_HEADER, &hex!("a104426b31")), b"")
+            .is_ok());
+
+        // ES256, EdDSA with a critical header, and no alg
+        assert_eq!(
+            verifying_key.verify_cose_sign1(&with_headers(&[0xa1, 0x01, 0x26], &[0xa0]), b""),
+            Err("Unsupported alg")
+        );
+        assert_eq!(
+            verifying_key.verify_cose_sign1(&with_headers(&hex!("a2012702810e"), &[0xa0]), b""),
+            Err("Unsupported critical header")
+        );
+        assert_eq!(
+            verifying_key.verify_cose_sign1(&with_headers(&[], &[0xa0]), b""),
+            Err("Unsupported alg")
+        );
+
+        // A detached payload, another tag and a truncated message
+        let mut detached = MESSAGE.to_vec();
+        detached.splice(7..28, [NULL]);
+        assert_eq!(
+            verifying_key.verify_cose_sign1(&detached, b""),
+            Err("Detached payloads are not supported")
+        );
+        let mut cose_sign = MESSAGE;
+        cose_sign[0] = 0xd8;
+        assert!(verifying_key.verify_cose_sign1(&cose_sign, b"").is_err());
+        assert!(verifying_key
+            .verify_cose_sign1(&MESSAGE[..MESSAGE.len() - 1], b"")
+            .is_err());
+    }
+}
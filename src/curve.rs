@@ -3,5 +3,7 @@ pub mod montgomery;
 pub(crate) mod scalar_mul;
 pub(crate) mod twedwards;
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use edwards::EdwardsBasepointTable;
 pub use edwards::{AffinePoint, CompressedEdwardsY, EdwardsPoint};
-pub use montgomery::{MontgomeryPoint, ProjectiveMontgomeryPoint};
+pub use montgomery::{x448, MontgomeryPoint, ProjectiveMontgomeryPoint, X448_BASEPOINT_U};
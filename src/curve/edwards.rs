@@ -11,5 +11,9 @@
 /// If this is a problem, one can use a different isogeny strategy (Decaf/Ristretto)
 pub(crate) mod affine;
 pub(crate) mod extended;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub(crate) mod table;
 pub use affine::AffinePoint;
 pub use extended::{CompressedEdwardsY, EdwardsPoint};
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use table::EdwardsBasepointTable;
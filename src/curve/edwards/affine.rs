@@ -95,6 +95,18 @@ impl AffinePoint {
         }
     }
 
+    /// Standard compression; store Y and sign of X
+    pub fn compress(&self) -> CompressedEdwardsY {
+        let mut compressed_bytes = [0u8; 57];
+
+        let sign = self.x.is_negative().unwrap_u8();
+
+        let y_bytes = self.y.to_bytes();
+        compressed_bytes[..y_bytes.len()].copy_from_slice(&y_bytes[..]);
+        *compressed_bytes.last_mut().unwrap() = sign << 7;
+        CompressedEdwardsY(compressed_bytes)
+    }
+
     /// Convert to edwards extended point
     pub fn to_edwards(&self) -> EdwardsPoint {
         EdwardsPoint {
@@ -3,10 +3,15 @@ use core::fmt::{Display, Formatter, LowerHex, Result as FmtResult, UpperHex};
 use core::iter::Sum;
 use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-use crate::constants::BASEPOINT_ORDER;
+use crate::constants::FOUR_INVERSE;
 use crate::curve::edwards::affine::AffinePoint;
 use crate::curve::montgomery::MontgomeryPoint; // XXX: need to fix this path
-use crate::curve::scalar_mul::variable_base;
+use crate::curve::scalar_mul::{
+    double_base::vartime_double_base_mul, fixed_base::TWISTED_BASEPOINT_TABLE,
+    recommended_wnaf_window, variable_base, vartime_variable_base,
+};
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::curve::scalar_mul::{straus, vartime_multiscalar_mul};
 use crate::curve::twedwards::extended::ExtendedPoint as TwistedExtendedPoint;
 use crate::field::{FieldElement, Scalar};
 use crate::*;
@@ -15,7 +20,7 @@ use elliptic_curve::{
         typenum::{U57, U84},
         GenericArray,
     },
-    group::{cofactor::CofactorGroup, prime::PrimeGroup, Curve, Group, GroupEncoding},
+    group::{cofactor::CofactorGroup, prime::PrimeGroup, Curve, Group, GroupEncoding, WnafGroup},
     hash2curve::{ExpandMsg, ExpandMsgXof, Expander, FromOkm},
     ops::{LinearCombination, MulByGenerator},
 };
@@ -225,6 +230,10 @@ impl CompressedEdwardsY {
     ///
     /// Returns `None` if the input is not the \\(y\\)-coordinate of a
     /// curve point.
+    ///
+    /// This accepts points with a torsion component and non-canonical encodings:
+    /// the \\(y\\)-coordinate is reduced modulo \\(p\\) and the low 7 bits
+    /// of the final byte are ignored.
     pub fn decompress_unchecked(&self) -> CtOption<EdwardsPoint> {
         // Safe to unwrap here as the underlying data structure is a slice
         let (sign, b) = self.0.split_last().unwrap();
@@ -255,11 +264,47 @@ impl CompressedEdwardsY {
     /// - if the input is not the \\(y\\)-coordinate of a curve point.
     /// - if the input point is not on the curve.
     /// - if the input point has nonzero torsion component.
+    ///
+    /// Like [`Self::decompress_unchecked`] this accepts non-canonical encodings.
     pub fn decompress(&self) -> CtOption<EdwardsPoint> {
         self.decompress_unchecked()
             .and_then(|pt| CtOption::new(pt, pt.is_on_curve() & pt.is_torsion_free()))
     }
 
+    /// Attempt to decompress to an `EdwardsPoint` as specified in RFC 8032 section 5.2.3.
+    ///
+    /// Returns `None`:
+    /// - if the input is not the \\(y\\)-coordinate of a curve point.
+    /// - if the input is not the canonical encoding of the point, see [`Self::is_canonical`].
+    ///
+    /// Points with a torsion component are accepted.
+    pub fn decompress_canonical(&self) -> CtOption<EdwardsPoint> {
+        self.decompress_unchecked()
+            .and_then(|pt| CtOption::new(pt, self.is_canonical()))
+    }
+
+    /// Returns true if these bytes are the canonical encoding of a point
+    /// as defined in RFC 8032 section 5.2.2.
+    ///
+    /// That is, the unused bits of the final byte are clear, the
+    /// \\(y\\)-coordinate is fully reduced, and the sign bit is not set
+    /// when \\(x = 0\\).
+    pub fn is_canonical(&self) -> Choice {
+        let (sign, b) = self.0.split_last().unwrap();
+
+        let mut y_bytes: [u8; 56] = [0; 56];
+        y_bytes.copy_from_slice(b);
+
+        let y = FieldElement::from_bytes(&y_bytes);
+        let y_is_reduced = y.to_bytes().ct_eq(&y_bytes);
+        let unused_bits_clear = (sign & 0x7f).ct_eq(&0);
+        // x = 0 exactly when y = ±1
+        let x_is_zero = y.square().ct_eq(&FieldElement::ONE);
+        let sign_bit = Choice::from(sign >> 7);
+
+        y_is_reduced & unused_bits_clear & !(x_is_zero & sign_bit)
+    }
+
     /// View this `CompressedEdwardsY` as an array of bytes.
     pub const fn as_bytes(&self) -> &PointBytes {
         &self.0
@@ -377,6 +422,12 @@ impl Group for EdwardsPoint {
     }
 }
 
+impl WnafGroup for EdwardsPoint {
+    fn recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
+        recommended_wnaf_window(num_scalars)
+    }
+}
+
 impl GroupEncoding for EdwardsPoint {
     type Repr = GenericArray<u8, U57>;
 
@@ -518,13 +569,41 @@ impl From<&EdwardsPoint> for AffinePoint {
     }
 }
 
-impl LinearCombination for EdwardsPoint {}
+impl LinearCombination for EdwardsPoint {
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    fn lincomb(x: &Self, k: &Scalar, y: &Self, l: &Scalar) -> Self {
+        Self::multiscalar_mul([k, l], [x, y])
+    }
+}
 
-impl MulByGenerator for EdwardsPoint {}
+impl MulByGenerator for EdwardsPoint {
+    fn mul_by_generator(scalar: &Scalar) -> Self {
+        // The twisted table gives [4 * s]B after the isogeny, so divide by 4 first
+        TWISTED_BASEPOINT_TABLE
+            .mul(&(scalar * FOUR_INVERSE))
+            .to_untwisted()
+    }
+}
 
 impl Curve for EdwardsPoint {
     type AffineRepr = AffinePoint;
 
+    /// Normalizes a batch of points with a single field inversion, using Montgomery's trick
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    fn batch_normalize(p: &[Self], q: &mut [AffinePoint]) {
+        assert_eq!(p.len(), q.len());
+
+        let mut z_inverses: Vec<FieldElement> = p.iter().map(|point| point.Z).collect();
+        FieldElement::batch_invert(&mut z_inverses);
+
+        for ((point, z_inverse), affine) in p.iter().zip(z_inverses).zip(q.iter_mut()) {
+            *affine = AffinePoint {
+                x: point.X * z_inverse,
+                y: point.Y * z_inverse,
+            };
+        }
+    }
+
     fn to_affine(&self) -> AffinePoint {
         self.to_affine()
     }
@@ -566,6 +645,96 @@ impl EdwardsPoint {
         partial_result.add(&self.scalar_mod_four(scalar))
     }
 
+    /// Compute `scalar * self` in variable time, with width-5 wNAF.
+    ///
+    /// This must only be used with public inputs.
+    pub fn mul_vartime(&self, scalar: &Scalar) -> Self {
+        // As in `scalar_mul`, (scalar mod 4) is split off to keep any torsion component
+        let mut scalar_div_four = *scalar;
+        scalar_div_four.div_by_four();
+
+        let partial_result =
+            vartime_variable_base(&self.to_twisted(), &scalar_div_four).to_untwisted();
+        partial_result.add(&self.scalar_mod_four(scalar))
+    }
+
+    /// Compute `a * A + b * B` in variable time, where `B` is the generator.
+    ///
+    /// This must only be used with public inputs, such as when verifying signatures.
+    pub fn vartime_double_scalar_mul_basepoint(a: &Scalar, A: &EdwardsPoint, b: &Scalar) -> Self {
+        // As in `scalar_mul`, split off (a mod 4) to keep any torsion component of A.
+        // B is torsion free, so b can be divided by 4 exactly
+        let mut a_div_four = *a;
+        a_div_four.div_by_four();
+
+        let partial_result =
+            vartime_double_base_mul(&a_div_four, &A.to_twisted(), &(b * FOUR_INVERSE))
+                .to_untwisted();
+        partial_result.add(&A.scalar_mod_four(a))
+    }
+
+    /// Compute `sum(scalars[i] * points[i])` in constant time.
+    ///
+    /// The two iterators are zipped, so any extra items in the longer one are ignored.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn multiscalar_mul<I, J>(scalars: I, points: J) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+    {
+        Self::twisted_multiscalar_mul(scalars, points, straus::multiscalar_mul)
+    }
+
+    /// Compute `sum(scalars[i] * points[i])` in variable time.
+    ///
+    /// This must only be used with public inputs. The two iterators are zipped, so
+    /// any extra items in the longer one are ignored.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn vartime_multiscalar_mul<I, J>(scalars: I, points: J) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+    {
+        Self::twisted_multiscalar_mul(scalars, points, vartime_multiscalar_mul)
+    }
+
+    /// Run a multiscalar multiplication on the twisted curve
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    fn twisted_multiscalar_mul<I, J>(
+        scalars: I,
+        points: J,
+        twisted_mul: fn(&[Scalar], &[TwistedExtendedPoint]) -> TwistedExtendedPoint,
+    ) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+    {
+        // As in `scalar_mul`, split off (s mod 4) to keep any torsion component of each point
+        let mut remainder = EdwardsPoint::IDENTITY;
+        let (scalars, points): (Vec<Scalar>, Vec<TwistedExtendedPoint>) = scalars
+            .into_iter()
+            .zip(points)
+            .map(|(scalar, point)| {
+                let (scalar, point) = (scalar.borrow(), point.borrow());
+                remainder = remainder.add(&point.scalar_mod_four(scalar));
+
+                let mut scalar_div_four = *scalar;
+                scalar_div_four.div_by_four();
+                (scalar_div_four, point.to_twisted())
+            })
+            .unzip();
+
+        twisted_mul(&scalars, &points)
+            .to_untwisted()
+            .add(&remainder)
+    }
+
     /// Returns (scalar mod 4) * P in constant time
     pub fn scalar_mod_four(&self, scalar: &Scalar) -> Self {
         // Compute compute (scalar mod 4)
@@ -594,55 +763,77 @@ impl EdwardsPoint {
     // Standard compression; store Y and sign of X
     // XXX: This needs more docs and is `compress` the conventional function name? I think to_bytes/encode is?
     pub fn compress(&self) -> CompressedEdwardsY {
-        let affine = self.to_affine();
-
-        let affine_x = affine.x;
-        let affine_y = affine.y;
-
-        let mut compressed_bytes = [0u8; 57];
-
-        let sign = affine_x.is_negative().unwrap_u8();
+        self.to_affine().compress()
+    }
 
-        let y_bytes = affine_y.to_bytes();
-        compressed_bytes[..y_bytes.len()].copy_from_slice(&y_bytes[..]);
-        *compressed_bytes.last_mut().unwrap() = sign << 7;
-        CompressedEdwardsY(compressed_bytes)
+    /// Compress a batch of points, with a single field inversion for all of them
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn compress_batch(points: &[EdwardsPoint]) -> Vec<CompressedEdwardsY> {
+        let mut affine = vec![AffinePoint::IDENTITY; points.len()];
+        Self::batch_normalize(points, &mut affine);
+        affine.iter().map(AffinePoint::compress).collect()
     }
 
-    //https://iacr.org/archive/asiacrypt2008/53500329/53500329.pdf (3.1)
-    // These formulas are unified, so for now we can use it for doubling. Will refactor later for speed
+    /// Add two points.
+    ///
+    /// This is `add-2008-hwcd` from <https://iacr.org/archive/asiacrypt2008/53500329/53500329.pdf> (3.1),
+    /// with a = 1. The formula is unified, so it is also correct when both points are the same.
     pub fn add(&self, other: &EdwardsPoint) -> Self {
-        let aXX = self.X * other.X; // aX1X2
-        let dTT = FieldElement::EDWARDS_D * self.T * other.T; // dT1T2
-        let ZZ = self.Z * other.Z; // Z1Z2
-        let YY = self.Y * other.Y;
-
-        let X = {
-            let x_1 = (self.X * other.Y) + (self.Y * other.X);
-            let x_2 = ZZ - dTT;
-            x_1 * x_2
-        };
-        let Y = {
-            let y_1 = YY - aXX;
-            let y_2 = ZZ + dTT;
-            y_1 * y_2
-        };
+        let A = self.X * other.X;
+        let B = self.Y * other.Y;
+        let C = self.T * other.T * FieldElement::EDWARDS_D;
+        let D = self.Z * other.Z;
+        let E = (self.X + self.Y) * (other.X + other.Y) - A - B;
+        let F = D - C;
+        let G = D + C;
+        let H = B - A;
 
-        let T = {
-            let t_1 = YY - aXX;
-            let t_2 = (self.X * other.Y) + (self.Y * other.X);
-            t_1 * t_2
-        };
+        EdwardsPoint {
+            X: E * F,
+            Y: G * H,
+            Z: F * G,
+            T: E * H,
+        }
+    }
 
-        let Z = { (ZZ - dTT) * (ZZ + dTT) };
+    /// Add an affine point, which saves the multiplication by its Z coordinate.
+    ///
+    /// This is `madd-2008-hwcd` from the same paper, with a = 1.
+    pub(crate) fn add_affine(&self, other: &AffinePoint) -> Self {
+        let A = self.X * other.x;
+        let B = self.Y * other.y;
+        let C = self.T * other.x * other.y * FieldElement::EDWARDS_D;
+        let E = (self.X + self.Y) * (other.x + other.y) - A - B;
+        let F = self.Z - C;
+        let G = self.Z + C;
+        let H = B - A;
 
-        EdwardsPoint { X, Y, Z, T }
+        EdwardsPoint {
+            X: E * F,
+            Y: G * H,
+            Z: F * G,
+            T: E * H,
+        }
     }
 
-    // XXX: See comment on addition, the formula is unified, so this will do for now
-    //https://iacr.org/archive/asiacrypt2008/53500329/53500329.pdf (3.1)
+    /// Double this point.
+    ///
+    /// This is `dbl-2008-hwcd` from the same paper, with a = 1, and does not need `T`.
     pub fn double(&self) -> Self {
-        self.add(self)
+        let A = self.X.square();
+        let B = self.Y.square();
+        let C = self.Z.square().double();
+        let E = (self.X + self.Y).square() - A - B;
+        let G = A + B;
+        let F = G - C;
+        let H = A - B;
+
+        EdwardsPoint {
+            X: E * F,
+            Y: G * H,
+            Z: F * G,
+            T: E * H,
+        }
     }
 
     pub(crate) fn is_on_curve(&self) -> Choice {
@@ -670,38 +861,26 @@ impl EdwardsPoint {
         AffinePoint { x, y }
     }
 
-    /// Edwards_Isogeny is derived from the doubling formula
-    /// XXX: There is a duplicate method in the twisted edwards module to compute the dual isogeny
-    /// XXX: Not much point trying to make it generic I think. So what we can do is optimise each respective isogeny method for a=1 or a = -1 (currently, I just made it really slow and simple)
-    fn edwards_isogeny(&self, a: FieldElement) -> TwistedExtendedPoint {
-        // Convert to affine now, then derive extended version later
-        let affine = self.to_affine();
-        let x = affine.x;
-        let y = affine.y;
-
-        // Compute x
-        let xy = x * y;
-        let x_numerator = xy + xy;
-        let x_denom = y.square() - (a * x.square());
-        let new_x = x_numerator * x_denom.invert();
-
-        // Compute y
-        let y_numerator = y.square() + (a * x.square());
-        let y_denom = (FieldElement::ONE + FieldElement::ONE) - y.square() - (a * x.square());
-        let new_y = y_numerator * y_denom.invert();
+    /// Uses a 2-isogeny to map the point to the twisted curve.
+    ///
+    /// This is the affine map `(x, y) -> (2xy / (y^2 - x^2), (y^2 + x^2) / (2 - y^2 - x^2))`,
+    /// in projective coordinates so that it needs no inversion.
+    pub(crate) fn to_twisted(self) -> TwistedExtendedPoint {
+        let XX = self.X.square();
+        let YY = self.Y.square();
+        let XY = (self.X + self.Y).square() - XX - YY;
+        let YY_plus_XX = YY + XX;
+        let YY_minus_XX = YY - XX;
+        let denominator = self.Z.square().double() - YY_plus_XX;
 
         TwistedExtendedPoint {
-            X: new_x,
-            Y: new_y,
-            Z: FieldElement::ONE,
-            T: new_x * new_y,
+            X: XY * denominator,
+            Y: YY_plus_XX * YY_minus_XX,
+            Z: YY_minus_XX * denominator,
+            T: XY * YY_plus_XX,
         }
     }
 
-    pub(crate) fn to_twisted(self) -> TwistedExtendedPoint {
-        self.edwards_isogeny(FieldElement::ONE)
-    }
-
     pub fn negate(&self) -> Self {
         EdwardsPoint {
             X: -self.X,
@@ -729,8 +908,33 @@ impl EdwardsPoint {
     ///    prime-order subgroup;
     /// * `false` if `self` has a nonzero torsion component and is not
     ///    in the prime-order subgroup.
+    ///
+    /// This runs in constant time, and costs two exponentiations rather than a
+    /// scalar multiplication.
     pub fn is_torsion_free(&self) -> Choice {
-        (self * BASEPOINT_ORDER).ct_eq(&Self::IDENTITY)
+        // The torsion subgroup is cyclic of order 4, so the prime-order subgroup is 4E.
+        //
+        // A point is in 2E exactly when D = (1 - d)(1 - dy^2) is a square. Its halves then
+        // have y^2 among the roots of d(1 + y)s^2 - 2(dy + 1)s + (1 + y), which has
+        // discriminant 4D, and both halves are in 2E exactly when ((1 - d) + sqrt(D))(1 + y)
+        // is not a square, whichever root is taken. The identity is the exception, since
+        // one of its roots gives zero.
+        let one_minus_d = FieldElement::ONE - FieldElement::EDWARDS_D;
+        let discriminant =
+            one_minus_d * (self.Z.square() - FieldElement::EDWARDS_D * self.Y.square());
+        let (isr, is_double) = discriminant.inverse_square_root();
+
+        let root = discriminant * isr;
+        let halving = (one_minus_d * self.Z + root) * (self.Z + self.Y);
+        let halves_are_doubles = !halving.is_square() & !halving.ct_eq(&FieldElement::ZERO);
+
+        (is_double & halves_are_doubles) | self.ct_eq(&Self::IDENTITY)
+    }
+
+    /// Determine if this point is of small order, i.e., is in the
+    /// torsion subgroup of order 4.
+    pub fn is_small_order(&self) -> Choice {
+        self.double().double().ct_eq(&Self::IDENTITY)
     }
 
     /// Hash a message to a point on the curve
@@ -816,7 +1020,7 @@ impl Add<&AffinePoint> for &EdwardsPoint {
     type Output = EdwardsPoint;
 
     fn add(self, other: &AffinePoint) -> EdwardsPoint {
-        *self + *other
+        self.add_affine(other)
     }
 }
 
@@ -824,7 +1028,7 @@ impl Add<&EdwardsPoint> for &AffinePoint {
     type Output = EdwardsPoint;
 
     fn add(self, other: &EdwardsPoint) -> EdwardsPoint {
-        *other + *self
+        other.add_affine(self)
     }
 }
 
@@ -838,7 +1042,7 @@ define_add_assign_variants!(LHS = EdwardsPoint, RHS = EdwardsPoint);
 
 impl AddAssign<&AffinePoint> for EdwardsPoint {
     fn add_assign(&mut self, rhs: &AffinePoint) {
-        *self += rhs.to_edwards();
+        *self = self.add_affine(rhs);
     }
 }
 
@@ -1019,6 +1223,90 @@ mod tests {
         assert!(twist_a == a.double().double())
     }
 
+    /// The unified addition that `add` and `double` used before
+    fn add_by_unified_formula(a: &EdwardsPoint, b: &EdwardsPoint) -> EdwardsPoint {
+        let aXX = a.X * b.X;
+        let dTT = FieldElement::EDWARDS_D * a.T * b.T;
+        let ZZ = a.Z * b.Z;
+        let YY = a.Y * b.Y;
+        let XY_YX = (a.X * b.Y) + (a.Y * b.X);
+
+        EdwardsPoint {
+            X: XY_YX * (ZZ - dTT),
+            Y: (YY - aXX) * (ZZ + dTT),
+            Z: (ZZ - dTT) * (ZZ + dTT),
+            T: (YY - aXX) * XY_YX,
+        }
+    }
+
+    /// The affine isogeny that `to_twisted` used before
+    fn to_twisted_by_affine_isogeny(point: &EdwardsPoint) -> TwistedExtendedPoint {
+        let AffinePoint { x, y } = point.to_affine();
+        let new_x = (x * y).double() * (y.square() - x.square()).invert();
+        let new_y = (y.square() + x.square())
+            * (FieldElement::ONE.double() - y.square() - x.square()).invert();
+
+        TwistedExtendedPoint {
+            X: new_x,
+            Y: new_y,
+            Z: FieldElement::ONE,
+            T: new_x * new_y,
+        }
+    }
+
+    fn test_points() -> Vec<EdwardsPoint> {
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([13u8; 32]);
+        let mut points: Vec<EdwardsPoint> = (0..8)
+            .map(|_| EdwardsPoint::GENERATOR * Scalar::random(&mut rng))
+            .collect();
+        let two_torsion = EdwardsPoint {
+            X: FieldElement::ZERO,
+            Y: FieldElement::MINUS_ONE,
+            Z: FieldElement::ONE,
+            T: FieldElement::ZERO,
+        };
+        let four_torsion = EdwardsPoint {
+            X: FieldElement::ONE,
+            Y: FieldElement::ZERO,
+            Z: FieldElement::ONE,
+            T: FieldElement::ZERO,
+        };
+        points.push(points[0] + two_torsion);
+        points.push(points[1] + four_torsion);
+        points.extend([EdwardsPoint::IDENTITY, two_torsion, four_torsion]);
+        points
+    }
+
+    #[test]
+    fn test_dedicated_formulas_match_unified_addition() {
+        let points = test_points();
+        for a in points.iter() {
+            let double = a.double();
+            assert_eq!(double, add_by_unified_formula(a, a));
+            assert_eq!(double.is_on_curve().unwrap_u8(), 1u8);
+
+            for b in points.iter() {
+                let expected = add_by_unified_formula(a, b);
+                let sum = a.add(b);
+                assert_eq!(sum, expected);
+                assert_eq!(sum.is_on_curve().unwrap_u8(), 1u8);
+                assert_eq!(a + b.to_affine(), expected);
+                assert_eq!(a.to_affine() + b, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_projective_isogeny_matches_affine_isogeny() {
+        for point in test_points() {
+            let twisted = point.to_twisted();
+            assert_eq!(twisted, to_twisted_by_affine_isogeny(&point));
+            assert_eq!(twisted.is_on_curve().unwrap_u8(), 1u8);
+        }
+    }
+
     // XXX: Move this to constants folder to test all global constants
     #[test]
     fn derive_base_points() {
@@ -1101,6 +1389,39 @@ mod tests {
         assert_eq!(decompressed.is_none().unwrap_u8(), 1u8);
     }
 
+    #[test]
+    fn test_is_torsion_free_matches_multiplication_by_order() {
+        use crate::constants::BASEPOINT_ORDER;
+        use rand_core::SeedableRng;
+
+        let four_torsion = EdwardsPoint {
+            X: FieldElement::ONE,
+            Y: FieldElement::ZERO,
+            Z: FieldElement::ONE,
+            T: FieldElement::ZERO,
+        };
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([14u8; 32]);
+        let mut points: Vec<EdwardsPoint> = (0..8)
+            .map(|_| EdwardsPoint::GENERATOR * Scalar::random(&mut rng))
+            .collect();
+        points.extend([EdwardsPoint::IDENTITY, EdwardsPoint::GENERATOR]);
+
+        for point in points {
+            // Projective coordinates other than Z = 1
+            let point = point.double() - point;
+
+            // Walk through all four cosets of the prime-order subgroup
+            let mut shifted = point;
+            for coset in 0..4 {
+                let expected = (shifted * BASEPOINT_ORDER).ct_eq(&EdwardsPoint::IDENTITY);
+                assert_eq!(expected.unwrap_u8(), (coset == 0) as u8);
+                assert_eq!(shifted.is_torsion_free().unwrap_u8(), expected.unwrap_u8());
+                shifted += four_torsion;
+            }
+        }
+    }
+
     #[test]
     fn hash_with_test_vectors() {
         const DST: &[u8] = b"QUUX-V01-CS02-with-edwards448_XOF:SHAKE256_ELL2_RO_";
@@ -1212,4 +1533,139 @@ mod tests {
             assert_eq!(rhs, expected);
         }
     }
+
+    #[test]
+    fn test_mul_by_generator() {
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([5u8; 32]);
+        for scalar in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::random(&mut rng),
+        ] {
+            assert_eq!(
+                EdwardsPoint::mul_by_generator(&scalar),
+                EdwardsPoint::GENERATOR * scalar
+            );
+        }
+    }
+
+    #[test]
+    fn test_vartime_double_scalar_mul_basepoint() {
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([7u8; 32]);
+        let A = EdwardsPoint::GENERATOR * Scalar::random(&mut rng);
+        let scalars = [
+            (Scalar::ZERO, Scalar::ZERO),
+            (Scalar::from(3u8), -Scalar::ONE),
+            (Scalar::random(&mut rng), Scalar::random(&mut rng)),
+            (Scalar::random(&mut rng), Scalar::random(&mut rng)),
+        ];
+
+        for (a, b) in scalars.iter() {
+            assert_eq!(
+                EdwardsPoint::vartime_double_scalar_mul_basepoint(a, &A, b),
+                A * a + EdwardsPoint::GENERATOR * b
+            );
+        }
+
+        // A point with a torsion component keeps it
+        let A = A + EdwardsPoint {
+            X: FieldElement::ZERO,
+            Y: FieldElement::MINUS_ONE,
+            Z: FieldElement::ONE,
+            T: FieldElement::ZERO,
+        };
+        for (a, b) in scalars.iter() {
+            assert_eq!(
+                EdwardsPoint::vartime_double_scalar_mul_basepoint(a, &A, b),
+                A * a + EdwardsPoint::GENERATOR * b
+            );
+        }
+    }
+
+    #[test]
+    fn test_multiscalar_mul() {
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([11u8; 32]);
+        let scalars: Vec<Scalar> = (0..5).map(|_| Scalar::random(&mut rng)).collect();
+        let mut points: Vec<EdwardsPoint> = (0..5)
+            .map(|_| EdwardsPoint::GENERATOR * Scalar::random(&mut rng))
+            .collect();
+        // Torsion components are kept
+        points[0] += EdwardsPoint {
+            X: FieldElement::ZERO,
+            Y: FieldElement::MINUS_ONE,
+            Z: FieldElement::ONE,
+            T: FieldElement::ZERO,
+        };
+
+        let expected = scalars
+            .iter()
+            .zip(points.iter())
+            .fold(EdwardsPoint::IDENTITY, |acc, (s, p)| acc + p * s);
+        assert_eq!(EdwardsPoint::multiscalar_mul(&scalars, &points), expected);
+        assert_eq!(
+            EdwardsPoint::vartime_multiscalar_mul(&scalars, &points),
+            expected
+        );
+        assert_eq!(
+            EdwardsPoint::lincomb(&points[0], &scalars[0], &points[1], &scalars[1]),
+            points[0] * scalars[0] + points[1] * scalars[1]
+        );
+    }
+
+    #[test]
+    fn test_batch_normalize_and_compress() {
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([12u8; 32]);
+        let mut points: Vec<EdwardsPoint> = (0..5)
+            .map(|_| EdwardsPoint::GENERATOR * Scalar::random(&mut rng))
+            .collect();
+        points.push(EdwardsPoint::IDENTITY);
+        points.push(points[0].double());
+
+        let mut affine = vec![AffinePoint::IDENTITY; points.len()];
+        EdwardsPoint::batch_normalize(&points, &mut affine);
+        let compressed = EdwardsPoint::compress_batch(&points);
+        for ((point, affine), compressed) in points.iter().zip(affine).zip(compressed) {
+            assert_eq!(affine, point.to_affine());
+            assert_eq!(compressed, point.compress());
+        }
+    }
+
+    #[test]
+    fn test_mul_vartime_and_wnaf() {
+        use elliptic_curve::group::Wnaf;
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([16u8; 32]);
+        let mut points: Vec<EdwardsPoint> = (0..3)
+            .map(|_| EdwardsPoint::GENERATOR * Scalar::random(&mut rng))
+            .collect();
+        // Torsion components are kept
+        points[0] += EdwardsPoint {
+            X: FieldElement::ONE,
+            Y: FieldElement::ZERO,
+            Z: FieldElement::ONE,
+            T: FieldElement::ZERO,
+        };
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, Scalar::from(3u8), -Scalar::ONE];
+        scalars.extend((0..4).map(|_| Scalar::random(&mut rng)));
+
+        for point in points.iter() {
+            let mut wnaf = Wnaf::new();
+            let mut wnaf_base = wnaf.base(*point, scalars.len());
+            for scalar in scalars.iter() {
+                let expected = point * scalar;
+                assert_eq!(point.mul_vartime(scalar), expected);
+                assert_eq!(wnaf_base.scalar(scalar), expected);
+            }
+        }
+    }
 }
@@ -0,0 +1,160 @@
+use core::ops::Mul;
+
+use crate::curve::edwards::extended::EdwardsPoint;
+use crate::curve::scalar_mul::fixed_base::WindowTable;
+use crate::field::Scalar;
+
+/// A precomputed table of multiples of a fixed point, for repeated multiplication
+/// by secret scalars in constant time.
+///
+/// Each row holds `N` multiples for a window of `log2(N) + 1` bits, where `N` is one of
+/// 8, 16, 32, 64 or 128. Larger windows make the table bigger and multiplication faster.
+#[derive(Clone)]
+pub struct EdwardsBasepointTable<const N: usize = 16> {
+    point: EdwardsPoint,
+    table: WindowTable<N>,
+}
+
+impl<const N: usize> EdwardsBasepointTable<N> {
+    /// Create a table of multiples of `point`
+    pub fn new(point: &EdwardsPoint) -> Self {
+        Self {
+            point: *point,
+            table: WindowTable::create(&point.to_twisted()),
+        }
+    }
+
+    /// The point this table was created for
+    pub fn basepoint(&self) -> EdwardsPoint {
+        self.point
+    }
+
+    /// Compute `scalar * P` in constant time
+    pub fn mul_base(&self, scalar: &Scalar) -> EdwardsPoint {
+        // As in `EdwardsPoint::scalar_mul`, the table is on the twisted curve and
+        // (scalar mod 4) is split off to keep any torsion component
+        let mut scalar_div_four = *scalar;
+        scalar_div_four.div_by_four();
+
+        let partial_result = self.table.mul(&scalar_div_four).to_untwisted();
+        partial_result.add(&self.point.scalar_mod_four(scalar))
+    }
+}
+
+impl<const N: usize> From<&EdwardsPoint> for EdwardsBasepointTable<N> {
+    fn from(point: &EdwardsPoint) -> Self {
+        Self::new(point)
+    }
+}
+
+impl<const N: usize> Mul<&Scalar> for &EdwardsBasepointTable<N> {
+    type Output = EdwardsPoint;
+
+    fn mul(self, scalar: &Scalar) -> EdwardsPoint {
+        self.mul_base(scalar)
+    }
+}
+
+impl<const N: usize> Mul<&EdwardsBasepointTable<N>> for &Scalar {
+    type Output = EdwardsPoint;
+
+    fn mul(self, table: &EdwardsBasepointTable<N>) -> EdwardsPoint {
+        table.mul_base(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serdect::serde::Serialize for EdwardsBasepointTable<N> {
+    fn serialize<S: serdect::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = self.point.compress().0.to_vec();
+        bytes.extend_from_slice(&self.table.to_bytes());
+        serdect::slice::serialize_hex_lower_or_bin(&bytes, s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serdect::serde::Deserialize<'de> for EdwardsBasepointTable<N> {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        use crate::curve::edwards::extended::CompressedEdwardsY;
+        use serdect::serde::de::Error;
+
+        let bytes = serdect::slice::deserialize_hex_or_bin_vec(d)?;
+        if bytes.len() < 57 {
+            return Err(Error::custom("invalid table"));
+        }
+        let (point, table) = bytes.split_at(57);
+
+        let mut compressed = CompressedEdwardsY([0u8; 57]);
+        compressed.0.copy_from_slice(point);
+        let point = Option::<EdwardsPoint>::from(compressed.decompress_unchecked())
+            .ok_or_else(|| Error::custom("invalid point"))?;
+        let table = WindowTable::from_bytes(table).ok_or_else(|| Error::custom("invalid table"))?;
+
+        Ok(Self { point, table })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::FieldElement;
+    use rand_core::SeedableRng;
+
+    fn check_table<const N: usize>(point: &EdwardsPoint, scalars: &[Scalar]) {
+        let table = EdwardsBasepointTable::<N>::new(point);
+        assert_eq!(table.basepoint(), *point);
+        for scalar in scalars.iter() {
+            let expected = point * scalar;
+            assert_eq!(&table * scalar, expected);
+            assert_eq!(scalar * &table, expected);
+        }
+    }
+
+    #[test]
+    fn test_table_mul() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([17u8; 32]);
+        let mut point = EdwardsPoint::GENERATOR * Scalar::random(&mut rng);
+        // Torsion components are kept
+        point += EdwardsPoint {
+            X: FieldElement::ONE,
+            Y: FieldElement::ZERO,
+            Z: FieldElement::ONE,
+            T: FieldElement::ZERO,
+        };
+
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, Scalar::from(7u8), -Scalar::ONE];
+        scalars.extend((0..4).map(|_| Scalar::random(&mut rng)));
+
+        check_table::<8>(&point, &scalars);
+        check_table::<16>(&point, &scalars);
+        check_table::<32>(&point, &scalars);
+        check_table::<64>(&point, &scalars);
+        check_table::<128>(&point, &scalars);
+        check_table::<16>(&EdwardsPoint::IDENTITY, &scalars);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_table_serialization() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([18u8; 32]);
+        let point = EdwardsPoint::GENERATOR * Scalar::random(&mut rng);
+        let scalar = Scalar::random(&mut rng);
+        let table = EdwardsBasepointTable::<8>::new(&point);
+
+        let bytes = serde_bare::to_vec(&table).unwrap();
+        let decoded = serde_bare::from_slice::<EdwardsBasepointTable<8>>(&bytes).unwrap();
+        assert_eq!(&decoded * &scalar, point * scalar);
+
+        // A table for a different window has a different length
+        assert!(serde_bare::from_slice::<EdwardsBasepointTable<16>>(&bytes).is_err());
+
+        // Every entry has to be on the curve
+        let mut corrupted = bytes.clone();
+        let last = corrupted.len() - 1;
+        corrupted[last - 60] ^= 1;
+        assert!(serde_bare::from_slice::<EdwardsBasepointTable<8>>(&corrupted).is_err());
+    }
+}
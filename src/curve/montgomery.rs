@@ -11,31 +11,30 @@
 #![allow(non_snake_case)]
 
 // use crate::constants::A_PLUS_TWO_OVER_FOUR;
-use crate::curve::edwards::extended::EdwardsPoint;
+use crate::constants::FOUR_INVERSE;
+use crate::curve::edwards::{extended::EdwardsPoint, AffinePoint};
 use crate::field::{FieldElement, Scalar};
 use core::fmt;
 use core::ops::Mul;
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
-
-// Low order points on Curve448 and it's twist
-const LOW_A: MontgomeryPoint = MontgomeryPoint([
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-]);
-const LOW_B: MontgomeryPoint = MontgomeryPoint([
-    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-]);
-const LOW_C: MontgomeryPoint = MontgomeryPoint([
-    0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-]);
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// The u-coordinate of the Curve448 base point, as specified in RFC 7748
+pub const X448_BASEPOINT_U: [u8; 56] = MontgomeryPoint::GENERATOR.0;
+
+/// The X448 function specified in RFC 7748 section 5.
+///
+/// The scalar `k` is clamped and used as a full 448-bit integer, not reduced
+/// modulo the group order. Values of `u` that are not reduced modulo \\(p\\)
+/// are accepted and processed as if they had been reduced.
+pub fn x448(k: [u8; 56], u: [u8; 56]) -> [u8; 56] {
+    let mut k = k;
+    k[0] &= 252;
+    k[55] |= 128;
+
+    let bits: [bool; 448] = core::array::from_fn(|i| (k[i / 8] >> (i % 8)) & 1 == 1);
+    let affine_u = FieldElement::from_bytes(&u);
+    montgomery_ladder(&affine_u, &bits).to_affine().0
+}
 
 #[derive(Copy, Clone)]
 pub struct MontgomeryPoint(pub [u8; 56]);
@@ -77,29 +76,9 @@ pub struct ProjectiveMontgomeryPoint {
 impl Mul<&Scalar> for &MontgomeryPoint {
     type Output = MontgomeryPoint;
 
-    #[allow(clippy::suspicious_arithmetic_impl)]
     fn mul(self, scalar: &Scalar) -> MontgomeryPoint {
-        // Algorithm 8 of Costello-Smith 2017
         let affine_u = FieldElement::from_bytes(&self.0);
-        let mut x0 = ProjectiveMontgomeryPoint::identity();
-        let mut x1 = ProjectiveMontgomeryPoint {
-            U: affine_u,
-            W: FieldElement::ONE,
-        };
-
-        let bits = scalar.bits();
-        let mut swap = 0;
-        for s in (0..448).rev() {
-            let bit = bits[s] as u8;
-            let choice: u8 = swap ^ bit;
-
-            ProjectiveMontgomeryPoint::conditional_swap(&mut x0, &mut x1, Choice::from(choice));
-            differential_add_and_double(&mut x0, &mut x1, &affine_u);
-
-            swap = bit;
-        }
-
-        x0.to_affine()
+        montgomery_ladder(&affine_u, &scalar.bits()).to_affine()
     }
 }
 
@@ -120,15 +99,44 @@ impl MontgomeryPoint {
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
     ]);
 
-    pub fn to_edwards(&self, _sign: u8) -> Option<EdwardsPoint> {
+    /// Convert this point to the `EdwardsPoint` in the prime-order subgroup that
+    /// [`EdwardsPoint::to_montgomery`] maps to it, choosing the one whose
+    /// \\(x\\)-coordinate is negative if `sign` is 1 and nonnegative if `sign` is 0.
+    ///
+    /// Only points in the prime-order subgroup are in the image of the isogeny, so this
+    /// returns `None` if the point is on the twist or has a nonzero torsion component.
+    /// The exception is \\(u = 0\\), the image of the low order Edwards points,
+    /// which is mapped to the identity.
+    pub fn to_edwards(&self, sign: u8) -> Option<EdwardsPoint> {
         // We use the 4-isogeny to map to the Ed448.
         // This is different to Curve25519, where we use a birational map.
-        todo!()
+        // Mapping (u, v) through the dual isogeny gives [±4]P, which is then divided by 4.
+        let u = FieldElement::from_bytes(&self.0);
+        let uu = u.square();
+        let vv = (uu + FieldElement::J * u + FieldElement::ONE) * u;
+        let (v, is_on_curve) = FieldElement::sqrt_ratio(&vv, &FieldElement::ONE);
+
+        let four_p = AffinePoint { x: u, y: v }.isogeny().to_edwards();
+        let mut point = four_p * FOUR_INVERSE;
+        // The isogeny is undefined at (0, 0), the image of the torsion points
+        point.conditional_assign(&EdwardsPoint::IDENTITY, u.ct_eq(&FieldElement::ZERO));
+
+        let is_negative = point.to_affine().x.is_negative();
+        point.conditional_negate(is_negative ^ Choice::from(sign & 1));
+
+        // Low order points other than u = 0 are not in the image of the isogeny
+        let round_trips = FieldElement::from_bytes(&point.to_montgomery().0).ct_eq(&u);
+        CtOption::new(point, is_on_curve & round_trips).into()
     }
 
-    /// Returns true if the point is one of the low order points
+    /// Returns true if the point is of low order on Curve448 or its twist,
+    /// including non-canonical encodings of such points.
+    ///
+    /// The cofactor of both curves is 4, so these are exactly the points
+    /// that are mapped to the identity by multiplying by 4.
     pub fn is_low_order(&self) -> bool {
-        (*self == LOW_A) || (*self == LOW_B) || (*self == LOW_C)
+        let point = self.to_projective().double().double();
+        point.W.ct_eq(&FieldElement::ZERO).into()
     }
 
     /// View the point as a byte slice
@@ -157,6 +165,31 @@ impl ConditionallySelectable for ProjectiveMontgomeryPoint {
     }
 }
 
+/// Compute the product of the point with u-coordinate `affine_u` and the
+/// little-endian integer given by `bits`
+fn montgomery_ladder(affine_u: &FieldElement, bits: &[bool; 448]) -> ProjectiveMontgomeryPoint {
+    // Algorithm 8 of Costello-Smith 2017
+    let mut x0 = ProjectiveMontgomeryPoint::identity();
+    let mut x1 = ProjectiveMontgomeryPoint {
+        U: *affine_u,
+        W: FieldElement::ONE,
+    };
+
+    let mut swap = 0;
+    for s in (0..448).rev() {
+        let bit = bits[s] as u8;
+        let choice: u8 = swap ^ bit;
+
+        ProjectiveMontgomeryPoint::conditional_swap(&mut x0, &mut x1, Choice::from(choice));
+        differential_add_and_double(&mut x0, &mut x1, affine_u);
+
+        swap = bit;
+    }
+    ProjectiveMontgomeryPoint::conditional_swap(&mut x0, &mut x1, Choice::from(swap));
+
+    x0
+}
+
 fn differential_add_and_double(
     P: &mut ProjectiveMontgomeryPoint,
     Q: &mut ProjectiveMontgomeryPoint,
@@ -203,6 +236,18 @@ impl ProjectiveMontgomeryPoint {
         }
     }
 
+    /// Double the point using only the u-coordinate
+    pub fn double(&self) -> ProjectiveMontgomeryPoint {
+        let t0 = (self.U + self.W).square();
+        let t1 = (self.U - self.W).square();
+        let t2 = t0 - t1; // 4 U W
+
+        ProjectiveMontgomeryPoint {
+            U: t0 * t1,
+            W: t2 * (t1 + FieldElement::A_PLUS_TWO_OVER_FOUR * t2),
+        }
+    }
+
     pub fn to_affine(&self) -> MontgomeryPoint {
         let x = self.U * self.W.invert();
         MontgomeryPoint(x.to_bytes())
@@ -213,6 +258,7 @@ impl ProjectiveMontgomeryPoint {
 mod tests {
 
     use super::*;
+    use hex_literal::hex;
 
     #[test]
     fn test_montgomery_edwards() {
@@ -226,5 +272,151 @@ mod tests {
         // Goldilocks scalar mul
         let goldilocks_point = bp.scalar_mul(&scalar);
         assert_eq!(goldilocks_point.to_montgomery(), montgomery_res);
+
+        // Odd scalars exercise the final swap of the ladder
+        let scalar = Scalar::from(201u32);
+        let montgomery_res = &montgomery_bp * &scalar;
+        assert_eq!(bp.scalar_mul(&scalar).to_montgomery(), montgomery_res);
+    }
+
+    #[test]
+    fn test_montgomery_to_edwards() {
+        use rand_core::SeedableRng;
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([6u8; 32]);
+
+        for _ in 0..16 {
+            let point = EdwardsPoint::GENERATOR * Scalar::random(&mut rng);
+            let sign = point.to_affine().x.is_negative().unwrap_u8();
+            let montgomery = point.to_montgomery();
+
+            assert_eq!(montgomery.to_edwards(sign), Some(point));
+            assert_eq!(montgomery.to_edwards(sign ^ 1), Some(-point));
+        }
+
+        // X448 public keys are the base point multiplied by a clamped scalar
+        let montgomery = MontgomeryPoint(x448([7u8; 56], X448_BASEPOINT_U));
+        let point = montgomery.to_edwards(0).unwrap();
+        assert_eq!(point.to_montgomery(), montgomery);
+        assert!(bool::from(point.is_torsion_free()));
+        assert_eq!(
+            MontgomeryPoint::GENERATOR
+                .to_edwards(0)
+                .unwrap()
+                .to_montgomery(),
+            MontgomeryPoint::GENERATOR
+        );
+    }
+
+    #[test]
+    fn test_montgomery_to_edwards_low_order() {
+        // Every low order Edwards point is mapped to u = 0, which maps back to the identity
+        let torsion = [
+            EdwardsPoint::IDENTITY,
+            AffinePoint {
+                x: FieldElement::ZERO,
+                y: FieldElement::MINUS_ONE,
+            }
+            .to_edwards(),
+            AffinePoint {
+                x: FieldElement::ONE,
+                y: FieldElement::ZERO,
+            }
+            .to_edwards(),
+            AffinePoint {
+                x: FieldElement::MINUS_ONE,
+                y: FieldElement::ZERO,
+            }
+            .to_edwards(),
+        ];
+        for point in torsion {
+            let montgomery = point.to_montgomery();
+            assert_eq!(montgomery, MontgomeryPoint([0u8; 56]));
+            assert_eq!(montgomery.to_edwards(0), Some(EdwardsPoint::IDENTITY));
+            assert_eq!(montgomery.to_edwards(1), Some(EdwardsPoint::IDENTITY));
+        }
+
+        // The other low order points are not in the image of the isogeny
+        let mut one = [0u8; 56];
+        one[0] = 1;
+        let minus_one = FieldElement::MINUS_ONE.to_bytes();
+        assert_eq!(MontgomeryPoint(one).to_edwards(0), None);
+        assert_eq!(MontgomeryPoint(minus_one).to_edwards(0), None);
+
+        // u = 6 is on the twist
+        let mut six = [0u8; 56];
+        six[0] = 6;
+        assert_eq!(MontgomeryPoint(six).to_edwards(0), None);
+    }
+
+    #[test]
+    fn test_x448_vectors() {
+        // RFC 7748 section 5.2
+        let k = hex!("3d262fddf9ec8e88495266fea19a34d28882acef045104d0d1aae121700a779c984c24f8cdd78fbff44943eba368f54b29259a4f1c600ad3");
+        let u = hex!("06fce640fa3487bfda5f6cf2d5263f8aad88334cbd07437f020f08f9814dc031ddbdc38c19c6da2583fa5429db94ada18aa7a7fb4ef8a086");
+        let expected = hex!("ce3e4ff95a60dc6697da1db1d85e6afbdf79b50a2412d7546d5f239fe14fbaadeb445fc66a01b0779d98223961111e21766282f73dd96b6f");
+        assert_eq!(x448(k, u), expected);
+
+        let k = hex!("203d494428b8399352665ddca42f9de8fef600908e0d461cb021f8c538345dd77c3e4806e25f46d3315c44e0a5b4371282dd2c8d5be3095f");
+        let u = hex!("0fbcc2f993cd56d3305b0b7d9e55d4c1a8fb5dbb52f8e9a1e9b6201b165d015894e56c4d3570bee52fe205e28a78b91cdfbde71ce8d157db");
+        let expected = hex!("884a02576239ff7a2f2f63b2db6a9ff37047ac13568e1e30fe63c4a7ad1b3ee3a5700df34321d62077e63633c575c1c954514e99da7c179d");
+        assert_eq!(x448(k, u), expected);
+    }
+
+    fn x448_iterations(iterations: usize) -> [u8; 56] {
+        let mut k = X448_BASEPOINT_U;
+        let mut u = X448_BASEPOINT_U;
+        for _ in 0..iterations {
+            let result = x448(k, u);
+            u = k;
+            k = result;
+        }
+        k
+    }
+
+    #[test]
+    fn test_x448_iterated() {
+        assert_eq!(
+            x448_iterations(1),
+            hex!("3f482c8a9f19b01e6c46ee9711d9dc14fd4bf67af30765c2ae2b846a4d23a8cd0db897086239492caf350b51f833868b9bc2b3bca9cf4113")
+        );
+        assert_eq!(
+            x448_iterations(1_000),
+            hex!("aa3b4749d55b9daf1e5b00288826c467274ce3ebbdd5c17b975e09d4af6c67cf10d087202db88286e2b79fceea3ec353ef54faa26e219f38")
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn test_x448_iterated_million() {
+        assert_eq!(
+            x448_iterations(1_000_000),
+            hex!("077f453681caca3693198420bbe515cae0002472519b3e67661a7e89cab94695c8f4bcd66e61b9b9c946da8d524de3d69bd9d9d66b997e37")
+        );
+    }
+
+    #[test]
+    fn test_x448_non_canonical_u() {
+        let k = hex!("3d262fddf9ec8e88495266fea19a34d28882acef045104d0d1aae121700a779c984c24f8cdd78fbff44943eba368f54b29259a4f1c600ad3");
+
+        // p + 5 = 2^448 - 2^224 + 4 must be treated as 5
+        let mut p_plus_5 = [0u8; 56];
+        p_plus_5[0] = 0x04;
+        p_plus_5[28..].fill(0xff);
+        assert_eq!(x448(k, p_plus_5), x448(k, X448_BASEPOINT_U));
+
+        // The clamping makes the low two bits and the top bit of k irrelevant
+        let mut k_unclamped = k;
+        k_unclamped[0] |= 3;
+        k_unclamped[55] &= 0x7f;
+        assert_eq!(
+            x448(k_unclamped, X448_BASEPOINT_U),
+            x448(k, X448_BASEPOINT_U)
+        );
+
+        // Low order points give the all zero output
+        assert_eq!(x448(k, [0u8; 56]), [0u8; 56]);
+        let mut one = [0u8; 56];
+        one[0] = 1;
+        assert_eq!(x448(k, one), [0u8; 56]);
     }
 }
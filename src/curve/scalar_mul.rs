@@ -1,7 +1,79 @@
 pub(crate) mod double_and_add;
-// pub(crate) mod double_base;
+pub(crate) mod double_base;
+pub(crate) mod fixed_base;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub(crate) mod pippenger;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub(crate) mod straus;
 pub(crate) mod variable_base;
 pub(crate) mod window;
 
 pub(crate) use double_and_add::double_and_add;
-pub(crate) use variable_base::variable_base;
+pub(crate) use variable_base::{variable_base, vartime_variable_base};
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::{curve::twedwards::extended::ExtendedPoint, field::Scalar};
+
+/// Computes `sum(scalars[i] * points[i])` in variable time, using Straus' method for
+/// a few terms and Pippenger's method for many
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub(crate) fn vartime_multiscalar_mul(
+    scalars: &[Scalar],
+    points: &[ExtendedPoint],
+) -> ExtendedPoint {
+    if scalars.len() < 500 {
+        straus::vartime_multiscalar_mul(scalars, points)
+    } else {
+        pippenger::vartime_multiscalar_mul(scalars, points)
+    }
+}
+
+/// Recommends a wNAF window for `group::Wnaf`, given how many scalars will be
+/// multiplied by the same base.
+///
+/// A window of `w` costs `2^(w - 2)` additions to build the table and about
+/// `446 / (w + 1)` additions per scalar, so it grows as the table is shared
+/// between more scalars.
+pub(crate) fn recommended_wnaf_window(num_scalars: usize) -> usize {
+    const RECOMMENDATIONS: [usize; 16] = [
+        2, 5, 12, 31, 75, 179, 418, 964, 2204, 4996, 11241, 25127, 55838, 123431, 271550, 594816,
+    ];
+
+    let mut window = 6;
+    for threshold in RECOMMENDATIONS.iter() {
+        if num_scalars > *threshold {
+            window += 1;
+        } else {
+            break;
+        }
+    }
+    window
+}
+
+#[cfg(all(test, any(feature = "alloc", feature = "std")))]
+mod test {
+    use super::*;
+    use crate::curve::scalar_mul::variable_base;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn test_vartime_multiscalar_mul() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([12u8; 32]);
+
+        // Enough terms to take the Pippenger path
+        let mut scalars = Vec::new();
+        let mut points = Vec::new();
+        let mut expected = ExtendedPoint::IDENTITY;
+        let mut point = ExtendedPoint::GENERATOR;
+        for _ in 0..520 {
+            let scalar = Scalar::random(&mut rng);
+            expected = expected.add(&variable_base(&point, &scalar));
+            scalars.push(scalar);
+            points.push(point);
+            point = point.double().add(&ExtendedPoint::GENERATOR);
+        }
+
+        assert_eq!(vartime_multiscalar_mul(&scalars, &points), expected);
+        assert_eq!(straus::vartime_multiscalar_mul(&scalars, &points), expected);
+    }
+}
@@ -1,13 +1,87 @@
 #![allow(non_snake_case)]
 
-use super::double_and_add;
-use crate::curve::twedwards::extended::ExtendedPoint;
+use super::fixed_base::TWISTED_BASEPOINT_NAF_TABLE;
+use super::window::wnaf::NafLookupTable5;
+use crate::curve::twedwards::{extended::ExtendedPoint, extensible::ExtensiblePoint};
 use crate::field::Scalar;
-/// XXX: Really in-efficient way to do double base scala mul
-/// Replace it with endomorphism from pornin or use naf form
-/// Computes aA + bB where B is the TwistedEdwards basepoint
-pub(crate) fn double_base_scalar_mul(a: &Scalar, A: &ExtendedPoint, b: &Scalar) -> ExtendedPoint {
-    let part_a = double_and_add(A, a);
-    let part_b = double_and_add(&ExtendedPoint::GENERATOR, b);
-    part_a.add(&part_b)
+
+/// Computes `aA + bB` in variable time, where `B` is the twisted basepoint.
+///
+/// Both scalars are recoded in NAF and processed together, so the doublings are shared.
+/// `A` uses width 5 with a table built on the fly, while `B` uses width 8 with a
+/// precomputed table of affine points.
+pub(crate) fn vartime_double_base_mul(a: &Scalar, A: &ExtendedPoint, b: &Scalar) -> ExtendedPoint {
+    let a_naf = a.non_adjacent_form(5);
+    let b_naf = b.non_adjacent_form(8);
+
+    // Skip the leading positions where both digits are zero
+    let top = match a_naf
+        .iter()
+        .rposition(|digit| *digit != 0)
+        .max(b_naf.iter().rposition(|digit| *digit != 0))
+    {
+        Some(top) => top,
+        None => return ExtendedPoint::IDENTITY,
+    };
+
+    let table_A = NafLookupTable5::from(A);
+    let table_B = &TWISTED_BASEPOINT_NAF_TABLE;
+
+    let mut result = ExtensiblePoint::IDENTITY;
+    for i in (0..=top).rev() {
+        result = result.double();
+
+        let digit = a_naf[i];
+        if digit > 0 {
+            result = result.add_projective_niels(&table_A.select(digit as usize));
+        } else if digit < 0 {
+            result = result.sub_projective_niels(&table_A.select(-digit as usize));
+        }
+
+        let digit = b_naf[i];
+        if digit > 0 {
+            result = result.add_affine_niels(table_B.select(digit as usize));
+        } else if digit < 0 {
+            result = result.sub_affine_niels(table_B.select(-digit as usize));
+        }
+    }
+
+    result.to_extended()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::curve::scalar_mul::variable_base;
+    use crate::curve::scalar_mul::window::wnaf::NafLookupTable8;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn test_static_naf_table_matches_generated() {
+        let table = NafLookupTable8::from(&ExtendedPoint::GENERATOR);
+        for (expected, got) in table.0.iter().zip(TWISTED_BASEPOINT_NAF_TABLE.0.iter()) {
+            assert!(expected.equals(got));
+        }
+    }
+
+    #[test]
+    fn test_vartime_double_base_mul() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([6u8; 32]);
+        let A = variable_base(&ExtendedPoint::GENERATOR, &Scalar::random(&mut rng));
+
+        let scalars = [
+            (Scalar::ZERO, Scalar::ZERO),
+            (Scalar::ONE, Scalar::ZERO),
+            (Scalar::ZERO, Scalar::ONE),
+            (-Scalar::ONE, -Scalar::ONE),
+            (Scalar::random(&mut rng), Scalar::random(&mut rng)),
+            (Scalar::random(&mut rng), Scalar::random(&mut rng)),
+            (Scalar::random(&mut rng), Scalar::random(&mut rng)),
+        ];
+
+        for (a, b) in scalars.iter() {
+            let expected = variable_base(&A, a).add(&variable_base(&ExtendedPoint::GENERATOR, b));
+            assert_eq!(vartime_double_base_mul(a, &A, b), expected);
+        }
+    }
 }
@@ -0,0 +1,264 @@
+#![allow(non_snake_case)]
+
+mod table;
+
+pub(crate) use table::{TWISTED_BASEPOINT_NAF_TABLE, TWISTED_BASEPOINT_TABLE};
+
+use super::window::wnaf::LookupTable;
+use crate::curve::twedwards::{
+    affine::AffineNielsPoint, extended::ExtendedPoint, extensible::ExtensiblePoint,
+};
+use crate::field::Scalar;
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::{curve::twedwards::affine::AffinePoint, field::FieldElement};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// A precomputed table for multiplying a fixed point of the twisted curve.
+///
+/// Entry `i` holds the multiples `[1..=8] * 16^(2i) * P`, so a scalar in signed
+/// radix 16 is multiplied with 113 mixed additions and only 4 doublings.
+#[derive(Clone)]
+pub(crate) struct BasepointTable(pub(crate) [LookupTable<AffineNielsPoint, 8>; 57]);
+
+impl BasepointTable {
+    /// Create the table for `point`
+    #[allow(dead_code)]
+    pub(crate) fn create(point: &ExtendedPoint) -> Self {
+        let mut table = [LookupTable([AffineNielsPoint::IDENTITY; 8]); 57];
+        let mut base = *point;
+
+        for row in table.iter_mut() {
+            let mut multiple = base;
+            for entry in row.0.iter_mut() {
+                *entry = multiple.to_affine().to_affine_niels();
+                multiple = multiple.add(&base);
+            }
+            // Move on to 256 * base
+            for _ in 0..8 {
+                base = base.double();
+            }
+        }
+
+        Self(table)
+    }
+
+    /// Compute `scalar * P` in constant time
+    pub(crate) fn mul(&self, scalar: &Scalar) -> ExtendedPoint {
+        let digits = scalar.to_radix_16();
+
+        // Sum the odd digits, multiply by 16, then add the even digits
+        let mut result = ExtensiblePoint::IDENTITY;
+        for (i, row) in self.0.iter().enumerate().take(56) {
+            result = result.add_affine_niels(row.select(digits[2 * i + 1] as i16));
+        }
+
+        result = result.double();
+        result = result.double();
+        result = result.double();
+        result = result.double();
+
+        for (i, row) in self.0.iter().enumerate() {
+            result = result.add_affine_niels(row.select(digits[2 * i] as i16));
+        }
+
+        result.to_extended()
+    }
+}
+
+/// A precomputed table for multiplying any point of the twisted curve, with rows of
+/// `N` multiples for a window of `w = log2(N) + 1` bits.
+///
+/// This generalizes [`BasepointTable`] to larger windows: row `i` holds the multiples
+/// `[1..=N] * 2^(2wi) * P`, so a scalar in signed radix `2^w` is multiplied with one
+/// mixed addition per digit and only `w` doublings.
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[derive(Clone)]
+pub(crate) struct WindowTable<const N: usize>(Vec<LookupTable<AffineNielsPoint, N>>);
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<const N: usize> WindowTable<N> {
+    const WINDOW: usize = N.trailing_zeros() as usize + 1;
+    const ROWS: usize = 448usize.div_ceil(Self::WINDOW).div_ceil(2);
+    #[cfg(feature = "serde")]
+    /// The length of the table in bytes, as three field elements for each entry
+    const BYTES: usize = Self::ROWS * N * 3 * 56;
+
+    /// Create the table for `point`
+    pub(crate) fn create(point: &ExtendedPoint) -> Self {
+        const {
+            assert!(
+                N.is_power_of_two() && N >= 8 && N <= 128,
+                "the rows must hold 8, 16, 32, 64 or 128 multiples"
+            )
+        };
+
+        let mut multiples = Vec::with_capacity(Self::ROWS * N);
+        let mut base = *point;
+        for _ in 0..Self::ROWS {
+            let mut multiple = base;
+            for _ in 0..N {
+                multiples.push(multiple);
+                multiple = multiple.add(&base);
+            }
+            for _ in 0..2 * Self::WINDOW {
+                base = base.double();
+            }
+        }
+
+        // Move every multiple to affine coordinates with a single inversion
+        let mut z_inverses: Vec<FieldElement> = multiples.iter().map(|point| point.Z).collect();
+        FieldElement::batch_invert(&mut z_inverses);
+
+        let entries: Vec<AffineNielsPoint> = multiples
+            .iter()
+            .zip(z_inverses)
+            .map(|(point, z_inverse)| {
+                AffinePoint {
+                    x: point.X * z_inverse,
+                    y: point.Y * z_inverse,
+                }
+                .to_affine_niels()
+            })
+            .collect();
+        Self::from_entries(&entries)
+    }
+
+    /// Split `ROWS * N` entries into rows
+    fn from_entries(entries: &[AffineNielsPoint]) -> Self {
+        debug_assert_eq!(entries.len(), Self::ROWS * N);
+
+        let rows = entries
+            .chunks_exact(N)
+            .map(|chunk| {
+                let mut row = LookupTable([AffineNielsPoint::IDENTITY; N]);
+                row.0.copy_from_slice(chunk);
+                row
+            })
+            .collect();
+        Self(rows)
+    }
+
+    /// The point this table was created for
+    pub(crate) fn basepoint(&self) -> ExtendedPoint {
+        self.0[0].0[0].to_extended()
+    }
+
+    /// Compute `scalar * P` in constant time
+    pub(crate) fn mul(&self, scalar: &Scalar) -> ExtendedPoint {
+        let digits = scalar.to_radix_2w(Self::WINDOW);
+
+        // Sum the odd digits, multiply by 2^w, then add the even digits
+        let mut result = ExtensiblePoint::IDENTITY;
+        for (i, row) in self.0.iter().enumerate() {
+            result = result.add_affine_niels(row.select(digits[2 * i + 1]));
+        }
+
+        for _ in 0..Self::WINDOW {
+            result = result.double();
+        }
+
+        for (i, row) in self.0.iter().enumerate() {
+            result = result.add_affine_niels(row.select(digits[2 * i]));
+        }
+
+        result.to_extended()
+    }
+
+    #[cfg(feature = "serde")]
+    /// Encode the table as the canonical bytes of the coordinates of every entry
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::BYTES);
+        for entry in self.0.iter().flat_map(|row| row.0.iter()) {
+            bytes.extend_from_slice(&entry.y_plus_x.to_bytes());
+            bytes.extend_from_slice(&entry.y_minus_x.to_bytes());
+            bytes.extend_from_slice(&entry.td.to_bytes());
+        }
+        bytes
+    }
+
+    #[cfg(feature = "serde")]
+    /// Decode a table from `to_bytes`.
+    ///
+    /// Every entry is checked to be a point on the curve, but not to be the right multiple.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::BYTES {
+            return None;
+        }
+
+        let mut entries = Vec::with_capacity(Self::ROWS * N);
+        for chunk in bytes.chunks_exact(3 * 56) {
+            let mut coordinates = [FieldElement::ZERO; 3];
+            for (coordinate, bytes) in coordinates.iter_mut().zip(chunk.chunks_exact(56)) {
+                let bytes = <&[u8; 56]>::try_from(bytes).ok()?;
+                *coordinate = FieldElement::from_bytes(bytes);
+                if coordinate.to_bytes() != *bytes {
+                    return None;
+                }
+            }
+
+            let [y_plus_x, y_minus_x, td] = coordinates;
+            let point = AffinePoint {
+                x: y_plus_x - y_minus_x,
+                y: y_plus_x + y_minus_x,
+            };
+            let entry = point.to_affine_niels();
+            if !(point.is_on_curve()
+                && entry.equals(&AffineNielsPoint {
+                    y_plus_x,
+                    y_minus_x,
+                    td,
+                }))
+            {
+                return None;
+            }
+            entries.push(entry);
+        }
+
+        Some(Self::from_entries(&entries))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::curve::scalar_mul::variable_base;
+    use crate::TWISTED_EDWARDS_BASE_POINT;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn test_static_table_matches_generated() {
+        let table = BasepointTable::create(&TWISTED_EDWARDS_BASE_POINT);
+        for (expected, got) in table.0.iter().zip(TWISTED_BASEPOINT_TABLE.0.iter()) {
+            for (expected, got) in expected.0.iter().zip(got.0.iter()) {
+                assert!(expected.equals(got));
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_base_mul() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([9u8; 32]);
+        let scalars = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            Scalar::from(16u8),
+            -Scalar::ONE,
+            Scalar::random(&mut rng),
+            Scalar::random(&mut rng),
+        ];
+
+        for scalar in scalars.iter() {
+            assert_eq!(
+                TWISTED_BASEPOINT_TABLE.mul(scalar),
+                variable_base(&TWISTED_EDWARDS_BASE_POINT, scalar)
+            );
+        }
+
+        let point = variable_base(&TWISTED_EDWARDS_BASE_POINT, &Scalar::random(&mut rng));
+        let table = BasepointTable::create(&point);
+        let scalar = Scalar::random(&mut rng);
+        assert_eq!(table.mul(&scalar), variable_base(&point, &scalar));
+    }
+}
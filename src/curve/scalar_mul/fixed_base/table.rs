@@ -0,0 +1,2737 @@
+// Generated with the twisted curve arithmetic from `BasepointTable::create` and
+// `NafLookupTable8::from`, which the tests check against.
+use super::BasepointTable;
+use crate::curve::scalar_mul::window::wnaf::{LookupTable, NafLookupTable8};
+use crate::curve::twedwards::affine::AffineNielsPoint;
+use crate::field::{FieldElement, ResidueType};
+use elliptic_curve::bigint::U448;
+
+const fn niels(y_plus_x: &str, y_minus_x: &str, td: &str) -> AffineNielsPoint {
+    AffineNielsPoint {
+        y_plus_x: FieldElement(ResidueType::new(&U448::from_be_hex(y_plus_x))),
+        y_minus_x: FieldElement(ResidueType::new(&U448::from_be_hex(y_minus_x))),
+        td: FieldElement(ResidueType::new(&U448::from_be_hex(td))),
+    }
+}
+
+/// The multiples `[1..=8] * 16^(2i) * B` of the twisted basepoint `B`
+pub(crate) static TWISTED_BASEPOINT_TABLE: BasepointTable = BasepointTable([
+    LookupTable([
+        niels(
+            "82846f0a7821436a468360983c651204029321b828263a61c9ea92156822938a0a0c0c226b9fa4728ccd860f1d5a3850e4303cda6feea532",
+            "02846f0a7821436a468360983c651204029321b828263a61c9ea9216e822938a0a0c0c226b9fa4728ccd860f1d5a3850e4303cda6feea532",
+            "2affd38b2c86dbf557e6f84b2df8571f306c9689b4610bdb69a167f36d2ff8ed39073e1f6789da3cb38cb0eb141a0b0e8bef8e22b275198d",
+        ),
+        niels(
+            "e997758aab28275212d463f42c2b4be95b351bd0365f487ff38dec3e702c3594a15cc12be42b22500037af020cee009351d8ef6eeba44486",
+            "74ad9837d9a71c45df54d0410759722bc568549993665e1d10d10acfa5cb18df508b703107dcabb218e5ea58c46ff1f9e0a40c400b9f31fc",
+            "0af228764bd554dceff8ee8d44b6e95f8732379fb06c3375b0d9b03bcff69a9f2c69b68355a4f9874f71c7adde2b209e84266676dd443209",
+        ),
+        niels(
+            "eae7ce1cd52e9e5623cb459586b9a8d3d51e9ca839bfa5696cc69b34f103d2785945accc9810ba7c920ae19533e49522840c3b1019d474e8",
+            "d27f96d6b143d5540969f4d6e1198046c795bcc5e50cddfe557feea45a195ba05a876d74c283b3e67522821612d69f1862cea0fc8d2e88b5",
+            "1e87bb2fe2c6b244691725ed47579ee57566877bd38435d96374a7b39c20f43431a65aacbfe5efe105392cc3844c69c42f05a178751dd7d8",
+        ),
+        niels(
+            "33a454de30d4267c0c9a9b37defd2395a9632e7d6bfbb0788688f5f23b62040cfdf3dd923d2ae435677ebae1beb2fae5a8232066927aa436",
+            "7fdef51b62d61a4dd227de910a8f0083b58b17ac9cf7bc1532a3dca0eedc3e4ce29c0dd71290aafef08b4e7e8c7c68c92a478e688c8d018b",
+            "a2d31b616aaaf4f78f96d21d064f768ed0ba717ec7eaa6e0f04c9a224670470e3d133950d13a3474e6d1077af23ae229db5db284392ea258",
+        ),
+        niels(
+            "cfb79b22990b3962946159321bea1c163f6922e3ed73ae0b2c9296e9e77ac09ef126d2f4498186cac0510949a5a0525d0d3ef83af164b2f2",
+            "9c21f166cf8dd1142472d344181937a827287cf2897d558cc7d656cb17da1208c4fe722e9f96782019152ffa45000470ac0cedc4debf7a04",
+            "c1443e6ebbc0c4718397b7a97895261f92ad15e620bffd919b5d749c79ac4f94487f9252e8114c2f67472d7e5eabcc9a3ab001431ca9e654",
+        ),
+        niels(
+            "71576eac1bdff28a858d828f1743c1a019bee32e0f81cb78ce4a40a6d21597177021534fbfe3aaae5d1626d4cf0c15c9b77a76ac8a128121",
+            "e06db897646405b07929c044666a2b10fe1a3faf62da46650b271997ba6acfcedb2c88f3caea4bed28fc92c3ec4ab287cfff7c6ff164313b",
+            "1f597c73edc5ae9b5bee5b12a1c7d2734535f77b4c044d5a7435a0ef1471eda679d81886a340a0aa55b822114ef2186c35fefbbfdf53512a",
+        ),
+        niels(
+            "c16abed6d64047b2116d5b0e71b8e442a1eaa0e84433e9a9ea3cea2fa325a49a8836301bb6c035245ae5ab2e733c919c943f5f9d7ec4777b",
+            "74a5173a225041ef15b76fb4092e57679a991839f34bc18c9bfc22cf9fbd5b3470844d04cb9ba11c93dc8977935b149fbeeacd90c1e0a049",
+            "88c7d2790864b8b8f637c8fa1c8b17476d89d42f6d4faa9518914e35cc12bc9e9b8cebe221865710f9101945adc5d65094c560b5ed051165",
+        ),
+        niels(
+            "ab3056a187c2afcd8da22e23b2d97ed816cd9dbf27756a1b14035f0b26adad0adcede3887c93d65c7b010c3e28ae081ff7344f830cee6100",
+            "68e36ec339077481d46993f13666541bd326ee9a3f305cae44fa2e4c3db390f8538e20f343d34cbf764a15f64fd8797e77a8c58901600d5c",
+            "ed1972c4de0f5bb103763d9fced9e252efcbb0d9a6a3b654e35c3cdd2b64dd372e7388468650e5f504c5398c85c925782b0bce467a234e7f",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "b8f9b3ad83967710bf107fff3a18faabd315730cabd7a2dfbf985b97ae7c144cd2232a8cbb3be2c047e2a171b4f7ea95e34a358c5113531c",
+            "d258dbad95c92d0de4d75f20b69f562a255cb818053fc780ebfa5da63d0472bc9bcd4005434690d41af06bcaab6741f87aae96a11447fe81",
+            "ea33fb06c816a58ac0acad2c6c0ba91161d77dbd55536a0c7acbe6a81afb1a324bfc9edeb7ab3e4c9743e9f9564531dd3b2d758d421bab82",
+        ),
+        niels(
+            "8b1d83135ce857c8323228032113dcdad3dcf2c7eebf60d759d9e65e2fc22d652df03b4d71b4c125fae00c3045f75b02f40a939ba53a8d11",
+            "fa9ff1dfcf61b20797ec494d8f72a2306642918951706dcbb3411198401dfdb7b63770cc334e7a0f8b8d0f9a8df6eb8c5470df94d4edb4ff",
+            "904fe99534ecdfa57463b596d46540af8a5524714407617fa0227431025f5162b050d5ef08d2c859c53bb7fd05bc3a1b38d3b852ed654f80",
+        ),
+        niels(
+            "e488dde963e65ebe90371e157a045206431bc20a058243eca739e5ee572553f89ee6572e3b26596a92dd02f2f88d8cafdc733c5d247f1517",
+            "a1b5a3d4090fbf63de6af22449d43cd3fefcdf7ac0a598ae5663be5bf4c5a098ba9c488017878ddf7003ff34ec5a32d5a3abd0af1ccab9ad",
+            "81db470fdc0797635c203d7ac407077cd500fb46b58e20e32c43003842298b3552a349bed248563fec1766e5a14039a01919719b16be59de",
+        ),
+        niels(
+            "70ee1f549e60565a315eb248834b74f095ff8b11ab8d816dada9bcda8b5df4a613eada730f85e4d8e667f49343c7f87f62b907f2086e96e2",
+            "e161b8c425dacec82b2310049edc8dd94dbeb76f5c8cf4241b69285218d97d5895a8890f740aa4fd4bf34b7fc460a5093e826108aea19e7d",
+            "b2f844287f3f8b0a5a4b233dac7248469abc45b0629169bb09916bd1f8503fd8fd69b72d97f6d059b016d47039a0d4e1b493847d39092791",
+        ),
+        niels(
+            "c991c5214b2db7d67bed046cb286f6ce92e0f353ef5121535ed31fbdc32f24b749efa3f7bf83c2974573adb1ed29519c9c2b07ed1fcfcf91",
+            "252638c879c1783992c670fcf6e68e1be427ce6059a1b14c69793798862a1c924762aca6305acaf78afb2298d71516924e7c5dee5c94b28b",
+            "26e9976a4cbd0164a515d1f0211ba7b4ecfc24bac03883fed8704941523a31df36f41d8a737be983ffe4aad4ff8229bc15c69369baa310ce",
+        ),
+        niels(
+            "753b45d3a02ac9bf7877023128bf0871ec8f60e2e8e304d541385aa5280b74ddd4013211fd760f08991d446155c4c7384e6abff0127ca19d",
+            "ab2c10eb6e86d7d9b9d24d58857df987e23aeb28cede630c63c7f1fb356d101409f299349adb5e18ca07d2280f7a980aaab9cfdffff512ed",
+            "81f5069e9ca0bc6e636eb2ffb6f7c90cfb90696458215fb41c30aec8e490212b0e7f11964b28624879668a7fc40648476e5e3917c015b411",
+        ),
+        niels(
+            "466f3259b0f804a9238b52a32ffe4349db6bc018c5f2f558af5fbe3f1b3091144268dc4f375dfdfa7ebeea598084a80bfc3a2be707b095dc",
+            "33343d7f5bd1ff62c4f101c91978fc9754c43e65761b8a2c125ff0cf3b3f02910ae9e223b668b406de2ce0f4598a672f07f0ab5f496a3bdc",
+            "e335f7774618c39f316b7afce3a08d979d1bb40e07a74ca5dd6f92804ce7288c76e13c243dece8ee700b3e84108730b84e6b53a7cf13c529",
+        ),
+        niels(
+            "44b7e79068c58e178c7ba3816f6763dd9b2a9bf45210d84e6a13caeb0ade076232e04958b2c4a261866b681ed88f48a3d93482da2c5a991d",
+            "45a37b019edcb1fa52d4d95243e0500ebf8050f8ae74270c5e26ce991113fce9460f8ddc4ade1f5de9603166ac983288c88a20aed65ba4d0",
+            "36c0ff3a8c862f05e6e5c0a7b73a09877868ebe3d10b0a151db90c241e73c0c8d3402a9aad438d17d762c5f3505bbbcb8539ee8cb7f1aa70",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "67a35a664eb2128490b6ced4ab68c736bc2fa1d9634b1e0b39db4f7c0785f4394aaf61f9bf7285148bf8248a7cff4a22b2450098ba6a66f7",
+            "6f3f4931f30e833992239e5b5e8d61a3aefd28d9848a5fa02f0533645e3088e6cefae34050e8ebacd09514643961740eb643bf94e23011ad",
+            "0f67b4f380481df6704c23db7c8d5988a63c113b26f0208dcc3ee16f9bc6664dcd89019ef1f0d4efccec8b19f72bef0e065237e5dd9f297d",
+        ),
+        niels(
+            "0d06a1b413697b028396e574e31c02f307e286c7f1469edac61fce785b94978bdce95e2c8a3c8bbe54c0f12ace7d5cb1d71c390b814c9f1a",
+            "ef756a088296fc649e4feed3372215573fd917b4027d96992c40c9aca0187dbac20abcb1194dd14eee40fd082d2ab9ccb4edd23d05cfd742",
+            "ba8860446d76fe556e235b36a95f79a1bf1d49d33d04a74e1acecfe99853704fe965148247deecd395b8e73b51629926478685f95ae477ed",
+        ),
+        niels(
+            "2f484b284dd0c2ff197659b7a6b95db3ca5ca39385c0cef6db5ad630c91ed6908422088d7c6fe461a88760d30fd252d5434ca9befdc7649f",
+            "da53c39b95c9bbd6ae448152c71ad867d9ffdeb9933c3e919d3be66a219fa905b294be671cda35e0dff106532aad08113145434e564c7e58",
+            "4d834a1114d342187b29d11526a32b64b64e707c666d8818a376e334e90f0e8e93d4c0817d144d4a39d736fb525f844aefcd6f5fe6b07224",
+        ),
+        niels(
+            "6365e598aeea67a1df8f2dcc7fe7f9535f2d5f354d22b5ea090d79472f8f017004ebfdfb7e62c7234bd0285d70639343f2cee81522e29d5f",
+            "583bdb3245a56cbfd9ece725aa721b2758c99f5da7cb73e03d22b00f0e3b443b0c5375d1b1907f12ba6e1146e03bfcda5322303fcc196f18",
+            "e5dc29faf77664f9d0c4112999bd121dbe2d4bab85e7a417a016532c367535d4cf4fa16d4e4ee5fd7b2a1dfbddd8855fae08b10dbd83233e",
+        ),
+        niels(
+            "d55d6a21fbf7bf5304203eeb76117529c3b2ad730cb96034bbca45acbafd76b450b90b144376da4c4bcd0e1404d1596e89af1711bb7a2037",
+            "3deecbbff6c0f6dfb1f1388538f3559d18f277979062832272705099844a47b2146b701a22b209e2322f5c37cd07430c8b612ebfcd0431fa",
+            "f3c6fd2c35096450d3a646c173125f0b0a9accf8deb79167b65dfa477f09c1fcca5569d29239e9ac64f4c531a42dd20f18d3dabbd86ceed1",
+        ),
+        niels(
+            "456aa0b48315796fab26ae500a5efd787fb894acfc195299b30e79fbfe028449b303686e43bb4b92880303a0d5656977c8492ff02dadac41",
+            "dce241429d61dd1aaf9da984a9b13b4ab436bcd2a8ea32d5945c392bead1c0fd29e48d3e0337c8d3e0fb9e7aff2502cf3f7ef99803bf1bd1",
+            "a726b33529997eba8e1d0cd3e35e19bf0037d8f954bef99b814d14634ea65ce330dc605218333af2fb7c7fdc7f3f6adac8fd71612fcf9538",
+        ),
+        niels(
+            "f93868032802be3936a29dc2a34c91e5afdf034673623d05e8c21811514e9fe29c591e4382ba83a8a71a8ecbabe8329a58b882bfbd0be9cc",
+            "5a1c8eb51f37b803ca5c9fadd3c071ec9d5c1f092ef6acd34d1267e440aad16efb14275367c9fcca2601f6cd6ae7d277cfb152250a677c49",
+            "a25c23881067333d31fe0269978ba3b8b120f1d2829f6b118edc779aa1f903299b0c762e669cf9f6fc16655d3a569c782380afb000d7606a",
+        ),
+        niels(
+            "087b93cff9b362838899cf2f903e7a24d96b898e2d35e0552bb9756f657132963d222d89192ea05db9c249002d1a3dca52960fa5499d5203",
+            "847ebdb1c30238f5d7ce4aa70588be6c1e1e9d7450e49a17e2d765d37236f0bde7c809838db4356f3662e203f59fe5961494b6515ab4a67f",
+            "1321859f0b9a89c8c8a4bc06bf02d76a889c97708658e014ce0eb7a88ddf8e25834f73f5e78dc77973ca8ac54801c46db9d3e3ab3ad78670",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "0b264fc02f907e9ef48947f75c8cdcb5d924a410f29b6eec2b79cf9f5a1f709c37b753c7f89559758eb06192f38d5e5b745818a32007ee50",
+            "74d3003d8dbd16abc951dba89d9a847fe481a6f8123cd989176804db83ec10f83ba62c6f3fe262892c0d4ef48a4bea31b8b1f2320e3b016e",
+            "8aa1ff62c61bbfe9d48bfad3a91fc3c1608072906c741743d4a55e806c60714e1a551b930e89c90c334c47b666ae69e8ce9b7542e9b57f2a",
+        ),
+        niels(
+            "63ec1bfd9b4c6728af637cce907756bedca3a0284680d29b817579ccd662f298391e85b420a9bbad3e0271a124685a6e46c84de84e81cdba",
+            "9be6140bffa7d3c136406e0af88ef8f715969fed114682120850de42a484b590d62aa68faaa41900482cca81b2e5efacee52b75db90f2e46",
+            "56437ca9475fff5762e7f22f75dd8edda1c89768c28c22ca52dc601d0287a674101476091459f98045235d5da7af7a7256c641bb8ae3cf6d",
+        ),
+        niels(
+            "b752aa7a3d20d445129014e0333613ff5e3a5bbe40f9253b6e3869e09bb240048c1987e9d61656c60ea4bd0641d0a9d1b757c507e36971d7",
+            "aeccdb7282545b66fd87690e61e7ffd18d9b808d98a8e1bb288f432a52c69169e1488d2899f55b7443b7f14eb707420c7f9ad8e491ae71af",
+            "d7db10c20d38241d0895877b2762cbd99f5360cea85b6996c81b059834106c45676a1c8ea3eb5fdfc8bd92c848550dab0d6f30bd2a535b9a",
+        ),
+        niels(
+            "729efd96ea7a38914608662cb6fb35c0d02142f659d1bde24bc30dd607f5ac47b022bc26d2daaa2ddfe38b3f8e21464990c4864dfd1aa753",
+            "ad7119576e61b85d342a39d8c17587b757aea1452a8f3de6cb657c7b5117bc85ce52da1d0d098aa75f457b75c91e3b7d5c24f15d3818cf8a",
+            "07db9486027c215f45e8b41ad7cf7a720b1e68938337bd0290ead81429c25c55f4862a79f1808421f3acffa47ef6e872b5b97617009bf7db",
+        ),
+        niels(
+            "ecbf48e120949c672fee560175feb46a119868cd21208110b8a1f3fafcee3122821335ebc874e21470667c1abbd98c556f373f489ca0f52a",
+            "1ef776e13390004a26cc555c9387e622861fa518674ae045aecb2b6027a469d36e0e545f3cc4710ec4aec2f00c780f81d3dc73efe0ec5ed6",
+            "360f0e04398fe52829833e029e1ef9a05df409b70d0ae0a9591697f88f6014f1ba0fa23234e669df32a789f3a3c855fcd0604251d747eb65",
+        ),
+        niels(
+            "38308d64d672567f42fafc263246ee8c2659109b76b40ca47edacc068f6f5b91bdd531ba37209f09d87845a0031660bc9c49d9fdda9b0b77",
+            "9ec39711fe86b017635f35164e9f52fce5c9504fa17252458c409134098941dddf59a6267d277026cbc5899f422e5039b8d9a45f843ed369",
+            "ce7a1a1d1f16f7fde97d4223292b94c70f12d148b0fcadb3fb0e102359cbb358b7e8f6c32dcda4c2f1812773238c44b038081c5b5ab73f45",
+        ),
+        niels(
+            "7ebc41f2eba6cf7f25a9ba9c50ece2625f8c929bcc7039dfe0494d00366b8820010d756d1f19cdc13d5b2b0d1405fb616fa36222438a0e70",
+            "64d5305cab29fae7554b8e1ac6420c9ff5828a991a49f978ceaefee0d95f2b08d07fae48339daec8a3602d213270ba1bcb1b972642540e07",
+            "10eda0ff27d51dd618768c68facce504b2fb7d58ceb76f82988c9278f23e6288d7d78a27a9518f6b72b0013123a944f52ddd253f83711613",
+        ),
+        niels(
+            "697267306cf2b11bf9c1c63a38a14a4a793fa799b559c9f6fb3a29863bb44465cc5db14ee3f7f3c0001d39de297afbf8e316ccb2b8259196",
+            "dd84699740c7e81aafe23288015959244d84b0b7d095c4fc92b8785a5bfffd7e262f06978246f337f1442d171f7efc3e9fc2fe50ccd326b7",
+            "3b29b53e4662d39ea48e563c22965e306c766b26c9ae0276a713993e8fbf8403b09bf8b1d8f4a48985d936bfa140927ac18f7a404175ab65",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "9f7b874a548b73dc0a68f8038ff9f8f016f28d7e94944f7c410f64a7cd553ec54dd9a788fcc7b70318c1cddf3b543131786dcdfef2f73ec2",
+            "26a26434d82b631528d2ad7f8d0c705c2946b8f1eef5789ab720b11e3085b958b00fc17c06b424191ef9b201aef79e0c23b37354a3ca222f",
+            "1cff4ede8d871942050a0ad99f9afcc03a1365d5ae03714cc1c6235cf5e7e70e0376959caefca26a70171d3253c1e21d341d71d820ed46d7",
+        ),
+        niels(
+            "ab15d3bb72bf4b714c1777482df005a7604c7a7feb518f7062674b0e0469b8c2cdcc05f1492db8d1dcf65d5cdcd0e21dd8d087872532f426",
+            "9dbba177b3073c318d79d910aa66935075bd061ad4145ac049deca0d6eb7170a6589ce98c6fb2c7f2f435b2a3a50631d9344a9669ea23d8e",
+            "fa40457274881aaf7bcc9f7c74b4007786cce15c71133ee557ae351c64ae0715d7381401f7fd2f2b05c10427caf4849aa38a63269d82fde8",
+        ),
+        niels(
+            "550bff54a14ec7ed6a47964ab5bf3043777952b487d0bac76995af5769aba886f0d71faf96da747c7bb2ae8fdc24a3adae3530029a11a901",
+            "0876d93072e0e4023668bc5fa37c436434b8d9f6084c56359f62af8470368aef3d330dd31d62307a3daa7d4ed1a440a3365f153b076f5d48",
+            "a287a3feae9090ff74a357701420c5ed826e748c5216439d4a9c1f8ed1afb3617cc6e27bee9d5e780f3921c1d0dafdd8868d26e5521305b0",
+        ),
+        niels(
+            "e55170c77dcd12baa569d84f883088a3f8adf23655d967cca0b631424c2887785b31b2df0b4f55f0af3f79fabe7c842ca26786bfec40dd71",
+            "b38c2b348eb77fcf9a563822af55ba07c0e064fc8386746b741303f7b61fdb891f2e934278a26ebc094c218c84fb56ef2240184a1289e264",
+            "1e70a94c5464559da459dabe5dd7af8fd9f77e247f1bb8bcf4a59b6524efe027e24cf25966831e328bcd0fa8ca068ad4f80f1d6082dccd8e",
+        ),
+        niels(
+            "f3e8ada76f493c351e8e214200c4573ee15ac0649232555d1dd99006f4366eb8bae6716eab44eddb344f3c89d04a60324689d83b7a18b612",
+            "92377897bc9c8fd0a2204f1960c70f767acdf0339ddd27899b624d93aab566b6e7831a3c1dc04c8c72830ba80cdacd1ca8d99c868609409d",
+            "039b01285e95e15bd84f58cc141f0417b81fff3d5bf0a18abf48ea016cc84d691c47d5878b017689f4e8e4980c71a4a7f6e13f33196f1361",
+        ),
+        niels(
+            "5038f7a269d666fb0322dab1f998f2d7e1514b5d4eafaa3b0e295155ed0551355cc8e4d0c94e7074388323ea70397e428909f52dc3549c56",
+            "8819371df4e02717b9f524d6c5c8a840f75fe1ccab2580b8e031534997ee66643cc308431baa2eb929ea011b69945fcfd6d1d0e39bb7cd20",
+            "51c5801a2e939d351f42b3c8565361e08decdbf69be477f8c588424fa5868898b683f16eddb01a79e09c3022c8af84bfca5f15fe28a1e81f",
+        ),
+        niels(
+            "58b6eacb63e3eedd8eb901cf39f6a0c7bda010fc6c179329b12aef8ed15e973d898bcbd6a38bbc75b5159e79d511add6e10ff9e935892bf1",
+            "1c95efac0db5e9c0d803666c66c095f004641e3ac7e3a5138310d2c3695e5cb9d3524d1d68b2120597c98c6327fe1d1066872c230371dd07",
+            "e077a61f238bc5c0f6a6e363bacfb21bf7a19ea1e12441a4a28b354003252d17307490a29149592c4f0eb3ebf8f04b88886036c8f90cb11e",
+        ),
+        niels(
+            "7428ecb68951b930aea80d8b367e7c17ba02bd8078b7ec2fe2160cb757b1e582856bf7eaf4e1a8271ea6aec878954c96fe1c5d387b5cc562",
+            "479085cb59d76835e80a9c7150cb391d118a2d225a48ee7c47cf16a27a1e687e6a0fcbdef2844af504c9a6b410f003568008ca965d6c0945",
+            "45bbd59e3eb96a48791e6a4cb5dd1e9174a1f0bf5d6458d83b93c839b9d978b2d2bc19d19d56744b95dc9fa057fd258a18583415fdfb8c94",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "f2665a41f19f68ab4253410891be5a0e8fcd2d5ba9562c2a417d1bfb4406f4b39e799b7cbcfe7afff3ec75bf899e35a7225dfe03544ad3ff",
+            "dba6499087e1231feb4f5277daf36c1d19bf93fa8d673b38632864e354fefd67d862be65d8f474b89a78129cbff1671d91e19b0d40577c00",
+            "9d66eb98cf3162ea5e0e99439574edce5d5a7b3c242d06b77ed0699f1c4a08983ff1e751a4a7df7c47a1a0f25d253d22eab7fdfaf9564e68",
+        ),
+        niels(
+            "492d08174bc16bfc35b097cd7ec1f01a8f402e5febda969a091af08f286f87b49de009ae5a04499af442853e7bc9d7e20b7224f007dc0a1e",
+            "51a54add4ed52524347484ce81250ae5522cf6671949a444521b70c9ae15223032f6960f5d4aa1561e7cead3504f5f7e3d6cb225547610e2",
+            "87bbe63cbc7562e66402b774c7704769d982d819b2fabacc67a0c3ecc231f51090252903c4eac4c5cffde6b9d09fa95f5e4797870ddae617",
+        ),
+        niels(
+            "5fa3d0b8c455022b5e680f5bdadfc89c62dff03b2ec11cc849a3c80a8fe2ef03cdb8f59805fa1a27c9a29163bda9f249435a2707502eea45",
+            "9253518ebd5a728778507f7b8a83837af34b087adc432a2b7fe0e3e14bd73a288a5fd59e2147372c32a94c5232bbf1b47116bfee60666285",
+            "8737f81954dd199327766cfef8edba4c61326410d93d14e25360ba6e9586bcb2c36fb064327d6bce6f4729b0ba1c7e2b5733c0f6736bf671",
+        ),
+        niels(
+            "d5f7d33930d047c24c33a5385444fcaf1241e89158c535bbe79d845eaac8468227c511ed1c5e86445872661d2ea19340255245317a74f873",
+            "9171803f381eeba290f2c108e32821b139ce4c619d10faca0fe008ea0cd70460a8ba6a00c2aa865996fe488758e8a4cc5b47503fc5a3ee1d",
+            "e2412e5f21bfbcad91e0dcf2f06d454bba0eeae17d4f43d4685580d7eee38dd2a324fcef7c35c596919d369c9d7966f0229a090c97049896",
+        ),
+        niels(
+            "1dcf9cbc5c6a770c4c89ae4d92ba5bd8946d76cf09e3d6c66ff3a439d566a93b3c796941f2b66a5112d0a027a3b955fd01b7cea6cb54e750",
+            "299a4c04de29f1f6209c48a242d0fd963271b2bda61cc697db02d2e57d64f8253b7590fd9e0666ef104b525048822a712b59b4f1dc84062d",
+            "7ca2237a6bdbaf57678621e6680f387cc95f3ca1ce9e6a510a3cd8b1d8d5837c805b16a39b5d37f4a3f30d4e0a600bd67d068dff66a3dfdb",
+        ),
+        niels(
+            "af97cf0cb87fb3ca3630d681020baea1f4d2174b554370e592409ac2d795079789d617c6ce82ece5201d0891ebba401858a381c930010fd6",
+            "eaa9d2cd43348b2c49214e2014ef2afdf5c84ea3f0537f42b04a9354815802a743b74f6f59b8c485f81558892f645fac9972db8d1241b128",
+            "a9dd2889d85fb2f226fbfa38e4176c013ae18f8f1104dcf42bf31eafbdcd669d723d80764f8c57c25dae794f247e31e0e7dd443391abce47",
+        ),
+        niels(
+            "01683f065df61952735393c00f96b32f7793b41010da287510e049fdeb570f82aeb089a3c2cf03fa2b1e8b786bbf95fc6949577b759d0b1f",
+            "7f1c9e9f6d86063ecdee4c59a2eabef678351b39a57861c1b23c027462a17e819982b3d98f52871577cbbd89b65bc63dd049455fe3ceb645",
+            "abc71065f70a9d3962d3bbd3d76823d5f173daa67dbecd44601283c6e40114e34bcebfbdca948a7a3313ade91814bd6f6b76e2daa3de66ea",
+        ),
+        niels(
+            "4ddc72647bd6385f28dc2e9f51bd317eab8b61183adf241ed29134ba5a65730d2efe6489ab2493441196e6d20d2eababbb9682b8e33f06a8",
+            "e51020cb0e3390ec6ab822ec87c629a25be7f84ea2ccf920d6308ef500cd5d14fe14d898475593470c2f0625226142558320251ed429143b",
+            "4315f5f13d8950252831a246db5cfeeb124ba458a66bcc2934fc130e7f4d9a2920c038d86c5ecc4c7d22a2a218b2a9202000174197201fa1",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "43d37b0f2c768583d406f4b3203612adfcd0ededcf170d123565e63c2d931e4914e17a01a34fa4de72d6635e787e351d849eaea741c8748e",
+            "f2d1c754bda2b97018fe9afc5d2bc3bf3b47354c35b339b623341bf259920afe3bb66a3b465599bd58e36a3646e319d4e25957f6126fb8b5",
+            "5483ea1105132aeb2e813e5e10be6a855307afc33b7da943d922d74de2017e9783d534d32312abd9a9062cd8e54b0370a9b4f42866f7563c",
+        ),
+        niels(
+            "59a1ea75ad3a86fe539074ea4980758dac68b0a53976fead955eb0b7b3565db62b4ab90b808860080105657c128627191753ea13a92cdea2",
+            "540bb4c65113ec46e515864343284e3399f9d9eaf2073c6f7e9e11974d6dff817d4b6abfd1d66c5aeb74fb90f096b7a537dc306c1bff00c7",
+            "d584c4ca039f9266260eb06df6233a343bb35b7ed8e0abd0503d2a54204582695ee5fcd30395e78d2526ad7f8c8f11b21250a9c8d8f24526",
+        ),
+        niels(
+            "317c3d8d0f44fb876ba42635dc709f7092b923c2cec45ca2109b965f8739aac2fdfc72d9026fc50628be796de700f28591a9f00ada129dc8",
+            "833a053df68e952beba234371dead104465e612bea7381581602bc0d22914ef7839072c16df63a46ad82a010c3af68cf499d1f66cc5a925c",
+            "c01b42703d4883b8b7a312879e18d21f0cbc2596362043a0c5a19852157e6bb4ac7aed6a65fabe80c617780513166db684ca2a57afc6abe7",
+        ),
+        niels(
+            "a3b8f04826449b0f86003de251fcdeb51d9eca6cd14fae244238ffbc04fc30bf8e8ea836b47f6b816d16e737c929f609c3887bc915476c67",
+            "96a146e21b072468f2a69c8e2bc9df7d37535b18f5851d45934dd620b40bfd73f58eb32593c5315e6174f9c11a7fd87c28024096cee4b2e3",
+            "ee11b92e64907bb818828a8dbdd4795d9096396e0c69d4b3d3f041de3b69125d4f420d806e9efbe8e48066522a4345b94b20b447f2b18e7e",
+        ),
+        niels(
+            "7321af2b7700c370f5e13f0483622dc3bbf941cc1b090b0f0461c015d1238a886274311149021f0ee4058dae4017a300029b7900b616783f",
+            "aeafa3796062f2365b591f4cd1fd7356ea02289b8723f5fcd943427bcc17710862a4d1127ce9c502ba2f1f806b45e00cf8d48e373300e061",
+            "35372dab3837de25994c538f7cacfd47734997053824460351362e80c6d97ba39bcb1e51d1d6ecede9f752db9b4f3cdc537c965aaceb8105",
+        ),
+        niels(
+            "43be5df3c7b3b8fd3f985bf59e9db21c76fda4885bf9d1233a695f0b30f5bd18ecee5063269a898217ff96b4f1541de4d21671b20e08b4d9",
+            "114b65fcf025d4b66f36e5c7345bcb0b24d9d745c039f49e1bc0bc4f7a2329f9fd3d4a4fde16b8fe70974b7411449ca89034ffb4991d30ed",
+            "9045d7afbcf66bc6dc5dadbf1eabf839deb876fbf085ae12afed0fc57ec2ea1374c7afc4cc29184594cd2ffc9568f4651c3bb5e27552e1dd",
+        ),
+        niels(
+            "3ac525ace0a74ed044abb942396df9311cb5fa60db261deb99277e6d5d10dc7e00800ab08808a14e5bb508a9e21f0bfcc6cef1972eefc299",
+            "3b5e0324b9b03864848517140bf1960d17b4ff1883e4de7587a969ca1e8b756494d876ca43c6bf7ef5411c9ea6f0c8206b1690539629e695",
+            "ef8ab51d7ea01e4e5400c282dda2cf001978c5d836c15548b31530caf62ec18ae8e0c2f5a02c404ad61fd25898028d0e4540337498f8533d",
+        ),
+        niels(
+            "bbc29879bf91881d500b7f05416184fd973c712dd22b18e08fcccc562c608be3aae522ef26a6e97d5ab69286a684e69f9adc0231d7d345c9",
+            "7390b643e8120ee0c7d33698f0681daf5092396b95845db0559120e4ea929ead162218d5164302ba8e6ec7bd092dbe85dc97b33e514611a9",
+            "924d7323d095f162c97542f502d7372648ea0320c995652935af584f1a7d2a9623607f3211529e1037332b333b265d2efc09a1e71c76ebe7",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "fc02a52626870c817515858a776e3c6c84c523899134db8e3f63ae9fa706fb2511e3c3d72b5464f272fb185215878b50c108a4f816817b33",
+            "a7df1292db59218b8593fc409494d2a6666e2504a1da359b5391462bc260c91e0c0b3a8500dc7b9583401f6f97ada8a286a62ef97374e084",
+            "0745f0c55c3c80efb4bf66bb1a854fa9beb6a9f8c08605c697101a15c9d9e421e3f64a3d106ed2690bf1a40e3a0d513228cae9487ef1c782",
+        ),
+        niels(
+            "956623482635a41baaf2a1fc9994dd1b8cada0655f37036917968a4a038214a9910eb22f77c15fad86abcd43706326bb2c8cc79ebe8e22bc",
+            "1b0943f923a65553fdd16689256fdf5ff04a232155b18b2e2ba52fd96159c3e111bc287b96ff0042c26e43767bf894fde6d88d366964213f",
+            "745dc5110b377596ece198911bdaab195f303121113bc59387e211092feb33887c40c877323d1ad93be031fbd0ae3735c0ecc1b5613749f9",
+        ),
+        niels(
+            "8dc8e14c3690f211d6ef04465a0ca1d7b1a32a7d923961cead47fc38c316621b79cde6160275fdb95377d53ae61dcb5151769fb7363df8bb",
+            "b8a8f6bcf7a0bcaae88925ee50d9edb48ab698c9b3059c2b2cf96c4f70580082b517a5be69df669a09a61ceff891f916b16c734096207b33",
+            "23415eb590582c18a4d944179c327eed5a340eeea0b46146f5dab60193a238b1b1e92165b5bb2b0fa50c2d6502d43cd50acbada0e07295f0",
+        ),
+        niels(
+            "0698e06c44eebd6ba48bafdfbf389f0d9bca763055f45903f84c2507cb1a889964b4fa58d74023af96ac54ee554316129c6a13923f3c917c",
+            "beed6274d2bcb4c93d2e63f191c5e650db8bbac11650bb801644522a9e26f7136a58ac43fdb8e9a71edc3dd7fa1099336c34fdee3e50551d",
+            "0e2c9b3cb2b7e69813d71af62f9bddcafc3677b9d63fd3d730c4cd798257929dc5c3743d6d397465ac1701065d0d5279bf10c3d7798851a5",
+        ),
+        niels(
+            "603c5cee525fc0ed5be5c2ef0323615f3762e25204ba44ed9166319c4f436bd6dedd464bf20929a191953a054e204c9a65f084bb50107c4f",
+            "c6a43c60cfe9b58fc63f1d3198b1465364421dcd1187e67610893f247e8bb79f8deb6ee5579da9a483da67ae702879e436b16520cbb1cae0",
+            "7e0ec6d1dcc82b2cd1a4c1ffe01245d4d38e6ad2fc4c50ac5fcab149404c7a62c589ee6c9cfb1ef3e6a86c7cc386cbd0673146dcc8af402f",
+        ),
+        niels(
+            "bfc5e30a39f709c11c22cda6fa241f0ae925b6b19145a35083359fb09477fddd15202e9036d6e4ad03722ffdaf84a4ad77d17ae261ca4712",
+            "da94a9e842ea4559c58f1b90412b680fa67069d008fd4145ad1cb47d202e727d8ca5c3a2f1f6a6e5959a62fcb882391d24c34b1a0a12d1dc",
+            "033653232d66cc84e7ba81cac6f0f3cb5dd1560f5cef7c1aa5d16f41b3af9a307e7217d783156a6b52bd5e71d6041beb16caee8a8c628ce1",
+        ),
+        niels(
+            "fe2527a1bb55a0cb7bb2ccaf24ecdecca5a4231cc28ae3f6a4d409c76eda0cd08263b19b36b41c0f97d54cdc50296dbca0f9cc2981fb3f90",
+            "bbcf098f056b9d02a7e5e712c5d6d662b49b35f3bd9e45873116d8ea5e6ba43a8d1d3238da115f81f0bfd9d149682ca64f08e3a4396d689a",
+            "1b1d99a0db3e6feee794477ce0e40c2b8e807b190b394e2fcb0b5726bc06050cf84fd51fd08412d96618b9408a3365e4542c5632ff23b796",
+        ),
+        niels(
+            "daf498053039d0ce2c0e426fcd5d9a0302f5f11392d6c7c9371735b7ae543359f7831fdfc628a775615c88c7d2dbad5bc943fbaadc458e75",
+            "8c0e07806b6004dce08acb26c3833f8dfa01281f5c4e606805ae78b89fb66fcaa7557c75ec7daacdd4e146c455555a537e3e6afc259ef475",
+            "33fa5cfe644f4720bdc734aadbfce12da325bae09241b685be071f0341253a96af046fb75b105e52570ae73230d3716f074c3c5ede24e24f",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "72e2ee6e7b20e1ada9138c4ca128f6f2d0abae3177f5c22e4d7d8f53328aa12bf4998e885df5a73e9dffd67c86d0f55d9dcb98252ee46fc6",
+            "1c0580ba1414f88c34530eb5ad6196a6c365d6807aa1930dfaa9041e51d2b619e33680c13e7435878d476a8a8f359dac6dbdcc40d963a09d",
+            "70fd24bdc1e8301beeed45d11be22d981b46eec0bd1695c2ca9cc5ed5102ea962d60e4048c394656d8ddff0c2d0f0b01ebb22e93b3275ee6",
+        ),
+        niels(
+            "972f980f914e5222b47595f8e745105a2182c69e1e73bfde83f85c52960a05931e1c1446961922668922d746b2fcf2e38e3b742d5189655e",
+            "582f9b264038b5bdc66438f4f2b668ba887abaa692d630fec6f47b8a942e420fb8b09198104c1d402e5e3457adf5bb9d9c6379dbc48f32eb",
+            "2e4166095369f8aa257b1c49a9d4a80230783fe83b0cb028b5a0f4ad74e42303f1319333eff08d1c51213633886dba471b46a664a823b097",
+        ),
+        niels(
+            "97e13c8829d855a6ce5c15a57b9ee6414d8924c3a3300ecf7a0c5d4ca8e7b51b965c81ad04bb55fa1943ebe83529b0228790c615d8b6d204",
+            "3fcf1b7ff09a1c63efdbd6a82ca34b2038d3956f83f65a9937f7ddfa38ef7599104a660745dc49eeabae80b9acf18fc4f4624df46455354c",
+            "8f67d699c9917bccff0f437521b48d774aab8f8a7ae84a4921b636162e54bec64621f120f33edd29fae5471e3f343c0d1cdbb73582e242de",
+        ),
+        niels(
+            "98a59e06f89f77cbb292a331d0ac7d9606fce645392e749202fc7ac170ffd7efc4dcbfd0c6dcccb2917dd129d7cebf8e0d7b8aa65df9cb6e",
+            "6c01e1c30a2070a5cc3608b94c060080eddaa84621bc89f9ba7029adc74769172035442a8927e42fe05729e5ac596615af6281d7103ef48d",
+            "888ca4f9afe25353f0813a6e6cdf1e6ce6411bd8fdbb4fbfbe8a7c51d2deacd035eec41218bcf0a8bab9b80d50b1447eef4b928e4399692a",
+        ),
+        niels(
+            "aa9aebe6e5e144c39f200a1bf1e394743f5ca95e710ad961129ae1bba2cfb3f7a6058fd88df2544802430957eeb04f3c376fd531008841ce",
+            "c03be99d43621a4568e5d3b7747332e951054338344880aa40b8d2077ced916f63dd7e9324e5c39421bb8e53173475ffea4c33490ba4d662",
+            "1f34c37b287cbba3c1f7c0db577d89604b576e97dce9b63b649c043dc1451d56b7cdb1df54a7b193ee2751c80c941f70184ea11ffdd2be28",
+        ),
+        niels(
+            "7d25baa02166ec3cce3267d1837f06611f41ef7376541a5fc6f5d01693472e8a424558517d8cae8a0544f5c3474da2742660c7009abcc98f",
+            "d5cb97015dbf62faefd9b12f2b8189921bbdb3875bedf7fe0d5e45e7baf6ca7b60b179044f0e0f9dbd3376b2763448fff37c0ea1814f0259",
+            "738162489b491341feef644c60c1bcff27f39b6cd70db5af532bbcd31377eef398431aae2fc14962d77163c9268837b734e2c2aacdfb2e4b",
+        ),
+        niels(
+            "cfe9f0725729df73fa678d8bd8f71d43fb18b42ddebf0c0cac731fa85bf18ea20234b70ae61a9ccd089b09720e62a7c63e61eea74ac3c83b",
+            "819bbda6a182d83c0dcdb58a9510d920c1d05fe5f77c01c0c8a58b8d2fb31472f2a9bd239084636dcb90fcef3c60eb69e98bbbe73b625da8",
+            "e8d63bda5ad70ca37713389efed4642b6530a0bded0712acf1770b50036216d914a71bb1e0aa36816aef61d0c55c5538b3ade806006be97b",
+        ),
+        niels(
+            "1740dd0ce2a55b873903d08a70f456dd035060629eff7ce82a7b82cf089a65fa5e196abdd24743eb12f43ad9a278acc00bf4efc8902c3828",
+            "48784222f7760e800ec825057ac5ff222ac66580ff9e35d509dae62e2be99abf6a80421ef185575a34deb798d136788c7b2d1e18e8f55251",
+            "4bd3b5b2abaa8b40cf99ce4d15802502436ed10395c5750fe630e0c281993e51ed595a6fb7ccc07f3caab66ad79797dbb86730ec338ff4d6",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "3b7eab1022b6969411c51a8337c76b141a89fc5083453874497b0462ab8822ffa2a8d7017ab79fb8696876d7defa547d8e00f88c7ba3306a",
+            "03829b30c84d36952ca0ba6f7318157b7a8a8a83aa6b26659833054867cf3c05249160a9cde7dd7e6c8d83525fa91140fcc55aa3d47df254",
+            "31846eb2c4a4c51437cdeb54f622b010dafd8b4c6678fde80bfdf9ad0bee12f982004b2ca47cd0946bc7d2deb730c01331b27e19dd02c169",
+        ),
+        niels(
+            "fe21972f6328ec0c928189aa5a286c45faea928027f584cd7b4ac3275cfb9b45b02b053166cfdb591ffe1e40011ba92b45d74adbea08b4c9",
+            "67afa448a0e8767ff73d12b7b33ff36791b91c2a9c2663c98352ae9e2a3ff498ebfa89aa886f887dbe39f1b880e037e105bf7d8e512c1c82",
+            "b9857502ece0c293b04badd9508c25db25e6fdcf99cd31c1a58ee56a393aec19159f7eba2a9e9f00be47e115993973348eaa27e3e15489a8",
+        ),
+        niels(
+            "d68beceb25c116dfe8c7726e03dd138a47fa3da72e896a782fb999a1980e214ed8143c3d97836f0e15d0ecdc0228e6750237eb48abf7a4bc",
+            "1cc9607bca16913c09bc71d65969fdaae92433aa40a40aed92da340d6677c9491b5500ef5286c3e0cbf5331f608fed044ae00b7b50407459",
+            "547a695a35ae2150c2388dc878a4a6bf51fb9bc9ea21a4355fca396fbaf0efafa1cafb97fa478b1cf019e2159032eae0a5c6ca44cb72731c",
+        ),
+        niels(
+            "259ac3017140774f7ec74a1f46da8a52d4dcea72f76114c40c958ce79805024003435f393e7c35339985b4f0c2d39221bedb3f8331258a78",
+            "185513cb341d882ec9c2028d7189171fbd649bb2f1e852d3b8454f812d9c22e4e5b4ce1cc12d1b950357ea3942007b7582ae7e558be0e767",
+            "4d1ab435a344b8e4018135cc4eb90d1988865471162dea5b6bc9149973496cdcbbe30f7c56848511ebfdfca71834c7d0bea0226e03e93127",
+        ),
+        niels(
+            "5e4731e609d91c1e712d1b7e3a2739de047cfa3aff00d57c65a764915a77157bad0e4d954198feba1f69dea767047c6dfd895d504fe0b0d3",
+            "49cc3d4452a1e4498b05e2b4b50f95170c03ca2bb0a0c99d8fea342d506ca23d4980c804afb8e2fd02fd4830aa0b43ec66e233301522144e",
+            "3d38caa54c321265791478b2f7a638797bab6f9860348c1d3ed67c6cdd8e15d20ee33d3901a088ce3cc826e31dc619d24d75d42d8a2bb71c",
+        ),
+        niels(
+            "cfe00f823b0c15c79688e69b6294a74a524f0b4e51a5246320c5753736360ce72fa4c886fd1f733c963bc92a7c5440357c694b7fb0f840d5",
+            "26ce724019f77e12d127efbf2bec5942929a921d97a1a4012c3a0539412c23850befcb6762a68da7e496d656eade650bc66e7b1d9438426a",
+            "b09b9b9cdc78e14bd64baa5f6b009e19b3402b7ec27acf313475bb4458d50e2df886976a7e56e899ed5b710e857b4d80e3c80597d447752d",
+        ),
+        niels(
+            "74016fb4aa0709565e0a27f96ba7354be012b4ff88b6c7453ea533d83f94a92ddc4bc18d23120bbbc344128508f68894fb2089dc8d8f4ff7",
+            "c42cd159afb5de4bb6aa31f4d3377cf3186b7de7061bab4587604e94a56e1266288075b3b30fc299849beb648da9de2e5d7d284d0a630235",
+            "49d379d71e8664b9b91e8255a83d7226b5a8d92c7fede8c512cac5262d0f6d5ea3e820d3b780c7ed585ff9018c8c90568a1e48802d5dfe57",
+        ),
+        niels(
+            "06b951d5e9c1a9b1cf25b58478db9e6b6d80849c208e05f86342dfac861d5af24c4ffa916d9cfa75b3d3184038433b136acb077ed31c50bd",
+            "51a244686065a831d1fdcfd7e72e8b6908586faa3bdfb27adf5f21172916bdb7e299a7342a6484b22b6ecff55718527bdfc8c7f4f412b442",
+            "ac5e1a9877efd4048c5b1b0e32f3943ab0cca5910a0c0f2ef7438d8234043355894e156fabe75dc1775ddcadb48bf1bae6a0f22278d2c5e1",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "aba2035b1d41c46232d195bfe3a546db99f1decd60e1fb29ae07191008c05a4867da53b0e395064b0f448733600e8dcdb57d56c126f9e9dc",
+            "d31dae91916f9f639eefbdc57ac51560c13e7624094bbc87a0bd01578d09420159a48490e541723d57e87c0224326b956e684e1b247ae083",
+            "2d7756a9844c99e62a694617dd399eebbd99a99eb7ad74a56c24543ad1160210c0ff999018eb16a476f0943f176663073f2b80820edde38b",
+        ),
+        niels(
+            "6c9ed7d975ad28c393eed3524a3f1ca9c1f438f4858d741ca45b48d5008f879709827713affabb729fea1194c31632df70066812dec3ab52",
+            "481ff779f4c6c017a47b8c825ca91ac85e618b29428ccb4ff88cbd43891ea783e7588305befbf6c28e09d1cd867bd6990d1a2f9b488e2d1b",
+            "b00ded552fcbc618885dc86cc9f2dd40605e32f8d176ec23269bbbe3923b39181b1112d31a70eef6be0917553965564ba61bce732763ab3d",
+        ),
+        niels(
+            "74f6c578d42c09f8d222ea892fe3e3668be5b0fa255d232725857463faa1565d542a68dff15098243487be70105d6cce6e5e4ddeda530909",
+            "200bac1211d01a10d11c127f3c88ba6f8d39650b869451841e27b36564be07d70c371b2829051b80b0bd51e0ecea68fd0cce96e00d4cc3e5",
+            "80237d53527e05df5d6d63dd32e4c0f31512e03f08c94e679d072042e05b82f4136d05d6a7158e209731ebc9099ec43bd6569c8cc46d027d",
+        ),
+        niels(
+            "e15b29f5167be1170737d1009e0b39b4c9560630d267b7dbb1e4eb34c9e85b2bcb90fc80704889251bebc038b9eb75affe2963d290f0b1ed",
+            "6e104485ffe4c241aeec73b0ace5743ce5edd5de9f94287816f56b30696d9aa729e5f6ba4dc2ccb6ea29f69d2a9e4d9c26df1aea8c5b3cec",
+            "782c721ed4ec93428b8ab3d2e5c954a61fee2ff8e35099e27d8053e98c1ad3dac212d89db9a1db2d7f869ed178028865785e226b798ea955",
+        ),
+        niels(
+            "3991e22cf220c7c6a482c5b8e9bb52356073a5f1526c6e38e05cb09ae68ae0a902050caa257e939ec5b66e4a664263def364b4611b129092",
+            "2a87fcd014be71c6330ba67b4171a6821f7d1221ddd39389bbd9c340f80d16ff4174bfe0c2b6171941019d185669bf80049b7e3f55cf6947",
+            "a893dde44553470a5bda3839258083d8835a1848714794fe2d97914ea512d6d2852747d86c36a373d1a9ae013b76758fe0a61ea42ed90f3a",
+        ),
+        niels(
+            "2e16d1af58ffa15e6b370e2adbb86434ef1664e6939a77a3c7d3fac69ceb4aa977d1d3989be4e9cd46751e1b5b28be494c56e07ce0a1262b",
+            "f41cd895c4c31d713ef8890342f4e924c5918fbfcc46a3ee0be913b78457fbf31244f5f4dae182e1785baf19e54e1a983fe423194a91a721",
+            "c587bc67334f0e3996b65c853e7444b539f105fa3187feea50347381bf1f4a46e3f10ff11d3c0841d8b0b811cd5d2d5c4c58215cac039fb1",
+        ),
+        niels(
+            "3ce90ae147f249675b43097475d98dd27673222382b45ac5445c8130f19418b64aa6d586454f8c2301ec9677ad7c5196cbb686a5ebc5e499",
+            "8cc2265a1c04af5da3d0042c10284e942b953d8c408ca92690541ff875afb60c14f0ac34f753177712c22a448d135c7583152f495bfbc185",
+            "d17b89966d1b559e5853c0cd28a4c809ad99401226aa6ba42d0acd5c0fa6ec4f6206ebd3729da763a3b42d8e2f7cbf26364072a3c8ca3808",
+        ),
+        niels(
+            "d8ef28f4166f22bb32a118600396f81bd62477c0d1af5326a3123234e245a29df6403e8746fb56a735b51924cffecc50f5dd8e7fb911c454",
+            "ed440a683c2e436ab002c43a8140424b0484bb2ad349ea76f2eb4bea4a063ac9e070c710cbd1cf415592759a4e425c4cc65eb1b1cb4470be",
+            "072a0d20232b8432252cd710bdce454f6be5c650c86b62c3f50038dc373ee5b796140edb0a9f70da1dc71dcaba175d7babcd431bb9a23edc",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "06b9bfa86c6e9c00fcc099e3c683adf65f2a12c3331e99f5744fa4555dc51b5e43a964795da9507cc3b78f24c4ce6e9b4d9a4e72fc8f9abb",
+            "a529af4e78a90c70229ac49694585416cdf18977f858e3ffca30dfd4313617d2aff4ea284cbf46a5158d55091337a46089d478143eec0854",
+            "0ad17b0668a7a91548bc8ec757aef7bce2128748eb6bc1510da86b860909027a37af5b33d570666006f2ac9684f9975e4c65a283105ac362",
+        ),
+        niels(
+            "206dc6ad9083cc4b1ea47cb7ed4e56a9bd08935292c232890752a8b8b614c3b2defd12d3a78c9526e29bd0c5ef4239481cf7bb323bb116a0",
+            "129f0390875616ef8e91a1da30c4dc5a54698d57efee162abe3af5970a78c8f8eea7951691600be962f2304dd88170f717af2292bd493223",
+            "7b3abb0841e9c13911aa266ac0bacc2671a5fff87741d47ce40bb282c4383639077a76249654953dc9e8356bb8753ba6bec4b4d9d6e81d28",
+        ),
+        niels(
+            "9ad0dfe5272e571e9c40a2d65b1e687c2254772e74119df750c2900d27f520ae9a6dbd50c9d2ee224c848277e5f1605ff1037d3d72ef8268",
+            "09ac44a7d26d7a5404329c394aa1f21ff311a20dd1ecc29d73b45616f3cb572b1c7ad837f7fa498abb050e3edfada8f5bd6c593067086bca",
+            "e2326092431bcbc48dddf07e9ca95d055af3b53a0085c3ea733fb0db98c42d46410bde58a66d95e83379c4a99deb937123c3bc50ec342117",
+        ),
+        niels(
+            "35d5908e7ac990bba505847cd2ffac79b49ce4b6e014306a46887871c2f99d5aefe3e895b5d1b209d797d11418119ebd7aa05fc7af83e07d",
+            "cc8db386d19a203c93496b9dd7ce27c1f08e90d704d84c81e89d5bbeca384442a3c4433cb7432ef5d35181cf94ffd0ba6f70f29b20f9a9a3",
+            "dfdafc746325d53443106d8f1a1037bb6152c6d93186d218504be4f9f3ef71627aa85c83d938fbb5dba5a42ed8668ba37b0f82aecb4d5f24",
+        ),
+        niels(
+            "af7273e0621d83da9919884b6440398ce16b24f453d9ad41a4d6727fa1e7fea2ed199dc0ea8afccffcc6db3b31395681ea94e571ac12fefc",
+            "be3ca9958a4d219aaf433539932020a13a8e6ecbecc7913633cc4de5bfd692551b8a69a81afe38dd375bf5389c83916136a4c993b1b241d0",
+            "a9785896b12a43ac1688ac0678d13348ed2f5bb7a5ad30347fdcc53e507f8d5d6936789353c7fcac13cdb441140a64bba5c1ad13e7943cd6",
+        ),
+        niels(
+            "c6c820259cd44f58bace1c5d111df1f29f82604a9426737ed11205c2bd888cfd4fa44c82415683e774b855967739d5457fe175d8f935a614",
+            "d69dd5f17b39583b3c49e90909618a290a0129c1aa1209e18ded850d62238bd14bd66bf10183255a23f783dea6d6fe58d2808844613d0330",
+            "5205c58173acca7a34ce4c91eeafc2d33a55264e85ec1f6a63079cefa914a878bd4e68af1adfc57b0bb18d660c603626981a6dfcc07fbc8d",
+        ),
+        niels(
+            "6b7c2e6a141139aad7753df21741050e78a1a8468999e906fecce3a23825af06e6341e71040e98df59d96d8bc7ab23db498698f63abaacd0",
+            "ab33da171ff2dee378ccedcda3d820fffbe10f2700ca690a70be4f32cc0324be62f2fc9ea0865e067223e56068cc600003608b4965ac5f6c",
+            "1e4e5fce84880b906aca192a48128956966f91bc2a37334d3dd6ec86beb0624d97ee16c8ffb43aafb0f0f4ca1673edd820a09a8162f5164c",
+        ),
+        niels(
+            "245d2c58bcd785fbc05badbcf5cbe5f16acac1b362f1f77333348b91f04ec8bfda8816585a041be3c287a7f4d3cce4ee485c7fa9cf6ae8b8",
+            "7f2ae0135f964176d267ae53d2bfc7752e5614630a74f4142ea4f22d1b14c3e568bceb9ff711ed277c84b894dc65ba380629689304078096",
+            "23a73417a0030ef278923974a4910cbfa1f15de54e6b787c4ab9c71809b7d24a5e44e94d64716705ee83daafd8453eb005306984e7948f9b",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "104ec14f3db34a5753f64f1bf872db17095e7051420060de64c19ca26917bb26e3068501bac35524a19a4e0986bccc5bbd2eaebd2a590fbc",
+            "c8f0703eae1c794a0bad04228f82dbe45afdd903ea7c542991e639fd03f0681210adc1976f884380c609b2db2d2e293b1d60dd9dbf8243ca",
+            "b5ba13b4160174ac9dfa1d91f48b1eb2bc5f27d7c06b6c1fa79600702402234914989e9c333701d1c5826e7fdeb651b339912c30021be33b",
+        ),
+        niels(
+            "dae441841baa875e7eb964caf4440ac46d8b223ff2e2df9fd98c5b7c2eb9b157966c960e86f64e9743827ac1756f277b4be25366b131456b",
+            "2dd9d5fd3e6293322010bc38d5475915b249647622435573083f0741f1cc886e58dde37e92c60be4d83e9603762f83671e07b02882b90659",
+            "6589ac9c9644d8e9ef1136f97fc5b3390d19f3c2189ec8432ce6e5ec6f07bbaf7fe26fea8a28e2b1bb04b3d056cec7158fddcfd4b9e62873",
+        ),
+        niels(
+            "2edc67f91c06d57ad9144c741a4377b3ccec0bd1aa4e233766b6b5f71ae03f30a6a4cbd93c42e3ff1807fcb5e38c2a0e3661c2b1eabb96ff",
+            "ac4c4afb000d8020803ebdb88c6a8ccc9a0ab8c32379a798aa4de8d2270188110dc74a1530ffe6215f335a42df3a432649184e56a9fcce26",
+            "bfdadb4bddcb2d09f40d7c7da23be986652266c754ce9def5a453a1f4370d61ee22db44fb48be5eb61a34cf90e4cd2c5134e3c34ef939f16",
+        ),
+        niels(
+            "31b2687c02e38f22366d077f70df5deee498e832f0ea2491196994116a7c89d555a749d4b01fcd46cd49808ef2d0dcf054495bad52365ce7",
+            "b6a0283b40e2159266d5b30004c48cc8c680096753c0c50ab3300eff111ab14a11a330a38620fc10cfcf29ac9f1c8bb7649c1d80a8f6e978",
+            "d04d24f1a4e1555462efe195ca1cabd189e5490916af9b4d5612d665b07ca5632f1044c77e2463df7346bfc8d40e4d33d4ebf8e46ac39ed7",
+        ),
+        niels(
+            "9a69749eaefa837f7f6fe8b2778e7c87c69d9a44982d326ae9a525218d738a1423543c4e163b76d928a4ce28b767e3513c07daec72b7bb5e",
+            "3d28ef9bbe90b6c60f5d6e5fbb7512f68c1d86a1354800502c5685e6a1ce0705764aa0e733ad48cb02acc8a280b21a97393ad8e459703197",
+            "f43d93c4c734589fdff6e5f8847e5aeb6c6d48861d9c54d0cc1917ec3d3c11f51fbd3e2f4bbf4d203c941b084027bac45649484e83a523d2",
+        ),
+        niels(
+            "e4b9eb18137f77fb73fc1ebb70d080e71e163c3420884932928d63e7197aa59ef4c40aca1ac0a56ed60b81eeceb82969aa4a3cbe225cf66a",
+            "b25e93f70d58b38350ba59b7219e6fef13caddff72ef88c578199dcb3ab3f820f3c93fa17c610e58ceb405166f99884a0c7854124ce1a2e3",
+            "ea28ad8ccba7baffeef501453c28834e16ce45217e0483ad11a086356ca50f8effb874aaee4c53b5ed8636fd0ec15412ef0cc9390c858fde",
+        ),
+        niels(
+            "14c68258d2e55a08230f4092dcf697095b2c31915b34c4fa0967227ee550cb947181b6be1bfe1b10954c1c3290f08614ea6ed3411d753c57",
+            "8d03cfc6abd9fac711b660f8df14cb8bc512d93297efb7ff34235a2c4a10d79bc46f280b5d517d4c38b676c3648dae86e260546515b000b9",
+            "89c3a0c45b17acdc7b34f027041375698096d975fbe4be5eed824cb67d3a21b5b169d2757cd6302e383d3ec3d24a1eb74ccee6e386c3f84f",
+        ),
+        niels(
+            "1a80ad1f11ad48da6b3635e600592f997cb2d591568340dced05c59b99fda413af625290a7ec7f4fb95a83c245b3e96d05446a6edb7eac90",
+            "b32e4afec38af686b4d45631e48fdff00fb2884c9c003a7252b2ac55c319490c8a97de1e70dbdcde8a72defcab6cbdb211f9977f05799293",
+            "6e1cc8ba10d9ca5a4d5c06ba3ef5832b8a759024dbd5f2f349f6f752834ba1814e62ddefca1372c258ef944778c78926305f4edb45fb35f0",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "a5c4a8c4ad2427d7cc65c3da4afd84fa4bd21bd2f282c1aea2c57d1f878aaad80094d49f8e30d6bb42dfbd435ed18ba664d2f108eb96c45e",
+            "c40e5357600350a0ea8609a5f260552ee7c0353033c40db0cd601f3320b8d9476fbea9f311cd51d0136bedada11d7001db3002c214cc4250",
+            "e76ca0f2d7b3f769e9cc5fbdfecefe817ec53027bbba2467a42d15deb8faa591fb8709f1ea138dc6b46bee0681897549c2a5220bf3f99119",
+        ),
+        niels(
+            "fb28c5578da3784b6a7507555fd00b6bd1259608ff2c4d1cc3cc7359d31a7039887006f4be1f645edb872a7d326e1a83461285df66a4e0d2",
+            "9160c26d07b08a00e34eaa4121524ea8b53febf68dab7015bd5030ab673851fd984cb41651b30d3ef06d93c4886208c2c1ab3ce4e8445a5d",
+            "70c78b16828132984800d2cef0f36c69dc6cfe5801b267486ce320e39ce9345a0be91a1a440431c0bb3c8d40a17bd7c75df0ecd4dd87e89c",
+        ),
+        niels(
+            "849fba48703d2b627b263f1529a62e7abed6fa617b20f44cf5d41ae89bdd47c9eea920a56afed6ecadd454371fae687763e38cc0c6279b36",
+            "3148659b2c8b51136d6fb8d00f6ee3afec713a2719820cdadfda6173eaaff722ec5d2a4f8a2af001500ea0b25af09c88c92fccb524e9b381",
+            "e51ad3bb0173df6dc35f57d6dd87039d28773bef57ea85cdaf4d3bb661ec55748c43392520173ee80b44ea2180893b2c0f5b788b42224471",
+        ),
+        niels(
+            "4cf9d88d58322ce91de1c7a0ac277f19b4c98797a431e2894db7db28b39a9b9207ce44a60998537bf451e4318a4edbff4a6cb9dea86bedbb",
+            "c9bdf38cd813efb6d85de80bc7ac5dfbc0b3ea2091c0c622258e2237ea5b0a1ac30f414f441d0cfcc1f96ab0f0a181fae8127b623e6b5873",
+            "b6be5d3b0812ca15c78bb42a323306175afc2f50e41e42a7c5a9eab893700bb5a369fc22d4ccc603bdc2bec4638e803bdd39c8128498e74f",
+        ),
+        niels(
+            "b5f67ba79b86eb8e8b0a9d409bfc5c9b5f60a33021f15effc9114300743028627aad0cbdd4af50b42bb63506a802850766be2feab98716c3",
+            "068a057194a25af28aa7291c436ff77604c3ad071b32afa3d07a3f003e69e2846d45b60d865131345d3e480ad6b4f08df613d6357d4e848d",
+            "631c80cfb0864c1dd91c56cfdf831db14c5c311713a055503c21dc9d5634a27dad63182f575373dd2e4e2b36410e8b3479e57075083f314a",
+        ),
+        niels(
+            "caff6c700b0e79567cd33437d99f78a5d9506dc01f48660fe5bd192a7cc5d3afdc7111798dd8d39ca597d2df793927f65a891655e4585d65",
+            "f4869035d5045c3d2dcb5cafbdbfb93a54a7c17e782820bed31417ca1390d336ed524bb93e653d59a8ebf713435d461aa052618363752493",
+            "59809897c648795a1b0e3806e6bce89388811eb0c9a04fcadbf38e534abb456fdddb125d426165c09851d62368d10f5dfa521a9b011153f0",
+        ),
+        niels(
+            "9d2a86aedafa6600fc3fea8512a971fbbf605a7041f2f854f6fe2af21ceea65eddfdcc3cc7b32608fd01523e781a7bb5c6cd4b2440a9ec77",
+            "f788f9fa48099f2a8b9871d2439d842944358edd806d7be2ad82b6724262dba652a058eee585608e6e7a77e42c1322c111aa52ad7d244d8d",
+            "2e1f7ef55fee993fca06e878952bb00b54bc17dbb8d558a1557f27c5ad94bfcf83550dd780b0d14dc0dca5494683ee4bfee83dbb202d8330",
+        ),
+        niels(
+            "dcf1dc6de329137c8c36dce9a26be89e2f15cc940f88a943e3df4588a032becb1fb3e2dd111cb7b101a3d8c21279ce1ded623718ac81ad30",
+            "a7446123f61c2dc8685f0d9d9a35aa6259cdfe036421e3cce463b5f4f590604690ff7a5620056141080eee0415d3dc95e26f9ad60792576c",
+            "02f2705a45bb7dee00818d977ff545a27c7b6e072cbf5337261b2b93432c37210af7e30d1fa65940c58898d73e7c9725a768ca30e4a75039",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "0a42618aae99c22036a17226b03abe24d062fc26bae274f095821da13920eff2b1d05649106ecb697f798e18593c49ec8746cb910fed7ff4",
+            "4b3e5d50a649bc4f811b39bb0d073b3cdfd15e11b862ea23147908a920df488f8a29316209b82db5ffffa7b2ca34f5b8e19e4d37789010b0",
+            "6280357d896fd868af23dc62fb220ae5ae3fae339a10f79e521f8f776dad8c9a0707d5651d57a4f4df3aa638d5fddc30550ab83d160e3740",
+        ),
+        niels(
+            "4aa57cd68495e181342e76901e50e7a62123a59eef03c5504167c49a43ed0448d4b0ec4695bb43f26f98bd3722a010a0a3ccba8c1b81407d",
+            "1ad10af588103954c1b9bafd796c5b1a29968da0da10a68ddb512abcbb4207f5f08d9a28066cbb2e333770227ff5b4590a6e31f382b33773",
+            "cf34baf4bff23b0f81ea1f6665096d37bb14b201d73b4434d848ca1d51d60bd0d878b821a075c89f747565331b2f3a149b2ddb8bab673b7f",
+        ),
+        niels(
+            "71a16df4d09c8498e036bfbbcc220be42b03ebcf3c377d53cd2d699b3d7a0f37714614d6d18717e463898b262f8a88aea30127be5d83e6d6",
+            "b04ef2656ce19e7637d2b9bd9e0b59f2bfc2190d3a4631cd5d96a1ffdbca8984d86256be07746663c790239f3dc39444f843381a3050b693",
+            "7b7f3994cd163037d67788ab0ea82a73131a13f382465e257f0f64cb33a482374d506f73182e50280af34a052117fee0f7de52ad47c74796",
+        ),
+        niels(
+            "8c0a3a0a86385a6b73db19aff27903b3e30bbb96f87b3992c65cc7fba41bbda8d68f788fc944bba90219b343e84a0d46cc8a9e89ac4677bf",
+            "fdb32a374fdfeec195784d17248eb7b85b10b6f18f4849c069525d88375087cf829671c8380a5f570d5a12e53cc9db130e1d7c09d41feb41",
+            "46c4676f57fd18dbacf4997e9b7adb1a88415d5eedbe14294a0345a51aba5bfbf3609b78e341fe7e3a26f384071fcb09f6bcfc6faa3430fe",
+        ),
+        niels(
+            "31650d2282a58a80f61244fcbab8fd12182de234135bc79e636050082cfaa8868c1a9e0b8749f8768b6f07aba2f170f48f03c8b5249bbb1c",
+            "0f1e6e111c8bc499d36469e0a8c807950684ffee4e0793a10ab30d61f505ddcabc1adc9faaadea1794e53512ef0fc4b448da49a85c389fc5",
+            "0757c03dba27fe2de86832902b7e29602566b52466b20519550b623fac0b8f9b9d235d3fdf13be5b749dd533c33b611a7c6b625068946f81",
+        ),
+        niels(
+            "3a989d25744824c1784bf3fd98264164ba40c362232e12893a4b640cc5314aab771725473bee8a4ae10bf3804f5ece650a8564f20364d51d",
+            "2c87eea18826093f6da5b195518da09c56724d39cfccc2690073b7703ebeb20b63c7b658f7f419a173c037af503cc110337d26b033a5d703",
+            "b76819beafc7df190a3437e30969551c87a3bc5b8805cba6aaa53c31e2767cac75a1aa603dc98e35d2a73e73ec72a19a7315640e11dee6eb",
+        ),
+        niels(
+            "1ded2dfb369dd95c892885ec4a4a8c3fc5dca817b9b670407601a29d432b4e66260b90bbad29376b0017f318466b9a733993f5d4f94fb837",
+            "8b244ccc8dec975d034df9585e99108e41c918370f3f1108db562d8e0a4ff76ae29c70611453be0c0fc0358f3802f6a2d2c1f7907745f888",
+            "585aeb8f4cfa022eccff21d0483ababf645ab2692a85ca8035ed1f58a33b5747275dea6f1c0a1d818e68c80e0d8aa377a02aac8d2d2b9e17",
+        ),
+        niels(
+            "d27e6f8f81835a31b6fcebb3fef129073c1fa9e8511c58b260d055f040cb09f423d0b8973a4dc89cd9690e337d54cbebef294c9c57ef05ed",
+            "b45a913030949f23b7c305f69509f591dcdf55c7b83cd45ef40897b48bc3834cd58a02165c8641a134070ef58a1e171d92858098814b9b89",
+            "9fd83d46f09e5d0f4b91f734368b799b913dcf22f5e95a9a95b100c71f792c1a0437bec4089eb4501b8aee9574a683070a9bb93af110415f",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "929b6f660e9c7df1744ccc5e0fbf8c57fdd84c9c994760766b18821b47f21da03bc91191701dcce82ae616c1b96793309000e594c7af2ae1",
+            "51d8bb5defa55d12a42a621561af891f88cbd0c54e140e336b6d47073bc0bae1f9bc600e8758495bfdc27435fd67907c491e49792f6e6e5a",
+            "e92ed3fc7ae3720352a0edd4a5a259670219f62f7a01ba81675fe6dbf261621926b5f1d0f16b5ec8fbe13cad19895999b49924b64a0e1a75",
+        ),
+        niels(
+            "8ec79bb47d3bfba2dcbd65b681fa374dde3d70c2bc2177bd417f8e3115e203e7214b5d7600fb834b03540427c0c1f1c4ca7523ea14920594",
+            "9216e8612d8338f14df34d8a5f2efba87a4bc8b7625878f4f229008afd89bdc41b494703f65d575ee971b7c7de1598db47d33abe3ddba27d",
+            "7e0b79d6068f5b82c1eef329fdfd5eb58385ae79b4e0d6803bd679a67c8bafbcf806133e87e1188434b3c4866ef83ee9c34fa21dc400ffc5",
+        ),
+        niels(
+            "62fc30595cff504621d83749a680f4df75d1cbab1bbaaa40146da04d331665ef97697b2211d9916126ec5e3ab2d32dba2367ddd943afd4a6",
+            "ec8a0f61c84d94ee075ac633760d0f3e56bed936a789aba6cf97236a3204d2f5601e78f45a5f0fe1979adb4871ce4b4c5b46ebbd1285fa83",
+            "39c1668a7bba2e4769e9eb2fd8dd1d9f41cebb2cf3d664c44803ae5c93800576db9197d1423c748882031b32afccb69c83fed4fbd58cc65c",
+        ),
+        niels(
+            "23ba5eba8a4da67321c80fab0245003ae8f8e84fe3c81d5d7e00aa0249289fbb7c419798fdeb02287db86dbbcc9dff10429f53c1edc646c9",
+            "f6ebe93298347864f2a1d123c838f7aa5ed2a2e23a6ee602244955f52138d62078129a3819ff357cb2c84d88f68bceba7e0cc8393dbcd2b2",
+            "91ffc5889ccdb1ac323b1f7031e3bd4828a9d36c36ff195f5cd48f3390be985432b271a312a2c49b9705127841066c1ecb1c9907d7daf62e",
+        ),
+        niels(
+            "da522c1bc78df248fcd2da5b1222bd4109cd5cfbfa9963dec650c73099f16747a5601792f71325ba6318f5aac3fbd390621fbceda20dbcd5",
+            "c06a925721098e1e309b709f40cdc32957199f85462d4f345aca7ca43910d4b6674dbb3e5be0862e576b714d999b0654ec007a0e52fb2cd0",
+            "fde1a3fb7255af3d1efb246824ba80907193740ed3e5d541aa480c49469a18a264436a4d3c542efa7a29596273d94910e57e04de195ed5e2",
+        ),
+        niels(
+            "f7d24de1d3905b17a763d186313617147ebace9eb7570d33037f76a033c4c9fcfe388d30b4327aade5ff611a52b7a26374210644cbbd35e2",
+            "a3f6030cb0a85f5b3510e4010b18eadc206f2fc1199fea2a1d7ab4e2bd096f9949fd6870094134497a9d4d5efa5972b24fa53b755a5f208f",
+            "b9463b6669154c69a6d909e9c3738a79c753ba42e99e01520b2a04c58619b0fb04b62cac8cf7923b7516154c5200d3221d69c59e5588beaa",
+        ),
+        niels(
+            "fe1ec2461ebb661f9bb8b8fcc3bced5488a25e9af6c9cbb878b8a6d363145c7c6fb7081cf689053b808f0543b4a0c291068bbade5454400e",
+            "bf86670a04d517a03b3f70fc794c77b195d4836a4e5df9a42012547c878cfc28ac792d9a6486f926a9147e79ea4c07e64746ea56fc21b033",
+            "7b029df491003af3bc0173e1b26ba11352efa7accecc699b8e697d2582c2cdad71f5270b8a29cde1f9f6c18d1af73037d123f75f4f5192dc",
+        ),
+        niels(
+            "aba0c0231c4160f4639ec73f4c05441c91d1cf77fff415bc7daec2e8631b0514398afdb6783040f703921fe77296b4a3de010becffb06a3a",
+            "01fae842468c2669a863dd980d33a11852fa548c17f3591fa2c42e5d29d8cdf3917a102272a1771105400034f17f2ac135862b07f0b10a4f",
+            "0bb029de46a314f2dab5acfc83997ab140608658514063b117f5eef7817c1ac3f84becf81de3b72704ea9ac32fdc775854a46fab5891e194",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "ed1418c2e14d8967bdf37a0374bdd25be9dd2c1f57a7e737da1badc0f7f0dc714d81dfc7c7bd9c96065a8d24d2b06ff30caf2d06f34cfa9a",
+            "3ac7e5b786869e25b8cbd69b64e2ad91242a7eb9b67b28cf66eb334fb56eb656153e30c6385964b6f33ba8873154316a3701827f66e10df5",
+            "d8ed5c9efd127a21cc9d51980c4257f7f4a63fa4bcb02bc04ebd6a22f2cc85c268208fa765b83ed97176104c1b3062a831ecb1820f1ce08e",
+        ),
+        niels(
+            "fa26a34b777365a410471f94eb347b71f4dba7b74c4d5c381193f1ef52d8678af4ba2b2c2f5ef13c947c46d164b608e1591315b8f7719a99",
+            "5fb3e5532b6478ee9e0a966808761223680dcd08678741afabfba849b9a87925d25c76df412fbda57a038649ec52521cd96f9e72e305fc97",
+            "5f2b54f7676fb5d900e8a5ca5ec2278203d984573976c99ce9bdf49f26c684296a1afd01edcaaf65d4081ee40c94b01c9f0e99dd6fc922fa",
+        ),
+        niels(
+            "59b2c4407531fa4524c7715d1c7b262eb7181dce721992a4984d84a3f14d29cace2e3fe7bf24c75e3ed9ee832fb1dff0d2d8a13e1a86a976",
+            "d4f80f6166b66b5da17750e79cc4e07d2a41dc6f8601c9693a8a7df1ff367c4afd20b3942850595ad56d0806b559dfaaef5566773b426d04",
+            "f853ce37bd732727f7c191822220505731bd02309e22e268d625e9cf0c9f94eca73bf7e7d8a827e52cd549c4fcce1ca37b24ed77ee443b64",
+        ),
+        niels(
+            "94228d867a5caf74bbbab25baaefa13b45b858c69065c23b0fc8a42942ace5ba33f626c949bfe4baf8fea0e93d629163210e146bcb1cae69",
+            "14fe3e0c12fef778f1e1eae63f9aaabf6a63a423a56c78ca4d48cb36ce95a572f0fc1b66bf5fcf9428a6ea3d9c6022042db157e1ab945a6b",
+            "b29535130e3f0d2a005eeb5fb7b7ece969fbabfd525fc79a5bad183e727ffeb0cd37e84aeac0171e550e7ac19cc0bd986071b5ab9e8c18aa",
+        ),
+        niels(
+            "157497b09ca577a4671dd57b2264fa25bc1676f4a0eaf56e41671993d4bff369496b04c4de04d545affd0758dbbfbd4468e5ae14a6d96d45",
+            "e8c57219a4ff43a7d7a50ed4a267cb461ff203aa16e6146bb02b5030cd22548714ad8e8253951621f01c21d52c92354067b6418db0322286",
+            "5a3d7850af9c2e2b6fef770e35119064da051fc2ef052ac0eaa12b6c57fd0040b7ac56646e6c8e1b5790d87b27ccd143df201dea7f393a94",
+        ),
+        niels(
+            "91e830f65ec663e872bba2362a6a2986b2675c9796257f47abad55af56804a556429d8348da9bc65fe54a449d20de040e6255ea40ff3f593",
+            "f03139e50701fa564f6212897f132a4f23bc408eb16398975f3e8a7a91e401fdc0112042bef5ce1e558d5ad004cca06715e3932bcaf319c5",
+            "8f0e6a01eaf8e385bcd50fc5e3912410b7aa734867cdd514954c9dac0bea115ff9ceeeabe100fef2fc7748e4056b60b5aacd469937029bb8",
+        ),
+        niels(
+            "86ad70427bf145520cbc295934c3afe004639a6c61e42969f68795a2d69e90ba06daa9774323f632a0f00fc0adac0efe353461cbdf234442",
+            "c87bbf71b562c38f448d8ba9fe199b1f4e7dbe52aef5fe169bd0d03c3a17d4f45e0eb0dd96b5a7b917fc9b76d655156a87b6dbe0394f520f",
+            "f843e5724eb9dd3bd2e10253f6326c92569090f4715b9a3f00d5253484b4853b74ebecc5ba10c1917e1ccbc8d3d303d759ba7468be6f868c",
+        ),
+        niels(
+            "a9a6b44406823953639d31ce662ad2355bac3206b3c06295095d4d42a1b4a3f32d1c588ff009068c6ce053abb6943854ad6b1b5fbe076cb3",
+            "e5bef068f4745968f542b119539abaaba3b0bb1519575572a7fccc22506679000a564bb34490525e42df6cfaccc2022ed24fbb086c2582e9",
+            "bf4cb33268145a57a266f5114cb24c5d03237e6a173ec4a3184cfef3495fdadea9171a9f272f3d1172c0d20739f90dcbae50d5b776a83686",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "8288f034cce66443812dc2d88616f440eae7abe0942457331feb39e142d9ef153d31b5cec7a7fdacd398e7b66ce8751449a04b2c36359c05",
+            "52453df076b7106f95ba5eaabeb5317ad6095abfd137f1f3d14bd8a8810356b30ee05f3acdaf6ff219294acb300ae26ba8ef89109e21ac26",
+            "f8e2a595e5e80b9effd882e740d819c9bde9cbb2e5e2cf0f9098cbb3539ca6e823f079e18b797c40356cccee1240138fe0e6135e8ccb8bba",
+        ),
+        niels(
+            "48e723b07715a3b3591341b2234aa60e3f62707608df089aed64d4102ce8b514bc445bb7260093a5ae8613974d7ae50a5dd2b8b1a31791cf",
+            "eee401faa87c462dd710d9cb9a93b59cac57864240c5b4c3b70b9cffb5b814a133d90a073670ba982527bc9f012c62649a49ec4560311b17",
+            "0f4fcefb2a1d04d165d649a7b1c752465d40b1257b49b28d78935b0655d3bcc6d13265b1bf94c1df415a30ec5c32cfca518c810b4f0b588d",
+        ),
+        niels(
+            "f49b690d2fca4ca6e981759074b485b4768540d3bb3c65b2ed43686ec9a751bdcb94325273b38eefce7678bd3248a709e31436c2e6b678bb",
+            "20138a83c06563d1aee88ebef8ecb4a26f484756ea16838f2a3ba050b337352a87afd2c126481d480083659c18df7cd55546c0a5592ceecf",
+            "a6ef014b292a22286ff02071dfddc076cd5bc21a91b38738b9dd320ce8dc5c6db64964c9a8340f37368764a9c0f16f1ef4e31155ee9c8b90",
+        ),
+        niels(
+            "57b7f9cb5e897e255c0a01395a3b9648ffcf736bfb3b1206d2126a3690908be86708894834b71cdf54add83e530488dadfb0b456076d286b",
+            "6e5784798492fecaf84146ba344dd9570842ee052ca52344534aa913ba4a1d869e0ef0f0e1cdc050905e338d55a0eddab9c4f4ee63ac97b0",
+            "e7fe4c69053d09a92e2658f9340a61eec73b1506e5ab8dc66a135908292ca34e95f2df47857c9986493fa699573f6aa8814fbbabe14e1a1b",
+        ),
+        niels(
+            "c943bf643651bcac60f9f3082551ba76b87081bdba65acecdf8e655e961966e366c1ddb0ef1ebabe4f2bae1c9e3b41b0452a99b22a0e76da",
+            "15ea3646c42b6475b2874cbe316cdfab729ead79b06fd6e7bca1287e4d4fa13a82559020572abe1fb55307499837535a8ac63f38d75a8849",
+            "f4eff65d1588d145f354b6447caf04775f7d5cd0c5bf500f0d7b8204caef06c2f793003d7595db9fa231567b376b3575784a690007e8a5c5",
+        ),
+        niels(
+            "2f4de684f10476bbadec98ca377f5cec587111c946c2314adcd65da4bc8750b5098bc44c854745cc95af8f45addf81cea7eb06d7508070f6",
+            "6bdcd39fed51ead0f2ded0e6ae5e2e89375037d126f0bdae6f647a4f9395f3c0be0918423b7fb7d75aac88a25a18d1af09c7ccc613da198d",
+            "0699a3481c07cabd30621b91536e446d27fe6bf41a8bf406c2389df2e3b462f7d2891531c7ece789fed254c7196285dc57eb2e2d8689be0d",
+        ),
+        niels(
+            "0f78701d8919bfd50cf7ec42d093030281506e5feef6ef985cae843ef0a4c16c2e4d3182ba471ee74014992d844d5a2561b917007c80bf32",
+            "86e87533a2e21f1883ffad173b188d79729a04be6b93fb6d0a7049840eae97573d8806500c04919657ed3dbd83078714e6f79c5b04a7c708",
+            "7e8a7eccb947dfa3b9959747db83970f8d190a22ad877cf0256bfc1dbc76e290d1fe30f8a648822a5e5f89b54a2505441da7fffb9b701cd1",
+        ),
+        niels(
+            "77d8823bd6fc95fe71201d30e538759b244a406de8255d198a5dd1c69e653e137fd9f77c1528adcc83e51e4d5be5954e49c083ffab43511c",
+            "6d77b9b1e31dbfd0ddf04e1fade49c6ffc5560dd650309466890db2476dcf2779c786c08dddefa81064bbc05425cb9649f651e2095cabdc5",
+            "43e08c3df66dfbe3935ddfeee31a08e93b9906622ef00000eb3d73eacc8f33dff642b513c71409a0e3d891276d72cd1172fcb2ff0438137d",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "a3d00a4eff66ee6e7eb3f2c94036d4b85841be571d89762910997cfd41cf5963aa820b19da76be4f969e7178541b98199c9d87f1fcea6fe9",
+            "08aafa9f1bde1792ba21bc27f10966bf100f103058b0c2d0b8953083c3bc49845c883dec34577af6dedd0be2075bd0ec867ba48a97472f05",
+            "8bf40ddb96cdaf203a6576e6fbbfcc25e46a85811e8a55249e153e0d0600841d2d193d736dd732551221feee6b1e0db4e427ed445925b295",
+        ),
+        niels(
+            "866bdb3d2a945b2d469b60776e9219884fff511de19bf8b2d88eccb2cbd9b9cdb7e10f765eef2531a14af171fd22f3ef1e2307ca021de68e",
+            "bf067d91198d16fc1d4cf33dce9827fb5f30c855827da0811ee299763482907f91c8837a2cb3e7511a7244a28b71d190aef4a12134d5c195",
+            "c845def4abe3eacc563f08a01e21b866ba600d58d6389bdb74e6a5d2bd4800b7cac403436158ae2b988aa0d3b4865a5b9da4a650a3040f5e",
+        ),
+        niels(
+            "62cf0706eede0f197c0491661c42e0f8a8c5b3f29af906ab9bf8d91bc65a2fa1f431e8cab1dffc87838622c52e1eca54f01cd87b499d804f",
+            "a8c0e3f6a5e4513e8108659d6869c2e26cb61a9e9c048c1050e3fa48f3e8a18840a9ed5a9cdb1ee01d0aff24f5d020d7fa89b7d4dbf65dea",
+            "4c7e9af5a72a7ed7cabe2d08d24fe94070b3881b333886018bf2170ce7ab08b7627977f3d39fed29d9e029d80d0551aaa90c08e3afb2a728",
+        ),
+        niels(
+            "db785546567ff48a545d9272cc86e2e73b69788082353c7fc12c640d8717c953637bd76119820cc5c2e36b8b3082516badafa2d1bbff716d",
+            "7fecb2d628a82f02e4e398d2eda9c0d9e3c1caf14c326bd3b5b6ecd1491126f09a683d2c2d60b223d9ce64f5a675e61ba90499ba108d830f",
+            "ffe93140933fe73723afe3f27ee2a30f677f264f9aa0a461c14075ef25ac1d9d68a2ab9a90659cc3b079d15c7747fdd9bdf41ef12b3af3dd",
+        ),
+        niels(
+            "6a6c63553902321e3e8640996578e838c7d18710e0657b960ced75b42e763e5a9bfc17f28630d5cfb96912468522e5b9500b974cc5fcdbef",
+            "8989cd698120c797ff367ee193ffb2b7f27eb20389595e9cd2e3a6bfefbeac70c8c8d9773c89f12d5c2d2f4b23072e11daa4a6cd1a620fff",
+            "1edbc1f3f438c9c4adfe786f0819828d6fa957aee7ac3aee5b2925592e65c3783abdc696d665f94818c655ed3d023a185010cafbdbd49745",
+        ),
+        niels(
+            "11172a581d16282b375e71b86dbaa93a0d0467bd305cce1b13b2be2c44087c24616aab30d4deb8e61af4f50bd04bce3bb59bb3515790ea71",
+            "bd3a994786e2976dbf8cd28d80d973738224f47a40e5c5d49f6dd8e7c86ccffa7d028a6a87c1cc06dbad0afba7e5eb114baedb5eba1ec2e2",
+            "9b7420cb477a089816cc6b4535e1da3d0ab94a1d250c56bab97c531bec8e122fa3d69ea3b44ff6273854f8ecb54183f4a92db975debb842f",
+        ),
+        niels(
+            "6fb7b362ef55be9e5f8b6bf97fbdb922085c5f33e53e8248f7492397ff27ca3045b60c8c95ba442273a00c96a4be396761c49885e385cfb5",
+            "2079a6125ef4f0572372b39a369150729a2e21129dc8b1b6c09f8a663551deddc2a6ba522e4dead9abddb34774caef73a68b59747ff02125",
+            "2c616bb05c997b7c0aaea3c13449b2097e75545004296c68dd3f968637617d31fd9c65f27237b4ea75786a4eefda8667730555cfc0eede4d",
+        ),
+        niels(
+            "fb7309d695d56bc11382c8d44c51d1491337ac22845ac40ccccb563ece130cdbf0a8045d31a084cfc7ddb7a9b9dc70b44ca2b09f57e91cb9",
+            "6f7f420ae075c813f22f243c6fe3acf512930e996baddffa072b426af2c80d5fc7da58d5f4c645f92146d26d550f8f1fb118b1786f277768",
+            "fd5865ee9f8b24be7151889053b5be3ccb9e3fb5c0ae9060db3e1ff156d82bd68064f7ce6ed2e3d728f54745375a42069ded31507d2c9d69",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "0b0bbc17fadb387d1381bebf0a9a715b30f85201a2d2bf005b6fc000957cb08808da24aab9a026771e6942f8db787189e07b48bcc0756a51",
+            "3dfbf3ff0b3b1be4456b9efe1f141c93b6ebad3402426f8ba6158883e57e709c8088fe9e2681bce7417ac714f514da015c9126436160d4f9",
+            "9ff9e38ec439b953f24ef67113e05f3d4f5bf4398c9f3688f7368667a24b2967b16306a88e6074a803701a1f807c88044e84e271571f74bd",
+        ),
+        niels(
+            "2f0b8d2ec148a1ae25f5a429697514580b72f4e5957f6855c28b0368fbd61d628b63c372a9e3be48d5502b284786ab8974d8129c944f0842",
+            "3db675e1a922643dbab3ef7c50c474a5e0648b23aac99669cc8481dbcf42b467c6e12245a712a918c135436457eee8baa927cd152009f094",
+            "517cc203768830391893a2719794c5610bd125b73560944707eccb69e865e04428bd157a2d392903fc36ad3cf70137788f86ddb006158301",
+        ),
+        niels(
+            "46a75cb9e41d26ad0960e38750b59c0dcf462536944e0190415a834d0bdede1016b49b8640e3d53c8aaf8c0a986c2c7949f5f75648891213",
+            "6ec1295e345cca0cfd73aee09a23b8f56021dc3528d1935fec7ec77ba0b094edf0e44fbeb862ab61348a69191be5eff0003e5a0e72ccd9b1",
+            "bbdadfd7d962263e3b0edb79b5f8f6e26f0eff60484ce92b6129383f581e727abbbb15de15720ad3fd000bc7584f745349cc376adf5c8ff8",
+        ),
+        niels(
+            "53aac0225b73c62cfea76ea507b04fb14fdf66b84d1c77222593d875639aef7a5429fa886745267022eade4862bf055c3032ea961cb407b0",
+            "c6b0ed8fb5cd27af4b50024363a166b3d579b55cdb12609ddac480dd448f85e70bcfc79e87c5e42a0757b61b379ac916a2151b33bad43ce1",
+            "6afae70d19c0b74d6a02a4b1bf8644de2df598227e7eaccb059759ba2706fef27baa73cc7c10032965a6018002502b64750556b620f6be46",
+        ),
+        niels(
+            "5b100dbf2ee40b51151b9ae80a4a5c18f6c6efaa2f246cd2ec1ab88915062d1a8ef8361ac622ffaba644615aa6bfb0bfd6e03617dc15bb76",
+            "418fa9a35699702bd23a15a339e47ed0b1981addee41d3e21c34a39dbd66f6acf1d50c50398ce604a152d5ae5b95244bf07be785d4d109ff",
+            "2fb0c2a176b32bb9ed6c930ae7372634c6efa55758691389bc01238e4f3fea6e4e4732c4dd372518b1da4e652b29a1848244fe1e1464a1ec",
+        ),
+        niels(
+            "1a00de78059d550dd4f449b9d221245640e5bc0b5715c8c4fa5bbec0a41f00328a21f78a72e2f91642f987e374c8efb338024dcad17404e5",
+            "871ccb6a75b368c62cc7ba9f7e678384a2441041f596d6f9288134c4f885c338f2566267a9d39ae1c11633bd272b37bb26198b191eda8009",
+            "2c74effc2d7cc03e3b3874b103922f82b548047f1a4195d371c76d5a2902947101deb304388890fd7e7c8044c5313241006a7a205b5ffb2e",
+        ),
+        niels(
+            "f4add5c59b69dfef1eedca7c45bcbeeb5a01ba91db3632bc63592ea4950e96bd9baa4625b0f89a3bbb358026380cd620bde197f77c9822db",
+            "f7b3b5ac0a61ceaadf8f1a61404e10ebdbe21592f183231475d6578684a63d87401b384161ba7d61a2898a91fc2789ad6c754e9bea69b2ef",
+            "b745a673508cb5fd845ceabaee0975c2eacf09ef5d830f14e2abfffc6e50de84bda1d2dbc3566cc1549cc69ff7d23a82618b08ea9fbb2a62",
+        ),
+        niels(
+            "b0028734d32900ba543f5429003420856544f5cb3d41d959d01bff28e7afa59015c6640cc054615cba85d264b2851634fb8ad7e81b22176c",
+            "b0e69c44450359a84a8220f798bb31dddb74883b7fee32966ba47f5c7a38a3947be5d4fbad6f6908335a87667872896dccae65dbc9f2dbd8",
+            "8834813c322d95cdfb8f51407c8b30f489067e272ded7d0e25f4cd8c33aa5ee70191a1c9a3f5a95bc50ecc882a2b7da4aa5939a4909dd8c3",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "db3c08563f51ca64c1c0e5ab8715d857a4a259f8125c159d99f41296fcf13296b9dd1702727e5f26b4d7c06f4781ff8fa49b75eb721b978a",
+            "cb59599ac27ca198f483579a1560c06bd4d6fd939fc7388a5aa10b555731bf3e7a8fc04cedde9fc6d4ce2be3d6b56aa1b37a2da4ae3607f3",
+            "c2ca9486b0364bd0472bc1e28480024712a3a6f34ff8183635851f63014a70e3ff172b64bb67788fcf88efd54becb703c234fc9f4180eddd",
+        ),
+        niels(
+            "c1c7a158de5082c938d076d726a6af387763fe523c7dd53b4e678e4a27debe0c1b2a306221206a4b97fc53d995f73bc17ae8520169b43480",
+            "088bf1f1486b93cdfa52caf2a08ae5735b989a90152965b0c7725e675df0c72b07c7d1d50c5f8abd95769935d7e027d45b62bea4395fb39d",
+            "e1ddeb3f4700e55d27d0aa0e9372be95736c24190754d4c6e3af75e5abc7e9cbe330344096deabb01bf56195ff318e1a760987fbe287d2e0",
+        ),
+        niels(
+            "da4edea857fb2eb2db659f41bc8148841f3bd136fe8ce41f32406dddf4b30d5e8115b9969e2854c268be30732b311938207dc675bfb7eab0",
+            "28d3adf939a9f083fae9be284eb11e87196d10aaac9205bc06068735bd31778c2c42f338d9012ffcd2e8b0d8548b62062c685c8639fade69",
+            "0480a412e0fcebc1fca0fd9b0ec869e4e7471429401dad730d84c24b23fae54ad61324154f36884c45ebd03f3dec41ca3d628d17b50e8c1e",
+        ),
+        niels(
+            "7a36a0cd1c0ca4b509235ab02b9f55eea6b06c6c2fe9b599772986916554bab0e5ba90b2bfa45b2023e244e725b87cc56aec493c7a493620",
+            "b91f1b8a07d361cfce350f0bfa63fc0b2011bc4bafcc22c5406a0b0fa2da4edf42c2eebb4eccbed7a826f058f02aed99fd97827731b6adca",
+            "cdf12d7f39a26c5e8a9bc6d3071553aac89f3d77c88af60483599f183048065e1c34f1f919f2dbce8c2d22d81d97212fa5f3f54b5c711696",
+        ),
+        niels(
+            "b0f68a97899818f104933d6b78c27d5d33a8f9a7428e19ed0d46a0101403bc3bd34d73db3cf52d300a964d5dde42a6bb289bfceb1c8e3ce2",
+            "70518fda6d9c2003662087376f45809e57107915fc6235779fab36ee2562debd6356126e4e414e69423dfa63273eaafb89ff19f08b62759d",
+            "b6a9cf5a6179e6e6f16bc7f0fe529bcf93dbcbc3777b9216a0fd9a5ca56be15989ef67ca15a026f7c3c2a1d8de28e5f9b52012924f8301ee",
+        ),
+        niels(
+            "42ad064d731165b3f5ff4e1b93ef2a3dcfd1a8623e93939eca7a82301b11258ba5d388bfdd75ed8466f2ac735d80a882df3d42cd21de0685",
+            "0bbe647a155c0987e970ac6022308156d53ed8ce67b350ccf1845f923ecb1a1997995a7412dbdbdd7e35660d6d155522bb8b64372f021cef",
+            "6ee9841b57d465b94dee84e0fef3431adbaf406d7fe1c933eadb43786aff5db0411fba82bebb0d06a0377ef503816d4a06eb9430ca32abad",
+        ),
+        niels(
+            "3c80e9e09b0435a62b6d08afc4d0a0d324ed1f3b23ac6d425564b198b50b497134392eda241957a72594e88b1b28b9826889c6a0948bec9a",
+            "a37d33cc1549358fa543f6d19cee264ad4852b50a14c7901ed9c4fc05a9e5aa79b81cc2ffc925f6f47c0ebc4665afa0076f923e00f81d61a",
+            "689aac4f9d117fe498884ce754a3c69aa37d1a7b5bfa6ffa61fb877fecfc3c6ec64b5a4eebbfc5b6d4fdf7b9766ef694893c170a24212feb",
+        ),
+        niels(
+            "6c5dbca85b000a8af025e2b0cff0bb5953890302b25c1d38f7e9e109ac911a5a8b8b0ec16be91fd78e8515e0e82580c6a600e59e7df8f8cb",
+            "cd3708a2cfdefd0f674e5c91108d14c5555f729223d83390049f179c84d08eea156cbf070aff0814a6a65f9ce4c4ba3fe73a17998c88951c",
+            "5f957fff1bc8d54b5d9cce4eb4d8f6cc15b8e8f1a6180057ca003f167be2ae71eee74a647486c2289b4a13da10705cdcab438c23d63588af",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "7f8026ca7b7074ee29c52cea1ece7dd5eb99d5533930daa28be5e22ded5caf290cd92d13725f81e704f69f2794f47fdd0bedfe47974f94a3",
+            "d5e7ddb54d75e0e558cc619017c17ce88bc027ffff6e89e0570fc7ccb370bf78e007ce4872636488b1e44956471fec2ff3533aed5bf28d5e",
+            "1feb2a55d20463922a3c3a379d9fc3a5ff13d95fae7a351d19ad17e54a2db9c8066ecb83259e660cc13f3f609f6cc9c2086e51af9434ae16",
+        ),
+        niels(
+            "6f182059dd72b6df484060696e8595bd8204a1241b936d5af3b60627bbd0691352e27197d1b9311a690f7e9a0af752e010a9b30bfb374810",
+            "80685f00b68e88fd97b640e65f7f5dd89c508447c18a0180fc886b8caaa3617fccfc484880f90377ee3e5d652f181d314cf3f600fefd0d37",
+            "a7cc81137822dccf8402086e69f5c736d5870454eff2fe2c4482aea49943ea4505fa8ba57a9b510662df390760f2d1d1de8afe954073eed6",
+        ),
+        niels(
+            "956b8d8d9734caf81bf357d5cf4d19903011be7fbbf1c160e3bff8f08ec7598f9b5c84cd38e5793fae630dbc957a15c22f2f098078e9c45a",
+            "f05f4dc3ce2c9a9373ffabe2ff4ba0a4e4ef4a539f859912c1253c79a9e56d90f1e98c18d9b19da7fab87ddd03d20e29b98252ae7d94f24f",
+            "516672411373a6195eb730e3ab7b5653662b9c8e58eacd375fad47c86e49824878639554706bb78961ee90a9c14f2f7375baf03f091e2c7c",
+        ),
+        niels(
+            "22dad6f24784cb6e2ec540f9d15f9c65975cb531faa9e0509147a8c716372a255b9422638fcab30ae61813a80bda92884e10d747ab0e0d13",
+            "bdc2d7a9ba1360276a411d52fdcba3b59d3c5c50ed6bc7c40487bde26353af48f89e3524eaa463455f587c65033f8bbc2bbb6f1d7b633fd1",
+            "71964e447100a3c46a09ad39950d021d1a0876aba8eaac51e208501283b0dfb9a528d579760768662c71b6f148632a62246b681d4409461c",
+        ),
+        niels(
+            "0fa7aa4bd362ecb9d7007a72f95f4267034e5d1cf6e06a38aa199bbff8a25e1bb64fee4507f07a572401e6d9b24e35f069db85500e43a9ce",
+            "ee61f3480403b4910ab93fdbc896c39534c4ce6f2264d1165a03801ad4e3da2f849bda31715983f28387a33b4fdacf6f0845a588eccd1eb9",
+            "66f7ebb575c99705159cf1d3e42a551764a37c262df1edc668e316871516a6e255c98bdc31e8ac46dd9c7a154a17396198387f3724891893",
+        ),
+        niels(
+            "e75ffd7392fa0fba6497067003ea9a81d7db70309c4a4d9a367f31069ef1149d6db993dc1f1adac23d97f4660f8108565b73de2e7d07e8cd",
+            "60125a172b7e236346b55838a2d706470622f3c0d9d8f25491f890c51ae9139cf4c2f1ded5864e7453fbe39449ab6b061604807aedc89cf0",
+            "b0bd533f8aaa7a190cad6e07c5bcd96a9cb7b1864c182c1250d1c149061c8edc2ee6053239528a7d866c669858dd642bf6ca4d54073672e4",
+        ),
+        niels(
+            "a3c750f38040f0c39620de977f68539a0454a76ff4b2b6842d661aab26d6cbb0df627f8b4328f839915ef9174c8538bc91882a0a7c6fa236",
+            "506684ede5d07e0d845a5b8c1cf8c4b3d77645859ce98b79f842ce44e51708636ed288021d7bd91c2633352122dc4aa3f6b01e0266f96835",
+            "973a159bf266fe171f0281fbbfeb0eba39cf10334426102656641f477bd51cc80f27ddc8a284d99263ccaddcac88a8d71b23d54a53c540f8",
+        ),
+        niels(
+            "ef74e400e9d2494bbaaa410f74423803629376b788f6057a5aa7574d8cb4af25e96df1c3bacc1252b09ef8cdea9f2685a49e5aad08469f41",
+            "4aac828384116042c4fd6f30b6b79967600121f94eb7a76e692f53862d9bb53b515fa479c811065ee7bdcd2e612e50cb9182a1c17db5fffa",
+            "03ee60208a5cd1afcabf2ad6f30c19089c649e897d2b26a82b2caf8ae8cc7f53ba53d1690749de158e1b063ef941ff5bead7b5f0110bca15",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "00f53ff5742f90ef5b9c3573fcd600368d0f74c0c7a8fb20140c0fda1ff8128462fd27ab375ad07a18ed3f630d266e00ddbf921e9484ccab",
+            "87e1961d14ccd805dbf5efe6d31ec2cf14085e277a6f7955682399067042278fa7ccd78eb6d816e3229bc280813849d98029c40c74897c63",
+            "a33558d7b6f5dc86f56d2ae63ad85293a4678e7fe656337a6d1b1353c880d05b5bab6fc613ab456ddf77db7d8b5587a4b52559a440de9b34",
+        ),
+        niels(
+            "6520222c59c17e6732b8b90f794a0572c2fe7a0412e8576f20943a04197b31cb14cfeefb0adaa4616264f5148d6f4f881f76e753d98e2ef5",
+            "2a5735f5c500bc0e53d5163ace49b0c26484a8a5d6bf0acdecbef6aae3db9fc733a6db0f9ed3a975539e94ec8342e4677615bfe892d4a7e6",
+            "8e3b148a9d234e79b6f5854b241366a64c838a86942ff26acfb9d1995d5c6c8235895212593dafb1dd8b6744ae540c9ff34233e9c5eab53b",
+        ),
+        niels(
+            "176d955e449aad80312dba11b2b4219d3f7993961fd5cdf4d2f36107dbc02904c9a755aa519dc028952c09875bb5d573f08dd121818849e3",
+            "d5f5c550a9cc25b1516a8d8bdd68b84c4c17df8f4af36feb5ea49a4d0d69ae05dcf7121f309e8ff56c73a52b182165948a5724b23bffb611",
+            "8cadadd21c4fe8da187184f6e6d11e6862e3fd39819ff9be85a752ae4c9d09d1c963ee0f0b737d9fa405a9210d1e645269add0effba7d832",
+        ),
+        niels(
+            "23cec4a0b2f8bf68127177cc884dba1f5e09f0c20531f5fe1743ea09fbd44792256d6265bc60e6212114f240074343276d650f4912046a8f",
+            "6e8e7ef3a1ffbe0b0848cb24ece634cf61199f3436f20a2b35de69c8903cbdce9db1e96725a6405b6174f6b49b4f1ed1a83fe217b02947bb",
+            "c753d3e877cf826fe63e5ca6d640dae8253c3c2ff5281c7f043e9d8946e7a4edfc5c79056ef30ff7775a1eac167d20c78fea67e9f242582b",
+        ),
+        niels(
+            "fb011e5541fe744d9d0bcbe1ac0c76b20d68acbab70f27f707e9c737c49d355bdbd53cf141442b3e09392da456a5caf86d3d20b92024fcee",
+            "3fccb8d07f81ec261b4d4b1fe2050a00dc2b8d0331ca0192e911ee4f07a040891a212fb0bb309f88182b8a89073927ceebdeeaee6dd3d091",
+            "53f70606cd717b4860c7cd8b35c1967f27c9830bdb2ac11f5c04cc7c5ba9aade2c8d043893c14d42aa22b78520cecca1fd184d93e1b3bc7c",
+        ),
+        niels(
+            "9a44afa12ecffc93fba9c78d4143a18cb81cab6eed96b7ff8575f75a69acb4f8e6d18bd1cb2eaca691ddd6bb9430d809027ece09292b6ae2",
+            "c804fd9d4b56c824dadfb304c62ad35871c499194d29a83695f17d15e501255d7be59b40fbc7748c5b08dd4b5430fd1db3ba1d6d790bca82",
+            "ba3930af412bfb0dbde1bf74b7735d614bf0dca9dd58ad51f1a0519da879958e975f37ffebc49994df014a49bfbff365eba2f1400ca37d1f",
+        ),
+        niels(
+            "2bf217b13e23e963696a89c4166fddf9a1aab8d8539902fdb67a5fe68e8de1f9c597ab1ff10fd0c1a5e5a09a11911661a43952dc08c2dbcc",
+            "b528c72606f8c9892eed98dbf900a569886738ccb3b2b7cdf317fad6e810a543d71b0f5e4b89b4255be8343f496c84841a742c6c9a6fb3a7",
+            "3898303edff0ecb3249b7e650b0b6bf9ac602b544451b69be19849561527341e0f7e86dbd7335f0b27977f8d6893d386d491c0c2082931c9",
+        ),
+        niels(
+            "9391c23a030c2c981df4c01c5894f2419e3ff3ce35b2e0dc22f96c9e11fbc4c31b8ac713a721a6b6423753a5f00045f02822d3f6ed4db02b",
+            "b09315e55de03ce54950bb81791dd0d2dbb5d2eb0b0de02df4b34e87d6daba318b9ed8279a70abf3d513b823d7b24bea3ea29aebadd5614c",
+            "2b10fdca81a9e315598758cfafd2e53f7f10b50e96a7d9738da127495af18aa1e4b6a835d15005843674e7e5e055c6defcc21cf79e08d7e5",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "9cd3b2468610b3602c1d670196cb00e66f593f6d321d877b8402633b50bbb5272b56232eb5a00e0c740537d477fa68445dcf73ff1d454d24",
+            "604cd5ee544242ebce4eaa38905430544cab8509b208ecab2842968ee1d5cde84a03282e72b8a939b45b69752c311db66f2fa4af1d28a9be",
+            "c383ca0b444cd4e6186d4aa484a866cab8aa262db982724b663421129c47979a761d8701060c46ea4bbfc155c13f20532e854e78edf00cfa",
+        ),
+        niels(
+            "7caf21d75b3fae3da234b0f0d96a37392d6bce29689bcbf0c830089288734b651a6387f63f0b9337f4fc811a39242be4a659a505f3c46152",
+            "3381fe9bad4ec952dcc048eb3266eebe315c8a56162282e9bfd549d5dca0be627bfd3383aec27ca95a7280530b59cb5d412ad0b2d71ad1a4",
+            "4ffd9bfdf453e935ed859db9bf24358fbaf0fa578b8f218ee41fa8d09559178ddf12829c4d8a01378e2e6715bf8dac435f58ef4300b2c4f8",
+        ),
+        niels(
+            "afd9a8318b805ef4ee4aec1f709bfb27fcf0b3431a879f0433595151cdbbdaec82585c6e2f6a551926a5c923a7babe596298c2ee980d794c",
+            "f8b565d2ad32b622971e3305689348f33636459f274337aabaa6d1b333fb2c07ee19b18aaf340c794dd4ec95026d2a1ae84b339b0f0babfb",
+            "c2601fb7935cdea6d5527883e51d35bda0348f4705ead4803daffca1b550f93fe5d4b6bc4425838de04da5c1b0cbc45415965add337d07c5",
+        ),
+        niels(
+            "7794921dda967f75a30ae1c45d30a98ef38e254349c69cb1c1a8c7db6de8247000c06c1b31a58b75f7b734384e212c1766cb3c5e9428f2bf",
+            "2753086486ec7be851902f3fdbe4c9ea424d1b4880f793024baed650d3f7ba767f4d684a5aacf6539876789eecc1c016eda30a8dacf17fb8",
+            "6336a59e0d82858a93979f962159b66f6a876c61a286f382ec2de99475f9127ccd2a4088258be8a49aea7738b771196756315c87db6cd4be",
+        ),
+        niels(
+            "9ae802421b367cc7e9ea0aa4839471f5b7513817a8ce48fd70fd34b2b07fb7965898abd4ee1407a60c6836084ee05541a08575771c9e266b",
+            "ac8c0c94e82706eba8ae1f51528b151653c5a53de1cfc1a840519ea573f08619ba504a569506b826a0acc5dcb7032fbba9a5ff369a0f0e82",
+            "e13e2c94f2d183a68ae2c61e9e33ddcf42ba7fec8b9e0cf39ec62e69eb1597c44d70862d72b9ec9d0d3be4f0dc7c9b9f5a3ce362bc3b7d89",
+        ),
+        niels(
+            "b15b754d2deb2f14415ced0eda8d8662b7c95976e8fcf570130990df0cb305233fa2aca305cddf601c765f0b1d0a3ca58edd7a69e157baa2",
+            "5d1a34180ad4567ee1da93781c731cba179e6028033fc92942b24fcc8dd88f7bbea409177fdee51a1ed121bf9e8ddff63bc4b35c5f341cdc",
+            "c9758d6abd52d2a23f193d0f1456727086dea461a9e5b2bd5313609a65e33533f1da91bd638214a9b31c2ef5a6eb83c3bf967949a6d5f189",
+        ),
+        niels(
+            "a3ae62ddbe70ad5d4cf924c1198cd2e3a5180452328599e9c453acb71934c453eb871f426bf62c85dfa7ae2b7169ebc87a03119933caf963",
+            "49f86d1e1400ee680b7ad341a281cc95b5179e13a68e35b5419773a4c0549bacb3ef46555b751ff3bd9dfc4de545bee095a74c8a82173d20",
+            "167ad053f8d40777157ed5910235a0ef55825d8f89d80ec9ab89e346a1f190296cfb2534f36148af6b23c99ffd56b48d0aed3cdd6677bb7a",
+        ),
+        niels(
+            "8d02670b3cba72930071c0667a5eea0926104e97ee230de40fd44b55c9f7fdc1457947f5ec4218d641ca08dada4323e7128da973fc61ae35",
+            "975df4e05f5fedc46dcc16d87b00aec4f2da89bf401086d4a7338dd89f9b0a61d5f9399ee14f7c1a8d52750980c47e0177cfdafb2b0fe787",
+            "b3211058fc66a3c4b4a9c3a9ba8876a9432e508ed77b7168a4b025e5f34d3f04c3c0f0d8be1cfa7333f46ebf97d83680a48959caf182fb06",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "1d88f720e21b6363f7af563d2f7a93d667bf92122c42e971a20dd994378c68458eab1f3d7d2e1a4ed3e660485ad8a10b341e45e1dc3c7ea5",
+            "b4038562fd4db7941ae6a5825f28de89d53c8db964c9e362b5def3bb63943465ddc3b3082a66877a31fdad117738319ca5cffc4857e9f8c2",
+            "15f8418839a8931caff2b5870030c1b91f7aed5a2386ab9ff475ed77c652bf7b8dd2dd7798014abba88f8a5dd2455c08a0e9b28a597a6cd3",
+        ),
+        niels(
+            "2f810631c6e2c60b8a5127a8791b3ccf0089bd320b0a7a94ff756536a09f8c07a68f3455cb64b72c2beecb4abe636594bb4f12009df88db6",
+            "5f3e7b9297548954cf7d3169efb8960f67c1ab4a0a53e400b60ef6b3f38a951473dcfcd2a072ad738c49aa56fc0c26250c51b20f770dfe29",
+            "1ce09d065ebfe79d418798068adf07f54cd7597c425dd16c3a37fb9288bd396328f9fb7bb55433f93aa39ddc31e565a81871c6f9716dd163",
+        ),
+        niels(
+            "5ee0d6abe52b891069be9916b5128759f8288d93860adfa6a2a732d84f3a90d0056eb30dc9ec08b659c1ba4a5c494d63c9e26965363b31f7",
+            "f8f56de716dad1bf32d9378c66d5084170a06bc3eeaee748261acd3c9e19f926dbfdbae36e0c7f6d72fc9845d4c1aa9a4d0b4b8ec64851ff",
+            "9d94f0dcf9c01ab7c3d6e08be1103e377dffcb3b9826f85a771a4e056e1ce6450df12af7482a84d0cb0f90bb3a59949e88c6dd99b1abb844",
+        ),
+        niels(
+            "7bec5d75a42550f3ba2f815941a8c7d084bb139f60517e887b60b3ae210a2ab70af98383afcd2156a76ea3131f78e895c2abb178e42a30a9",
+            "dcfcc0700d9b2810979ab05f982cc893175747b128aebf8d058146730540d463f23c19784c4a40d3ae6cf462a703e057c6d186f9bab313e4",
+            "939bde7ad82578edcc302112b0be9b3011592cc8bb81c4e7a65a11e5d5113d246239f06f765f120b31645924d3a45b3efa6ea8e64ad6e028",
+        ),
+        niels(
+            "7528fedd747a6e3684693ebbe94ca64378bcb54f43e3a6c57dc526ce51f19e2d7e3d13ee125c569b69e8c1beb3572401dcc21997aa7c558e",
+            "2645d4e8c44c5d7bb5f5f04750c1ee1f085891dcb2c86faf5437928391a487c3c594c1452495c55ece846e3d8e2d573195cd92aa83e169ec",
+            "3401f3dec5203746113501ee449fe1c3580f57a17c11d81563ae4b5196561035e41134544081d2045a77e4f086f06cbc4ab3be60318facf0",
+        ),
+        niels(
+            "950bfddf340091db62aa6cbb5edcd259e51639f734255832b8424546592e3af1197554c717e6e8537ae2dab9bcec909a38b90bcbafe0b627",
+            "d1872cb03031200989ad323b5dc4ed5f0fd289ca35b301b6337f4c1f982dda277fcb1739ed9b015d8b1cc09e78bad04f729a1c28579a5342",
+            "f52c0e15d5c532e2b3f3135f6d400eeb5dda666e71db9365e1442c868eca0491ae7f15172bb84a89e275160b6b4090168e41fb5fbbb18876",
+        ),
+        niels(
+            "7f34153899e5ede205bb56343e3c0018d4ed93ac9c56ae03c0e8e826dc8b535ad70355d03cfff7ac77f3431126d67e2f11c1a85af59a222a",
+            "3c521a49a42d8404fd316894f97d51aed4e82625c83ec9cd9828915eb69545b61f83e2c3c512fe21242477fc11dc8567806207a18ca585bc",
+            "9418d22a4581d4debcedc88da5931f4d7c2cea1e047c5f64808862a90905e7c76fc1d39934d4f93a0d55eee45d22d1c2ab55e6be5c04e58b",
+        ),
+        niels(
+            "32158ea305926387545eb897d418eac7349b8dceba9ea1216e859489aa12fd6aa3ff9521ea652111e4a64976a26e8b364c5a7dbcc774b5a0",
+            "66168347fbc8175375337fe1874b21369faeb8b5e3b2bb754d24f906afdf6ca50a6fb2548fc42c76b2498d12e7179378e158bc0f8158eb23",
+            "517916ba581d8475e8ff1282b09398f6e8d89b450f01c9729ab18b6512bfdc72a5596c392fd85371cca1d35b799b1aa2b5a993bd4fe971d3",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "ffa6b3a102a92430d3966007c478af683bc53628a31c3bdd583cb0c2c89c44f639d20ef6f85e0ed2b9ae14e06bc90cf3f22b351740c92d17",
+            "c7ef52b4ff32dc2d9edf8fc7e71ff397ac90627bc7a3c0df79e807c12e9b459ced80443b3b0224e58d24307418209c203e89f909f000417c",
+            "6363596a77a179c8bf789dd339560e469b408c183e444c0a85dd1a37de9cf8bb06c21e5a074f3d7604fed5c08cde77bc8de65ae76facf2d1",
+        ),
+        niels(
+            "67e8a79ec10ad2ba978a5316992b89f170587b68e6e586380623fea2a09f4d3850ce5a722b43b9f9a05237c1fd3ba86c2b354711900b2195",
+            "ce13f979ed7ae182bfaaf1c8ab1495c66202d18e7e7fc39ee3288ac41e1ab38b7a536297bb8f617743a1e8218056ed6db8b45b90dfdf5657",
+            "46a587c9c2e490a205195f697bf57c09b35a321c483f55836106764d57ccb6176e774df313f8da3f921d42d43c018915f1601e583a29bdbd",
+        ),
+        niels(
+            "03a5d416a7272af38ec05b2855cec17baf2a6ef6b6a370fe3f5db499d487a34e50841d7773b3e62edd99223f7c4c0c601c9e2ba52983af32",
+            "c3066c713036bf16cafb8675198877cf4dee43782d6ca8f819ec1a830b4bed6eca77f0c4879b865be53b711fd6821f86bd0a24dde9743435",
+            "21028075560a2132fc1dba1e17e410d5ba7781ea2521b68cc959da3b7aaeee3e572781a1d5f0b3195656b5200faa116cf3332b75596ede1d",
+        ),
+        niels(
+            "3c1474f2f51778cba20b2058ff67754f4bb6d79b2d40445e482e1301dc03480f2d44b086e39987e3c65188ab3128cc4dac403c6ccf556bae",
+            "9e6198862ab3dad30357b167912eda10e42e5b2e2e8a4dc9e40012b7ebbfe08fceb4d20266bdd4a2a5100d78e3448741598640a54cd851fd",
+            "554b0284dc542e34df70efe8a1832f646bceeeabb020790c3a631a4f5e6b79c4a5f4ecd9ecfe124b811c6067a9376b462ad071ae9d1fa14e",
+        ),
+        niels(
+            "bf899c66f83e4054c2c15feadd39341821fa61f6dffe1e8c5869e58fe88e0e7ecb0430f4c64cd4887e9a4facbe4d046854c00dac7f66f92a",
+            "4c42248bb304d7793f02198b69c8c521f541b6a7dced2efa13418ec251256be8160044d0be9bce01710e2d82dbd44b35c7f031ce9988e4b9",
+            "7d11da5f40e3ba0b7531e832bceff5182c20e7de8881a2b42669bd105f06d68600a031718bf8bdcc4bc91874e5090adaf56ccb2b499a2ed3",
+        ),
+        niels(
+            "1741d6d22c43d438b7490f34187e55a93fcea4c67a018c8affa8d0f0f95b5f3b2753767c0a9035fe41814845656cf3ea3d5dc956ba54b244",
+            "4d0a7ef149c653e0bc127e3355253e535c083323d4fd3c142aac62b2d2256480b466365aa79fb18198362c2edc514f17ccb4fa4840669f4d",
+            "1ab766e7ee65639524bd4dc603fc5326fae085d25c49374eb9d51085f223c773c827996272393ab53c6bf781bbadb8d7f80c78879e2cc0de",
+        ),
+        niels(
+            "a5e260eff34bcaf0cc4de9c8de5b93f9cb1a497692a892870fda70e7e2187db9fe2fe33bd6ec703534b068d07b31abe2d71e74a62f0e9e23",
+            "f07b8c33f8c046a2d67f0b36068465e7793c663396e055efd1b7dd4fe3696dc0144ee904b54381af362dd66e38fe7454e8d6b807536fd0c6",
+            "0127b3b875e9a2f52a99a74d804a1167e12cfc0b55dff4bd723ecb6960f6291896c99ab6f655aebc5cf9419143140cd666aaa6a9c323b3b8",
+        ),
+        niels(
+            "bb0e091c4ccb088508f14c07ef4b7798e643e5c51c7ffd2e2ee091aa1ad6c9527248f5be100e2547c787eda0aea2bff7c46f4f074739fae3",
+            "e680d679454e12f61c77b067dac9f66bde3770f0cda17a93fb4f94f888ca7ee52cc716ea3f60003a912e3cd7077f7715e3099adcaa632c86",
+            "c5ed9fdb481a025c97ffbfff7f3d31ffcc3f00b6428119dad2267df1ff12c457a5b9418c47d1538118d6b4e3b8ca7b9ad8e71d8cd5552ab4",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "9ead5e57dd42d5b5637b049735fac3216187022216bd1044edb8ac626d626a9453f956adc2f13f74c65dd61fbfbadb495d6d46f1bc23f13e",
+            "f66f1cf49ab22a451905e6d467d57118c0a9ee21334f6757b9df60598400b996435e0f88238ba3b50ece993217456e45f3899d2c4613ac2c",
+            "828ce714158bfa18708fc25fc74eb92090344e3700336fe90497240cb61c16fa7ebf805dcc7d203a95c79ffdd862bc0eb8d36bfcedc6899d",
+        ),
+        niels(
+            "a65aa6239f870c2c9527e85f0c1a1e42246f9055ccc04cb95db7ba0b3c0a0f6507cad335340cd419e5d657944ba2fabf9984afbdaf89c221",
+            "78f81dedf0ce4de053c8c23b69ac7cdcdbee7b76335526e1be06c1c7d0fa54e26708343309f5e3eccb9acd416945817cbbf5848c69361534",
+            "829f0e8362d2f166ab6a107d1f875c3c77fd6366e46721dabc02af344b23cbeeb5bc2fc9a8b2ca4f6dddfc98b3bbc42337f0a908a4f4c72d",
+        ),
+        niels(
+            "21348438c69a91be4f60b46b781de9a42832a52f5b69107f6855ae7570c8058b1b19e036a157397a0927d37c66816afcb711f261d802d119",
+            "909d2e20e6aec95cb315fc4029b998cc2655b7695cae47a000a44d531c6bc491ad19a399bc00d757a3b8bf54cedb855fdb8f5738041067e0",
+            "de31aba6ebda15d02a0816469a2c68451cf8aa6b9f77ebbf3ccaa695a5798c8bac9fc5fbab3d55a5789ea6ad4449ca903035c33ac47f769c",
+        ),
+        niels(
+            "40596ebb3cda2ab763daa6b98d2342d35369281efba827f039d9ce7ac2665cd01773873c2129bd7c04797e9f9ed13769ae08624b0c414918",
+            "121425c27e985fc0f5e73c134ffa0aee08f2b59a03f7ed196a373928b5ffe19c029f95a3c635449202abf3b985a534ce2b9d1b9d27a27c11",
+            "5d022a8dba7e12ae532fc61188fe6080c41c86d1a061ee396b07f2aa8d42f250a505fa536893fbd969c05f76ba97955348bd63a94b5b9e50",
+        ),
+        niels(
+            "7c1f0fe9efc2fdd9e324d438e21a01e6a7f4a63b944d9d5022d02f1146372f4e27523b736d32f07e2284092d7ebb8f64680e2a738401efff",
+            "064458a0a4f74fa9b4dce439493d1f01314c39ec9207e1e1aff3707c9b8a4248082d6857efcd138b777693a1303c8c71e54036434a48facc",
+            "a85da08a4c867ce7d18de9c2db9cd7283123411142b98c591037c1a3c13ddaf3c179de3baa0624b760339bc36b5f8de38b963bfa15af508b",
+        ),
+        niels(
+            "5a018dc99fb471239f1d7b51154f3f790713ea41d30db5fcc06ca7d10f213240fa4fc50a0270aac66ef9b0eecf2386f3529ad422fe41eee0",
+            "d05cf5bb7067e8bb4343e0a503ca176c15627e921670f936f0d9b48c7470889ee254df4a0e0a51fd1c7cf70e10e1dd2f52897192961d6071",
+            "e9c431a2580a1c942a4e87464ab0fa2c8016328828851357406a0e060ce276078453fffa84dc103f07d37b923d670389468898878d734f80",
+        ),
+        niels(
+            "5a6baee7951069d653e815dfb2e5f0f0a41d97ee71a032bba2725b1ca4b7b772b4a260409b02ea8c973cf4bc410ff753e1f41072d3628fc8",
+            "31e685bc9d7dc6947d0f05c6cbf9598b3487b0703cdf941c604043a88376c5720654344ab728692d5ad238e2a091af9183b2abb63b027c0d",
+            "de5b4a87ab55a013b06777768b2b087af78bac65cf8df7c2d38c7f75e453ddc861877dd1b961be6351d6948c878063499c90ad9f50d494c4",
+        ),
+        niels(
+            "ec2f56646f1af38de6f3edcfa6db5540b05fc6ae2d34f10b52d76c4f9420b832316f582bfa40bdacdd83bfa912aba897ff13d343d603bc86",
+            "0e5ae5ced286c54ca62c5e03f113422287de1d2e0ea4fbcb495f47ea0165670a88f3a988b9ebf52e2cc117ec4918091f1d5b92b2a1cfd39f",
+            "67130f3b19cf22c8d1f7fc4b3ffa4583ee200df300bacc4218beeb58fb0bd2244c4fe8f80d03f7d44cf6f72a4bd9ff2cb876c4bde1f037a2",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "1bb13e2452df0b0ad7ebfadf18f0825cd582b996f0647eeae0dda3038ec7d0a31fcabba29d6039d837f1c58d11b88e11512a3b061e20512b",
+            "54de348fd9bb68a622e83689ca4f1dadfa568058288c2dcc9c081b08b092abe9f53b1e21a2fa4cf108e546cdca6bb923fcee5f726927999c",
+            "fc4fd14aa0f5114b7d7ee4354ef77b5b40e33a7a21ab986e56f2568cf8453bce9f053626d7e5319d8dafb9d4f6f10a9a8145d4d756ae9429",
+        ),
+        niels(
+            "fc62589eba29ea4b9914e65099582395bfdeef99495073eeeeb3fd60a13f54096b6aac07cf91f393ed73b429dc210a3b9ce7b798cbff9854",
+            "5b74a06d2f61a75981173332e3973daac41f81f2f3a73ba58a1342f7f224225b6bdbffb1c56a39898b23112c3ec7719bd5b423a8cbb04d3e",
+            "8facd125d88cb5fa2dfb32d6862ebb72b7c1ae329d9883283f6442de4a67a3c2d3b3e906e0bb77c1fd650a41860ac648b9fe3bcf26ab7bf3",
+        ),
+        niels(
+            "e163a02361b2600382206b0720c0e22ec3a158040d04d4cd89d6ada6f2315a69b2bb4e96d63860c337a1f15f719805ab2894365dfd244cbc",
+            "0992cd17a40875f4979adab3c1fe71dba27f02c4119512e16cfa003d2ace8f71760d423b045a62bdeecc5c2721f537f1bda204d1d7c1c06c",
+            "96aefcb23f555be28ad67b953432719840e578f75002e548012e331dc530f1a21b62fc62372e4fbac4b867ea9faa4b39e3f288dd615dd625",
+        ),
+        niels(
+            "5e9704dd68b138ca5c61204738859dcbf5a0345874c7b816448e575205f658c6e75bacdd1c44bc872a0626dbbde9040515d1af08c52b5f7a",
+            "02e52caa67750adfb0577fddce9e3763f04b0218d7727bfeed99eb4ca0b57f6456db0e803b4823f85fe750e16debc03c60e7f118ec497d52",
+            "e277d50b1ca6e54c3433bfd440d29f546c483cdd786b52993435d259d9f84b7b2d24fb83342dbea19d744794bbc319604b22cc685793ae1a",
+        ),
+        niels(
+            "191fd74f766817ba5cc3db7fe2359515ff087a104da19778bd8eb356aba85975d718319616bdc8a27d9e64dc85cc76b2f72d19e846de03d3",
+            "7ffcd96441fbfa3252c9abe95579c4ca0f8d0757901da117b2f0f28699cd13a4bea11f571cd83c60aac0a07678a397acb4d389ed6a900512",
+            "adb53e6a0923c807b6575e04e0ec300ce0ac4b482f28a29641fd539c2d966d33a365bcd58a845553e717a768fe667af2fcf99d0a2e53ac84",
+        ),
+        niels(
+            "71c82881419d7c8f31e9720193715922ff2399217c0f84bd996e78ca8c05d9b279a5fc38e43738acd10460a1ef563fa22ea712cb032cc7f3",
+            "567ff64285b7eb31fada0f8bd18d9febc99695622d5116e75e538171779e11979cfbe404e11b2e60df11932599ce81be5c2237aa39b49777",
+            "26754341e29f836dd894dfdabbe98542eed28a26e0a41da0342eb7925409d10b5e5c0d90b420f4095d859dfb14b0b8dd94a4b02b46f7760b",
+        ),
+        niels(
+            "b595d4715f45c880423e2957b68bbdcf07d047938f3537ad7315e475625426c606d8af534a72b84cec0e8f400e9badd402b14972d22f0bbe",
+            "a673f72807eb5c3bd179279e366e1cfca536b7e3952f55699f7c9dc67e444af36cb004f4cb38de432f272480639a20967379a9c4b25f3b37",
+            "c7eaaa8336faa3fc2fb9fe45104e8e19bc7c11e971a54b6fe57d4e217d8a7695bb96b04008fd0d895473cdb6854585d9f8ba6a39cdd1c8b5",
+        ),
+        niels(
+            "5b0a6698f55f6d0b82fbbd528911e31a8fc2df5f1e28bdd1b8240c3d434224f875389212fdda3f05dbf377ec31f68c96dbd22a23e9163fbb",
+            "1e39c3eb33bcbe3a6a759db32931661f236fb291cd40fa866f5e62a4b1691be1f684a2eab1f644e6c0e730f518e9502c388b475a9ec6f104",
+            "080a4d96bf77778f050ace53095b4d63834c9bf4e0adef16f6cd45dbd26616211c0c05f77b5fa7482227be72af2b2d4b2089dc29a1fbd008",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "6375c1c82bece14dcc5899b361cf98dba3c4eda655831e4f6113ba82ec6cef30f79c7a77cb2bdff188700ecd24e9652c128de4ac4c9a9854",
+            "0839316ab3c704f7f724ed5950b5c71cd00ea2eb651fc99f55438cc6c72633b39f66f87a1744ccf429833a09275d3644a275fd1241fb006b",
+            "b2ab442c9752eb4b9d0c187d2bf0a14430d5558a3afd779e4d8f73839cbac9ef29f28c7483e2a337ae8cad7d06f87edec64c9af0653b190b",
+        ),
+        niels(
+            "67aa41e00f371adb61db381542b270be1d7c0749f61c80687369c334b44a5187ab8669ed29b483432e734382379ca6b0b934464774bde57a",
+            "5d32c8077478edb10d81656bf6e30f1427537b07a0adcddb8141376a8a32815e405c6f444504957ef5afc9029527ef43a9684721f507727f",
+            "14c891616b5fa425fd8cebb18bfe9711917e330ec6758aacf3e15ccc1c33d2f5da3aa21a36eec055beab8b7b06c311676ab913e7fdd77f94",
+        ),
+        niels(
+            "fee79efca6fc67528d28cddd6739c30a32d4033c63127df02b6d1266ce3116586ebdd89f6a413eab42232e36e76b4086bc80a1945056586d",
+            "e13bc3024531541260a4b3cf2f9c4ef98e9aed547b3150b12f1742440cb11c52dc1627dbe8aaaf121bbf2a255bd122f13c8e982b953dd8b9",
+            "4b3a1a8f33390de6ccef65ef8e8b48c632ad5206b4a173eac15961eb40d526ec25e535b08832180cc6865b282378abfb8e1ca88e72a17f4f",
+        ),
+        niels(
+            "27d6fb71f01e0ca98869940ceebd95b75c8045955e0fdb3d6517f730bec792f0e88371a3abf2ad159efae298788ee247d45ab762571d2026",
+            "4f33a0b1129a15f1cd322ec76ed3be086afd15f56a89d620aaef308f9ab7cd8fc7cf182444f9bab5baee72903513b1b286e025aec833c6d7",
+            "dcfe5db7130f8629bed1168c7d804e5d3608e910daf21c594713ba8035d362b4991510e5f11e941fea95472b6a87111908ac0b6bb31c4d0c",
+        ),
+        niels(
+            "3a79d244fafea11cf7cfebac88551bc0c084f5c34829a5f907663b13294b75bd7f1ea2de90bea754ec6b57739c80ce7ab6e0cc33b7f9fdd2",
+            "a4a87918cdcfd38d8c4af15651184034e61448bd7e5b2f5d37c3505088f6daa20dc0323841d56dd0d83b1dc5838d8f075b46ebfc18490cf8",
+            "e35cd9e64fd7c68e19aa126eb9ce6201df49c03e2c99b759e1600760d6a2e1a7670754a1ac68df356c69a1c9fcd4da560a41c1d7fb47b3db",
+        ),
+        niels(
+            "5e5a1c2f0849b8dcc4047d02450fe7a9007bd08ece522512e15288439ba7ef0b219977039ae74e8fd66dbddcc980718b540d51559fcbe7ba",
+            "986fb9a06d80b35c15fb001a93ceea914b5d91c76c05f11a2c180cb5b87467535815d7492ca087514e3dde6208c17f48e6bb9aa0df3d5ecb",
+            "e44cb1960cf5f3bf980c160152b028bd318da2a2ff0d61804d0ee3e8d668f1a5ce4f21b5416752af538bcccce404ebb864d876495217dd3a",
+        ),
+        niels(
+            "7e94ee17dff170e7d1a8c5c46e6f601cc07ffe46a6ca9bae001e24ea25a656d4b60f79e05e149a84045f544204599697b42a75151726dfbb",
+            "65894646a4c8406488f236798ea4f83a50f9cae820e1b65c8629e70ac39a9ab10542b555588254b8045cdf4bfd3d4505ceb71e4cbfbeb090",
+            "45dde397e8bcb9bdf7557e8b7b019a2ed1485f6123da41c9c24c2c97ef0c6c5cd21a3459098b1dc34c0100dc2f794bdc9e482f2895f6a14d",
+        ),
+        niels(
+            "2d0d724b7044e6a9a6a5e699011cf80be944c983b72c2064abd31868d2797f403cc21cdf55970be2f1be6a970323fb8435d3f1a0bee8b9c8",
+            "460657d8bf930fe89ef2df9b70034fb4d66dd1473fdce429171e18cd86cf892a14cac9ead5cd546f7b2195a9d12d901ce58630104cf90d24",
+            "925d8d04c6c3f4cba2cd47377adc82791b92d016e3297da2a005407832894ddf8d52832b8a4af3d43c28dfc505db78521f3be7885f117f70",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "7860f570b16b5d253146880f501fc1a4ab7f99903839ff2843b9fa79c4de6fae52df7633f4a9aeddbbc7ff15082a40f4cedadfa1a79ff231",
+            "9ace87adf0337bad8a5f9892b8e987237baf1bde10166cf32c7469fa5bc0c33261f9b3a5507e4a5477455150758bd742e9cad70e273ff58f",
+            "b692256097d48768867e3de03871897f33c218c6b7d6c1cd82d1f8c0cec779c74928acaeb73295214579e8323f772382aaa9041d1170703d",
+        ),
+        niels(
+            "665e369847c1fd58c3742af0fa34c2091454b85a0e299cb6ed0b843a57819de858ebfd3160130c7a6b7d0c8cf9595a63a8ebe89795e44844",
+            "267ceb2b37469ab0f7bf3196618c4e813396435784a7746102cf78156a30b9b3ece2077fb958249a89e67d045225efc982935424af4d31cc",
+            "3bb2d534ef90c445f14edc3aacab1eb822e0f2f353e70e461d73750b36099db22fd8275a3b2608ef6dda9a43a2573f1a6e2eb0eba930b7e3",
+        ),
+        niels(
+            "4bc4dc055c2a4f2506a5c125ec36d8a4db4db089b61e6e96ba877b6eb1dc984c16118fbf1740e830fe5ac004010b3d73e1a00ffd51b97d35",
+            "b9af5204b9facadcb6a3309f08507d88133ebc31556681d04b101e41f190ff78ddcb9a7e492106b298ec8926809d6e2147c992b191cde31f",
+            "af37581b212ea52730b3e6da88bf7b2b61137b1a42c2b2f77fdfa3f8eb5f96699799edecbcd4a8713bb7959d4252085da98b1a1ebe13c7a3",
+        ),
+        niels(
+            "6a9eb31fbcc72764ecc3d3b2e0d41bcd5380984298380ee4a7b22e65d020278ee30d010e211e2253277dc78e9cb6e18484e693dc146bb80d",
+            "b33fcde9595c05267a5869d425806dbfd8da9c0eee2eed40852cc3a7503173e96cc7e38dc15ac63964cc919cadce04c0bcae8e41a51b313c",
+            "e4de9d59d3302d10a58a994ff3f898a48bf587b654a595c5ea53ef93b3c07b521ba529e44fde918974a83e3614db440a957751a3b37d083f",
+        ),
+        niels(
+            "0ca78a977da678701033c3b134df236d9882895cb6465c0486c15f138293fa40fea08d0a3b2203df8a83706610616396b0281e717aa95c9b",
+            "bb1d1347780f1a6c85d7524fc8522d4df1e817c5319d539ec6381e731cbe328fb5d9159767388045b3f15c2698dce68f7e378245312c1cbe",
+            "0521d88bdacdd8e8d09a2de281f972cc3891216eee7b7316e33c9e0bfd3dd7b8fa3f1065f5168d5e3ef50c33f2288d8b4be7129a1b6e475a",
+        ),
+        niels(
+            "2663dd2d2af3570d7b67616732a58397f3f85718d8da19a9fd11d0622c4962751d60073e6ae86128b7934d79c86dac74da134e3b6d8cce98",
+            "cbd4a0f3124af30a9d6ff690abf7a8c5b8e27914d0f001dacf50a7b2fa810f779fc3188900295fc622def4537e4252b53796fa6faeb00ab4",
+            "ed31ddc89f0852a04ce52940cf2c8cfe9aacdc327e9bcbbea4d4069cf3d4872ffd98c150cda971ad2cf37200d196e2cecd68beb20f04b15b",
+        ),
+        niels(
+            "f6b84e14f36f26d004c17b2a361b31369f6cb8d864933bcc45eed92c7f887b24eabc4d093d93c927906f051bdd4304977c21e110df0de9ea",
+            "6b8f386e79a98df33e20b03744918fefa0347b49ae72860a4def103536e841a361f057793756b10a0e2ec4cb5702322be832d44e20f9d6d4",
+            "d16f1a1f2a4f588a71f56b42c6e28e05faef1e04644219f22c012398715e52e574111648e04b908eb19c5c35ff28554f7dcfdb16de78b3ce",
+        ),
+        niels(
+            "f8b6e4dd3a6b8cf62080155539822077ce69ddf5402dc0ce419b81084c8c77b47bb087c726960cb57ffd8b853fb8943c9579552b29d313e7",
+            "bd4d5aa6c50eb07a94057ddad030ae0d0cab01f0a7c4173d685025b996ed990338c75d76a913de79a931ca51ce820ead6f48c2aff006a326",
+            "2521c06dfd0836a7dd3c5525daa6a569cd408c536116a1262a42756bac4488d5fa8f550208e1d752c4246a885b91f8b2a292ab3c1e44c46f",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "6c41a40244195ec704b7131872ae39a674de63480a9b94f9d90a79bf6115001b2436764861c8ccc7b425aff058005525434ea6e43ae0eebf",
+            "637a16cfe62c10847bcbd4d0a972d5f73d39de37a1670847b7c92478d8b4a9b25f7df6ba8904fda6c3ceaa2e243d7f4d30504e93f5a1b4bb",
+            "575fadb4ea76cdd63ac30e3b5679e26b6c1527fed58f6261ab1c8e0cf578bc72886d6fa6ee903e1ef5ee075c84e5bf4d1fd41962f4a41dbd",
+        ),
+        niels(
+            "23bc34b7d4de51cc8e99c0412ff9595ba50850c319f3747593950f627742e6e81303ed9accfb293c1d69349fe65e1e8762e7a1130324e15c",
+            "eb6a45fd12e9e75ed5571c89c7cab2cea2d6afb8a066d3ab763c7bdfb66b92cad356cc9ddd173c747d99cf42af51dfff977252868ecc4390",
+            "70470c80a3133b80774f45cd878abcd54964441fcde0655f4063e45cd06b7afed171832886dba1865ceb06f239b6298b5bcd1a883b64245b",
+        ),
+        niels(
+            "986aa50897b94bd8280ae94cc3cd46de06caca8d384d84106f707640533dadfeeb8fe64a9dd92c94aef9c7f5f30e487ef3f3632978ec6055",
+            "fe92aa25fe071834971d5e83776eb31c9aefa97c980ec443420046673824ef5e397d07660dff6c53bf8d18f33f3e1d3ce7732d4a9fa6503d",
+            "4569bb6400f0b36de49a506c3d7356cd7b31af552ce4fc492bd40e30aa7b2bedbdc2da02f7555e28536ffb8cae459ad3d7f71eaf2b20d865",
+        ),
+        niels(
+            "3de2fc0eabf10548f1160eaf581de46a9aa019bbd4ae7d7883c3c753d8cea81e59f078f59269fc9b8a9a5eeede3a1098824299b6778465e9",
+            "8eb89b806b07f7df66e693d28637c2d4544338a525a56e2f138e0141b795d8883db90dba98becfffec3a59925f8d991a7e3c5a1356135ff1",
+            "33a687da3c1c350a3ee5723b4db7ebaa1893bca54e547409051e84696f4b4151866b2752eb12c5022b5195fad4c402573fd7ce50bcad983b",
+        ),
+        niels(
+            "14cf321f75549331da2e63c8070119870c82fe64efc07933a2efa205ec0013793e4af9f60e332f88936b9891725d2156ebf9c6afe72d7b84",
+            "4589bde79f8436951641411870e6c26b24ad16e0eb34ca04a6f71eb70a13f67921f5a5a9679fa0a3f9d44053994d1e66b6c6c892c7d63b49",
+            "c02b8be60c50555671a0bf88a32c5966b08e787f100d239067d3c0c5b5c2d7271a9ac877909720588b48e89aeb1554154a2b11ca57380686",
+        ),
+        niels(
+            "274a94d50c48e34b62909b28a0e098b2e18f24137a5482e113429470e252c21c2d6bca7aba18f0c170aa8454ebca9efd33a709af7640521d",
+            "c57af153abbfe2cef146f1851b716bbc8367e58af71e820afd051806b7fb4608cf789e159145f31c9d310b3c9ae6a842d2d5536e993b267c",
+            "81f141f312e305a305819fc5f5651f7ddf2034bd801d95b3aeb2aaf85d59cac4c3f187134c1bd78564a492e8ac3dd9c06d1aca5758d45f97",
+        ),
+        niels(
+            "17da7fa87a44d7f0748b18eee821c4c33d9b4737b7bd7039eb97a833dbff6b4fb1b38dbd069a214f7cca8bb848ab6cc3fb4a0883af492b19",
+            "e9fc820393e0b273b84e0d058ce73775a99b9c9799130902c3da4149e9816804a78b6e1fdcb2a9d9ef88cb13e7ab38aaec8952c5b14df2a3",
+            "e7ba58734d470a3b6a9d787b7b78751e5cf8e0957ae6f2e22600b1c1bb50eb37f1d25fe8adbd7d643fde64d6a9f76dda42baf3333655c864",
+        ),
+        niels(
+            "5047a4497064ebacbe7d21c3f620af47996a5362acc3a3b304126d30612ee9fe126dc75e6931237f01848a2cf52f9098edd4894852a4633d",
+            "ea7bf605dc24612e480f903c2b6901f62fd6a43bd98bb548706c131d5c2e3ca050411e4208f6989c7fc087297fa8f36fc6305333f683dbba",
+            "85c5936d4a98eb83c250a8706f475e449d0137edc359e829fe3a2ea993a37fb2123378ca1c9bb813e7d353e903879d9ea10c4ae113fb6d68",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "394c88fd28dffa0ebdb5a969d8216b84c3f203052abc9a8a8037266c4d1f6bc7ea4df665a5e864b3d9924f119ddf495cf5ad143a77bb58f6",
+            "f6110e185ecaaa2bdbfc0779ef926d13f6fd4b57796a60e2cd38dd78e8b246aa1bd81afade7ec3ff2e6ec3a58e53cfa9b72b1071b9c659b7",
+            "60479df648680124f67a10a52154804fc468b42443ede5840c9ca936937864e384cff7d2abd818f33d1fe6f4b4b0b6884fd5d540029fadbd",
+        ),
+        niels(
+            "f5df8d3ed1b97c1dd4a1a6c2d7e840a04d0e8b7dd4307fa95530d429c544423d94d007cce5a3c0449f700cd0a3d477e2df644ac3cb1aa8c9",
+            "643a6a03c709c8e672707b3c93c8fca8a9d6852d7af5aa372a07758dbfcaa9d0fb12ccef340f6f7be62b0dae1fbfe27a2ff0fa4fc7deabb1",
+            "c227a1fd62987deaab93123aad4efcf825a445fe74eec512074eb7f799a45330761c6076e06ba98c7fcdf25aefa702b6975ed7f3f1572811",
+        ),
+        niels(
+            "592bbd37a60acc31eb4ab775dc644b431387bf25306a3d2d79e86fadefadb7a7191eb41f4504d6aa3fe91acb27fc8de68290c8b3bfff702f",
+            "f4841b1fbd9c9ad20d5e067d7dc81d691e97b6a98a934ef411e37048892285c58745760dbc1f81beee683d1b65f5f48be45d7d6e01a42e06",
+            "e2f99cb49d96f907d62659109c862986ec8a184f3ced0d2376facc0294dd6bd0e15cd1cb917f291bc6e7af879992f094ce42168642bc8c6d",
+        ),
+        niels(
+            "9c1795018ad334d9ea203d419c3d6154c2f15b7895341aa38c15f8c022ac860f1bd7802006b31053fa2933326379205f35f6d6496a674dbc",
+            "e47de79d5273f5d851b40c6f5278786f1d5236266617951c4074d734b2037824c3a58b6b514d24204067a7eadf4299f7887376e6a0d607a5",
+            "0c9557e67c1bae2474659dc460a160c6f12778cec2eac78244fa17cbada87e4b7ca5529bf0a1f8b86b12c770a33856f9ee62e935ed461677",
+        ),
+        niels(
+            "d79ec30d0e6f77b14d155e9b164c9aeddcb0e4d0e79eafd88b54d4be43a02ba2286a1d6cea7f65c28cc63e3854250a730f063e2788087b88",
+            "8602bbd9310f4f4f3ff0e6f4dff05eb085d68e336534aad84262b2c6df6a20d2a507eb6fd3aa70c31f2ea3aa9427337e4ae2a246783ec45f",
+            "c5dfda0e4345ebc8da3c3c0c63280bdac12301fe04e27de2860d72e1c8bee9c5c91ca9d0cd61385e910576ad9250526600231ef40b2e1bcd",
+        ),
+        niels(
+            "1b280c2e31d6c2ec0bbe0a6bb435db5caabd129194b66fe113c6327fedaffd2d0e7395c29dc3324f3bea3910c69cde9247f0ac8d8c995153",
+            "d8d66b592364cebb991f2ac8c4d1ebb290c848b767be8ab38f58877f6497386841bbc9439803a4dc0e69b879485b3c3882664eb6d47bc4cf",
+            "39cc39df090a49a88ecff7615d16a6af0d834bb2630b81d27d9c676dc258373faa10102d3e0ef4f12ecbaaeb1ac586a04335b5a369943a10",
+        ),
+        niels(
+            "a01c35b3ef78e861a1c3de252485420f5703428bd00bad67cbae003f7add5ffa018b8e33eea1bfd85fea20a62593357b4617f278acfafb5a",
+            "26b78d5ae9f9d556d5750e4b6625a5cc50a5b871ee87d4ec7937a256db5d47c44474332c5b634500ee22a9a7425bfbd9002c5bed82a0579f",
+            "9aa77d3e76c549612cbbf7e4652b80e5ba84dc4ef869462d5b36cf3835d9346211aee3caac005cbc89994a2b26835782b53c4bbc2dda84dc",
+        ),
+        niels(
+            "fa8abdbd03e02dd3c1e7484a82d3896e4d096186d8317098be0f0813fef455f1b1564025e1bd9946ebc438e245c344dab995e4ea4dbb7b8c",
+            "20048b0a5405d93b5b22c40ec29ecc4f04ff65a576cfbe6bc1afe8426be3a78ba0e91d9b4e31ca75597fe4facea90a0711519211c45ec11f",
+            "0d695ab226c1930b9c77dd1e03e1c09a9ade8b20cdbfd2a4bef691018b00cc51d396ffd24a17196a4055faeb1b87c8158330d1314180b33c",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "971c731a50f008e017c75e5d6ff6f83b54751be6f8d6e6132c2d97d1e99432e9b7e8108d862d54fbeacd1d6200edac134bcf73d2d1a1534d",
+            "8b914d8b45cc393eb7f18d4eb95be92b93cb4103589c6d83d810fe965a3463b71101cf6c3458e963fd99cbafb7e72dc6a31430ca6c9edbff",
+            "455470961a4cf95a5b85c1d096d108a32e8e2f8501997437ec13b360ad56ad7b216ce8905dbdc5a4fd0c3ed2abbaf80a58a2bba8411a4e02",
+        ),
+        niels(
+            "cb6285f6b006b4d3ffe33c94f99eda9312d69103072114c289e8f9a2ae8c7fbf9aa0e2b12719d8f6e2e9b763d50fbc834c0521fbecf84f70",
+            "c2fafb9adf5b712d7cd51a8829aea39c73aacf8c714b757b79feecd5077b556c2f4e5f86429bed79e2abc454c22e70ec57bf06fe3b25f925",
+            "e3d849031ee50e2ffcb080e267ba979d78265f2ee16e473e1a5fcf89820a9f7392e23574ef8ad5645bcfc207e1db47e1c34c00cab7cde6a3",
+        ),
+        niels(
+            "a3604e5dc2ca32e6aac5867ce699ca231f35705b24681c02b7ef4f1f57452c85434dc1f9b9dc01ce896f529aa253a3a4e23f67cdde2211d7",
+            "34e157a5c2a913171c458c3c15c28f21cab6e4c1bf0c624928d4a286219f63c8a7baaa60ba16cda544af5bc5e850587199c2b26d66ae2669",
+            "6533e10c7b364bee1e7957267d7858467240a7f5188ec05000dccca104a22e0212be797be66dbd9d73930970e3f4f9682149abe58474e203",
+        ),
+        niels(
+            "e0c33dbf004e504875248c291a486f8f3f3539aa1d07e7eddbd566585eb2095c1fd8bbc82326bd985dfe743a625e98b763af89bf76399499",
+            "9e6eb606176893512163fb3a676a98376cb43364967a119db11edfcaeaae619bff5c869a3e428eb9d5f6e85b80986caf33967607ed18dd67",
+            "e8439d9f22784ba7ab3a42f1d5c8293ed74ec935482db70ffe6d7bfa7e21a5db8f15c53151826e626bac4b7de02c947aab48624b2849b566",
+        ),
+        niels(
+            "56f623b74621c8524739c001d8d9714d4dc843f26dcfbb08fb901fc249e1f7961a9e41082a7b4ca9a1c86355f99de851f1d4d6c1123da30a",
+            "06be0e5d47b138f6f998f521178b5c05a0c81d60efabfd13eb5d38c5752ee5c90d529bde4884858e34a4be074aeba0640002295f6e8aba40",
+            "980f4e212686d657e1b5da93d5a4eb6df6728d6dfc72509f2585d9499fd08f055824a540b820b228b09ca868328ccea5a8b5b89e5f8bcc2c",
+        ),
+        niels(
+            "1bc80f7b6b28cff82a1579c6198f97ec51a94c824bec73640532e00a40440b09eb5915d839261c90b3a8b7a8229859b3c06173c2c029cd3e",
+            "496829b03623fd2250d3a85f42e4a76e3feff74bc92471e0f5e2b1488db84303e86179a4a92db1d85c343c36d06d67d345ac2b5ff0b4f4b0",
+            "3fb27b80778d7570a0a3ab95b3f530296f41602a99429b47339059dfaf7155ca1d471ed28174fe346bceaa1430f9ef4e8586ccb05777c67a",
+        ),
+        niels(
+            "880c274aba244eb8e85462fe039669e7e01221151461c5530f9171e1c23d2d6eae6323a246f60bd7e1b6d38c7f755acc99851aad79fa1fd4",
+            "34bf48f8863a0710e66d23c1fa04d1a182929d5e7e4e7c3e038cd3e0840f753a45dfa7ccd60a3658338c36bbce9f1763d8defa87f28bb7a5",
+            "cf4eb6b52a9b23f4b961e64e8d5c1022fa717b038997d43f3362c98413a2c1a1693886575e2a64dd9d6fdcc971a884adc0b8f67ec35fc6d9",
+        ),
+        niels(
+            "0ce546c3785f51fb5826ffedb827a065137aff19c6d452dcd6612831c18b81eaf0708102dc402e8513b7271b0e94d20d68703cafb20a7cd0",
+            "3fd22aa5a92630666487ae8cb5f91998f6eb049cc06bf0f1266d7bcf156c54f4bae00533611b78539af47d8337aea989d5bd9f9d2b380b7b",
+            "cfea4a56cdc8027f97b8a540a50f158b08917bc801270130b692eb6e2f95a49fc535eb7307fee0c0dc50296364bf13b6c02e554dc86d745d",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "cac0e64e9c7eb097955be2375c4a1a73e5909e4cb34411a8798b6a4d17d2dbdae2b355e0e95cf29b7f19a55aa6351e75030edef8b559f584",
+            "503ba9711f45c4796d88426c702f8964e36057ab0fd5218d1bce2b2e087651bec06a6be26393e67540eecbec4b4e3b49d24cf8860c88dcf2",
+            "e1ea55c24eb1cca38f36a3c0c0c880bfb7f9b31a56e0b310e1929bbc55ea21a161c5aa57912ab9c325e32a62c9547cd3fa3ec3ca0b7444f6",
+        ),
+        niels(
+            "ccdb6ab3a970f1e5a7140880cfac86e726dcf2dedfb8440285894a72c18c11495b29a77911e100c4ab9308a3f6cce4c010dd99460a251729",
+            "7edc83265e36d7949f367522c7c0a59e2482ef18b3971bc0a4b35f34daa233854af539a16ead553034a57f551fb437125fcf84de59f4a0ba",
+            "3f7bb37db16dc8b820e81da62e7269914894047d0f4a095317497ba2c3c7c8d37ea2f75e5f0d014287602bd8820ed802439138cb1c133e50",
+        ),
+        niels(
+            "60ab19383b8088ef53d92e4eced9674c07a1f22f7683136645b47a926cdd5c35ebb0623290328b80f6a03ef183cff98f5dd09c59ef7e5feb",
+            "734b96530ccaf16df65af59666a6c3ca3ddbb1b5940a8fbc08c572cab31c042b2e4e8dc687bc5e848290a475a838100e61d89213b4c6eebc",
+            "67708d8b7701e80955908ffa2987a67a7f8620cc8eb753de02a5a3020fbaca600d51a6fc05aa971ffa0a8af17d89743783da79ef32ce0284",
+        ),
+        niels(
+            "5d578ece03f568d208e057313c54a62fc463781e06557e693b2b75076775548a65131ed1ec61f3c1fce6b5851263ff16d16eb82a86a65fff",
+            "166b748475a64b593253831dc4cd92fcad9fc5868e3975a835b4d9814387b6fc76fd86d3aa230ec384176ba493423d685383e2b18f887581",
+            "2f90189c0cf3d013e3c0455dddaad279837f84b5e73df3011a52566c36e0c7f3914590da7d9ce12ae9dd90373f9bcb036adb5f70b3247dd0",
+        ),
+        niels(
+            "fbba54053309328011e8e926640edd665368022f3a5111b6d4d1f2c731c92c4fad64a75e793ffe88f8a74b6317ff27c55d92ccccce40c428",
+            "43c1b13e66acca3a357675dc8beca06b20fd1ad3195166e6f1dec8ee0d6e137626399c77cb6d0bb8c31f4ab737dd4ff7b27f4be634535ffe",
+            "453338481f46c2143ed55711fd2db59294d05fe6f580d60bbe0410edabafd6cdcaa225be10266e4c89cef859411ff4463f53ea13c32ec607",
+        ),
+        niels(
+            "ca853fafd5548821356161dda4f3f7cdb669be105319f8722c770380b098abcc926742a8bd75624dbbbf86981f8fa7d78503d3ca600c66d8",
+            "2660042d18155b672f1fa46c47d9a8e2303f7013414b361ab654ca9dca718a614cff9915236ed0674ab9f72d687bbca4fc3b45d1bbc9ae5b",
+            "a66bede8466c3bec24d9340c915527530c1492a1adbed42d4fa11ddc1ae1e266275b732d284636afd4fe888ebcb295f53a1b98878d9cf19d",
+        ),
+        niels(
+            "aeea8a9f8c571d40e5791641503095a6370f9728ed9165b1b37665f4421f21ecb5975e5343bf242fd3898b0944a122d1fa7a8c40b1f09eb5",
+            "a3bfe56c14df356e341c6643f96bf69a0d83fb5166f2c87c066c2e6bd4f238a616d745ca35c495bef7cb4e8637dadd50816cb9678cabb6e8",
+            "a5337c026ae419bf11aa0e4860900077f772f89c4be766741bcc1c1b5d56bec402b5ffcf7cd670a8bf232b33bbc04464a06ccd68435b238e",
+        ),
+        niels(
+            "cab0f8a744229c17892bfbc95f0975510b30fa9d872a72a2c0aba57ebfd5b30e30d33b4030721de20674344b31fd741ec76810d12dfa2aa4",
+            "83dcfa3ca7479d3cdc22e96c8405335d5a77c822a20d9528eb57ba66de7ad25495178eccb730ffc7113c4e3c3af4de1a6a63763e102c890f",
+            "946b1110cd51b6559cac7a9d2129fec34562615c3242e739be65a01528cb42dfa87eb6aeeca032811d543f8c09078d3fdd1b4a6f2f340d73",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "861706ccc11b90e92f4444d23becdcef37bdd9e39f06009c56e2fdfa87b762e280c7ab7b818929906b8795c276b1ef2370f144157b7c832c",
+            "444227dc103b4d04cf7ad207ce48af9dba6f12b9af8762e2849a8e579c5a4b2a6539ff968de6238586c4675f6eb87c34f62c84949970564d",
+            "584d1b4fac4d424d5eb4e67ba7cf49ccbc30a3e07da285e92ed3df41e500306c884c57de7fbb68e7185a2a786772a73b86f6b6ef79f0dfec",
+        ),
+        niels(
+            "bc97da9560798c2d8b51bee75ff3130085b1c532280ac1e49b5caad74bc1e7e6d4b871edd6b55f77bd400b6d8bb78e0ce6e8ab5f1adaa202",
+            "df05d67565b13d0d174324590839fa295378fa76bed3836bdad195d38b5b70f2d95e199003098cadaf24d3276d3ce1181bf04c0b7b12f60e",
+            "958593febf496a474d8eee5f23981859a3059b2360106c560c234e62651563f84f32b34bb0c620daec15836553e1e4e989c81b2c4053b627",
+        ),
+        niels(
+            "8bfaea6b7325c24d1e236d22f274a7bd5219e6ec53e2dc32d3c3cb3089e5e9751294024371e400fc2386b3cc1b67bf163e0da80fa4cfc367",
+            "5a8def8e4417c91ce5ec628d44082b1ae4dac5e0759a9e68f466d4a0b0f1e4997e17c34c225afea4eb4570209c49e69bdc1cd3351a5296c6",
+            "daef5beb3ff88522a1a1bfe491dfb6dee63aa5ecdd214330f48086e2e1a8c14923c194235072a5fe93f4f06df7b40841fe14289bb49c06bd",
+        ),
+        niels(
+            "d9e8aaa0099e031e053909540d226a6ba1c9f9e6a2026437dff094b3d8b79ee169f54737a549011b032aa858475f7fd9782f60bd0537033b",
+            "613e61d746dcb09189d876846329c55f6cdeeccf8ba2ebebeab5c0c26b0047ca1cc1d1e31783398d4f7abae34b2a4a797ad51118b731e737",
+            "f8d474746eacef7f3791171ba7b2d34b565e4eb7693fe713622a3a87df797ab4a4f082de58a4a7c73aef40340faf2569ac3ff543cc4a0b60",
+        ),
+        niels(
+            "e529409012ea46cdda075e270df08433fc5a09564b9b66c51d497d55bf00b848cb44545addd76505ad3fb3b53ae7954b589c999d300276fb",
+            "343ffb55c8c35706fdd5de71495bb37863d21daf026b75b8ee64e0f2ebd6443b6c7d59b924bd5e5c920516fc88e272882bfbb477e684f592",
+            "0e91c12055d22f6efb9c5c8a51ac37cffcfd486fe68025df558febf81c770599fc94d5a4de32e1124f2a391aefa22b419a425771895fc3f1",
+        ),
+        niels(
+            "31572de2b72e06341e313fc284b58993b07d0b193d65360718a954fcd32f27a9686982bdaa0d5b2738e7bc3a06c13dc5b31eba5571bcb3a1",
+            "aeafeda28dbd52b6c4786d50fc74aaf54045befd08d62f967a8df99666ca17f3bb0550e90b1e26773c31b06122739c79e47cb6c253af53ce",
+            "659970cef92884a1e5eb57c860ddeae9a82908f5fcb27f9fab2e22484c88bc3307859af8e12dd4c9830ff1502e5d8c7708696794c7e6f544",
+        ),
+        niels(
+            "3dbe378eb0df9d5f01f5bcca143a0134ddb4381d66e7fbd631c3d02479c3dc65261a71a961cdde324f8277ec35d93a04c486876eb439d6d1",
+            "90c9872234ec5d6c84b32b66255afc6f92341551a3e26894bce3ae9c64e118be8e1168db5768bc105ac0062ab13e261acbaf2da0d27211fa",
+            "d9c60a71dc21a7d805e840afc7c998dbfd0111da10a6f6c6094cd48a4120375371bea21e614978c7372fcc93c7301c05bce98fcfef101f3f",
+        ),
+        niels(
+            "fd04f2023b59dab8b550fe41619e077970855043da030fb7099f70993e83256b041e00aa3e3e7f1f7c275bf5f8fa766b6cceeea56a8c74d0",
+            "8f09ec924e7dc59cec901a9d7332142f53f6618e3f47bd715b8cb120ba7ddc9cf688728c6e4001a26b01d50e6b816e250f172119a219e6d3",
+            "dc7da55b8d8b4b06fcc775e5b48c3cca293931fdf48f4622e507005464303ff0db1868ee984a662d092b9f5b38235e833e858289c6d514ee",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "de0461dbcc91cf7098e2bfbc6ea5cbae5efd196a581cc738c486f2224fcdb4094b503be1d871ea34d4a902442bca1ec2873edb9da2b599c3",
+            "5727d5ccacdedff1d3235df7311e4fa6c8b0705d38f53ecdff6a87ad49a31963b89292d21dd2399a328573f90a725a9cd2f927f6e52413c5",
+            "9e5b21d082fa45c50e199d9d65f0a53de5513ebf9b8d5a2ff196fc601b7a3f2a3f89ca7296ef29d2ccdb00028ff845b608b8e2e9d7a8fd35",
+        ),
+        niels(
+            "a94cad00249c948c07569f8d5cfd6c7280dc2f43843ebe4cb1e20740ea9b87981f59446f71089bf3890d605227fb43c462c0a92e90b0de6d",
+            "85541f0e31673645e34ac8ab39c4110aed268493e20d47f38e28a03594653a9ca44bb4ac538603b5ac1de348a21c71c3a8d6bfa5b6f896d9",
+            "d5b59ff8f07aaadf6328cc3c322b785aeedff6c8c4aec6942fe146ccc18e259b72d54a190ab1316ba0590df84f3b8291c1a53a6e663af2a5",
+        ),
+        niels(
+            "e8a25162d7f9f3b5045c6501b7fb2ebb1b1ea87fae92a3ad6316f7a24ad3e6beffd78b3ca8bca4de93cb20bddbf28299fa29203d02173796",
+            "080f18d6703748e3263e19f0691ebde8986d805ac8488512b23739095ee5900629427ff93a4d54228313252d27e28d096896ce790e47adb5",
+            "f3ae9b75e31f02a4f4109bb5fc9761b34f7aaf1d4034353f7db16f09191a94d12fabc2e305f665b3f27a95c4faa0d7fec5ce869a83747e1d",
+        ),
+        niels(
+            "808748995080290fe6a706452b63c5e1131141e571cc82d0fcca00e9ef948f1d8ba8b4f5471816977b99420adb34c3e805f5d92c624144b9",
+            "85dfbd2d87f6da59dbf243d9af40dda486da9d6bc45f892bf83d944fe2b1be0d96b513025814263d4ebea487eddc3c8e23333ff7f03606f6",
+            "0ddf03e45ebeab5b74f59253954982c2a180055c1b7d050088ebfa9cbaaf3f99cd035fac0bc8d4ef2278c50aad87ae8381028567dd30979f",
+        ),
+        niels(
+            "681b345cedae96144e45132d886c81f6c136f1fe24b79aeb4d9e16846f4d35c9c5f8fb6d8043488895a3aaa954b0fa44e780b26a71f20df4",
+            "24b61aa5c50bbb59f638f27a001deeca1491aa6940c26cdda03f7a0a40f672c116bdb5deea8ad2aa2b2c7d2a0616a3eb09d70ddb50490be4",
+            "c8cbb38b9ff00e5d2ee0b0d3f7b316ce05b1eb93eb38ecb3d51daf7148b4d370d8acfd89e2728f5b998ed6dade8e7ebfe6c18035828a0b55",
+        ),
+        niels(
+            "f71169ba40f826564a552ad0c7b726c4e6a0bc50ede909a5e490cfe2b2512ee1d3dcab996e20370f8e148a66bffcd84cd7236fd77b858e10",
+            "6b58ade15a1b22e3faa016f85dac32bd3d3de681df38ccbb484ed855cdebb88e24be7a54739297a3d09068e15095f00e62e90cb37e2a0a4b",
+            "b191381a1523a74f60c5be550c5ecee9f6cb946dda5965c4a1dd90a5e3e032ad629113fd2036651f1203bfa49019095931b17cd19cec953e",
+        ),
+        niels(
+            "97f509f121d5f49bbbb79918038ceaed9915f44357a86755439be1e8c5492f622c6d8d17db3ea27e5451903773b132afd52e935733863d14",
+            "3a1b1bd08c16be0e3ea7db36690555053bb73a7b5645ee57c282a08b3e66fb6704e127de13982f1f8a9e5cacab730b99623e8820de634d40",
+            "a00b730aa09de1c131edac7e828f3dfa767a2b0b486c8b4578b2abacc14c695bf7f68dde0bb6c58a673c376766b316a93be73167edaa8c8d",
+        ),
+        niels(
+            "06aa75cfe3f6da05923bff0e04be435bdfc465bd01e6df97232c86c5d2ff6e3736474ce5c8c8a30edc6188b2803a1203875067338bea24a3",
+            "46fda9de662fbde706637319456b6be3a044e7e7cf08ff2eeea7e8db27a425474594bde2214ce020c2ff4ede75d1d5ee404230abc99a0ee3",
+            "a312d380abb17e61b50746b95147ba8bc0f6a14e50808a4d05451b9d4368d7cef05124d0ec7c53eee77251deb44c41f1f45d5145ea3e304d",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "849dae327ca55043e45f6b9293260026022c7f128a048b316b971d894a7808c13edc320a04b06f952267ef254c2aa91bf272dbfb99023d38",
+            "f18c39131a497d8598505abb63ceb1e8aceae6e877f435e92d830806aae6842bc3ca34751e03b3f76601620fef11dd0e755d03ab2d088efa",
+            "3efdd5196a5f79d55245487b8cca5226a82f4c520cc5f26b006c6cde9bb26dab174aa49e3b92d468bba75bb908c690b86a7d55832eada444",
+        ),
+        niels(
+            "45dda27cb004a4e8d8dc21a71fdbefc003b59a9095d7a749bf433cfc631d5a292c6ada623ecf3be89987005325c84d4ac89464eb07b30e08",
+            "a5327cf08f8012b4aa39b62724962ef2f29bd8626b86e321d1d515bff61bf44cb6dfb0a71ee5417ff5fcb130e05f7ddd68994418dc2e65fd",
+            "358cad0fa5baf2ff28c44a52d4e1b98c7e7074eea2b66ddaaf61bc85bffb3aa102479e47e9682f6715e106409de97096828bac1959ad9cf6",
+        ),
+        niels(
+            "1649c0dcc54164fe4a50a742a7dcb8ba095cb46e48708bb106e70c38c2c02988781073827f31047ebff4dba5f0ff471565576efacc513546",
+            "c043164171d174349c17755264690edf26de124053114d5a1be0a3da9c79b563a0087f6c9dbc62db74f1c2cabcd3722502f7f7ab3e434dc2",
+            "c5fb5af74cf65708dce39bc1c999f9e1b6e41cfbbe97ecad9ec027477c2c27a5881c06c009e21ac330675661de00e06d0b729a8e0f849a65",
+        ),
+        niels(
+            "01d2c1483746ae883241545616f18a86ebd6585405d894046fb7c2250767af295e37f32a4b6e0452aeda1ba01e000f2bd716048d26768b3d",
+            "eb2eee8f258df3dc7703bac5aae29fe07b473b157b69b384cd42ea6c239c8a5fac4d5d6dd65a42103563786d0c1d4e4636f0e42ced977259",
+            "d766e45bd2f6948d5191537983b37e5812f2993b583b7e463e642812872be17cd8957943d6b15f8a2b65876df1342c12a3b512cc7eabc120",
+        ),
+        niels(
+            "25133ea825c6b860b4a40ac41846b676d266c05d648f0e0f7bfe774bd78f87ff0262dd5fba9f1b9bb2eb8e653c1427104d16efb60fd1d186",
+            "984458c95c80e13fe9478ea1ce20b4e6173e7b69f36ccdaddbc50faa744414276838c1d75079ce12421b5bf48114e2d97c1e13bc2c61ad52",
+            "6f78209be42f34518255d91bf557da94511098e0dcb2a862a74d28368ef84283393865ebee92fdf23402a130ef808d8648a58f23c6f9cac0",
+        ),
+        niels(
+            "e0646f4858defd777d093be0080602f40e008fdb5bdd02e224fc1522527302f58dc539dffc2747d4e7677d32a8c8f36746c0edc45ce31553",
+            "b9f27fd6d58beea7f646676360a117b28dd9e904dc847df873313a801799e6b99e9d58aa3a9db7458bb14a106a02ad4d1af3c61bb890da93",
+            "eb775b6cbbef501cee44b36f75a65ec14d1232576be9594fe0b937d7e3819d8cf39e26aa2783e70a32eb5bb270fd80a27b750af8524fa9b9",
+        ),
+        niels(
+            "9ff8924742fb267f796385f9df0e44e9b40af1a882d0ac9b9dd5621573205b47fd0098171006b896c6f34084f7053de55c38a44816c6b3eb",
+            "b46dd463f5d43db5c2cbf31d2b71cf265e0a7b461c43bcae9c908a3947521636ccc2388c6fe953e087dd43d9edea32a19915c82ff9e6a490",
+            "3d816a91dc53496f6cd5b0a5eebb17fe0f4c5f193e5a3a148b0455558c505c0906f582dfe197b52fc0b090085b74e4fdc92de254e3660e80",
+        ),
+        niels(
+            "c102dfdd3c56c98d410f74d84640155d017ece4395a4a7d9353ac5a9833b958cc73242291ba4810a38334c88bd400f29e3f0c8bbf30b8d2f",
+            "00bf1bf8a06955a6899b3e31b9b95c38fcfa8fd23a6d9e60d6d146bd335e876890e77362c0ca8a5dfdde09399a9615ca01ef511455e2e0de",
+            "74fb9aabc4f5fe135b8cc6b1f1173bb4264a0dd261cee25767bb5c7cff94d7dc7b28194050df217229c209fc73e12e9c7f7148c6d5c6b7a5",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "78aed5fb1c4d7a1d61462fef93deb40c17259eea9c6b3b771188b88f96ae6b8eb6d910258070f77ff126f00f4bd0879d3ff4b800baf379d3",
+            "7e0bc9a58743ee954224088dc53a0bbd5148265cb62ae1907a219a08a42f805d391fd59f5055e19e40b4d7e16fe1682a5783d679a0cb9be5",
+            "69dfc9871a34ecdb377bfe546b3e97f8f2d61ce273989abda97945c040000ab24b0575d4a653e401173c8da94f3edbee20b1c92222cba2e9",
+        ),
+        niels(
+            "b81aaa404aa316f06217dfd1931f9701cbeedb5051ee4343ee83bce8046eff31d9dd6403fdefec56937985e980d02a527d192ee32df1dd47",
+            "3a9be6a3c2983707f540890303dc13088f925d5ab30c9a7bc8fccdb8386c70e5cc6c104e258e6b1029cb4366b98b6b761ac354e4f4bfca40",
+            "fb93f3df40704d6e8a8dd023634bb8f677e5728fc84d79308c453308540b223f128240c11f28fddb1e6e88a0c27c7065f75d43983cac7e7b",
+        ),
+        niels(
+            "40b40850a9757293dc65eb45bdc40ce67f673327ff9d89db617de8e50f475a7ddd6227f9c322ecb51c105a97a428207e77af986168fbf39f",
+            "d975e753b793b75f7b0a2c7f8771a8bba2ba694b6f8d5957c87459dae0847a1e98dac5f320a70d4471e17b3577fc23a0bc105bc02a5eed93",
+            "77187b2c0d16a74d33f1f74d7641bfc422af2fe8d9970b60dd883080a20b6f7bac16709a0e438042b1a7cc5d21b70398d09902a4ca572cf8",
+        ),
+        niels(
+            "bdb4977d1ec892f20023a51e05bf77753d321a1e8b22b3740ae50f2001fc16560f1ba66cc3326c0aba0e29cb91d347cf053c787db31a670d",
+            "e83d99505f92b928db42bc21a21eeb1d54d38e1e8a7789005114b2f698e757668e4702889c34a47e6913d19e9b33c9913c5a13a874979227",
+            "45323cd27c2ebf2b6016c51c0e86b90739a2a42a0302afa158e1ae745fe33769c5ee8389b0e4e5f1b876a557d7cda400b4b7ae29cc288dd7",
+        ),
+        niels(
+            "e4ab2c63276df841018f808863ac94188a071fcff9e502399c8afa4e0ec631aa160f73c98b3c64987f05568bd32bb48afd7cd1cc4ede2900",
+            "6dad64a6cee4bd5644fdb10ca6779fe31434e55916f3874628accaec00aa22e4deee4179262c3c1f4a39a501477f50e76b9928560288ef65",
+            "0c70d4a4eb409e054df78b8ec14dd8d273858cb7a382adcef3dcc50e143f675187c3b86301ffeeb468a4019e9c02bf3ca9b73124b4e7f1fb",
+        ),
+        niels(
+            "bb3fadaddba37ce3cbda7e68bb01e9fd23e275f0e81239697d580dbdef0a35fc44419eaaa437a5a1c5bd2ebfba9ebf8057e7f9fa5545b5f3",
+            "ea88f597294d977a814f4b40ec0fa89cd2bac15c26b5985487086206fb24a21947d5784f82af7a6ba93373ba118dcc2ce819cfe3acc25ade",
+            "d9c84cda291c9154f10548ce56a01a8778ef0775100624ad171d23137903d2afdc19e631eb48bd4a67af3a8f8c38f5a8cebb8158047f4125",
+        ),
+        niels(
+            "cd07fbbc1bda0dde81b655cca8905cebeb9bf95e6b547f7400eb679ad7ce046e7823ab18321d2869a8ec600eeba667372f6f89184bcc9c76",
+            "b78b5ff87fcf48ef76d12970916c9e41a493f00c0da07518a35f9efc0d1db66b736779278bc04fb5cf3a41ddcba731482fee299dcab649c5",
+            "5065ddb914b044338ab6f9ff7e20224422f29616cfb4eb7a98c3fe8ab5d74dde40e37278325ca0614398b6fe28124bb37989763dce11dc5f",
+        ),
+        niels(
+            "b8859c471557cd70795439b077e38fd6b62f2aa915830a21ef7c0f286b8988736341b63bc5a9399c4af90e242a3579672fb559bf962d928a",
+            "05b76068813c1e82bab6a9326fc9352ba83ef4eab18fdf5a9ffaaa12dcbfb0134057cfaa186d5fb4a5b51fa2b57f2a7bb284fcf90e0fee6e",
+            "5a305bd227bbec2ca9ec43bb5c5abdfcf1201760ac2a0183521308ea2fc75f965da82629343a24f439cd695d083f813704047ce553aed29e",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "a623ab1d6ef30af5d3c18d11baacbdf359f4a4b6ba6581582ed4169aa09e35b80f99011b22ed5a89250fa1c2991a829dd4975ab078e28fd7",
+            "e86c1dde30c7f2c721ffabff0c850cf8a6cc6874af8b266230ad6e9b83d88579f94470cb6ef76f1867031d06a42e2d5f96320b411534fc59",
+            "70e1c5dc6234e08b3e501795f3fa8f86b0a4d916c5b1947b492e73949324207d60cd17f6c39a78011e0aae185b1ddf413c2d3d95c244267d",
+        ),
+        niels(
+            "bf5f6f6da122c89b555750b5b7d8052e63aba49c1f4946d2baba025045343697c7a14b878b5c62a0f1f12b0062d7ee603aa5dcbdbe394142",
+            "9e69012d2ab3945ac015cac03885626487eec9edd8291ad0510878dac7e2b6c4061a8378bb2548c217a9362ae016b3c12ca3e231800f17a7",
+            "20b93b2939ad8a9a926402f8fc6e582547d55c62f7877740acfc100d98a9be0304f6a7974b2863f5e3791e7ee0ff219efc7dea39aa7d2d44",
+        ),
+        niels(
+            "f9a1cb57ca88d2f8dd92638ddbf25af4cebecabd80c54ceb0e1ca47ace54c9046dd52038b19db0aec1c31c8a38a9fbd17cd93ad96888cddf",
+            "8411f1e5af992b7ec2c63f7a2772a8d248140b20f365171638175a958b93f5b10d09cf53afb14e2078fb6fd528982fd88685e500a1be398f",
+            "4806633f2c9de949f4d61fde2930a2a524d50a066ea2d92c5d7b02c5ff25747d6e366f30d6465b4aac06eb0f356df5ffbc10df81f1ed1ff6",
+        ),
+        niels(
+            "dac795b3980c22b7921abe1df8a62319bcac780f4f4a9631575e56ef77d8c9af592219d98c1b07d7fe9723288c5bde790ce989f543cfffa7",
+            "538f4b85a4f0a200d7fe0320ebed12f8b8098c33cde14ba9be1c235d2d1e9c43cbb6bcfd3c9c8fd3a94c9eebcfab06d0c379f328d436f975",
+            "8d2be610c6d7a1fe4b02aec12cee23c7f2169d4f98c4499227f89fe793f29ff1312ab66edc6fb5d6be7822b07f164a28719bf5cd5592011a",
+        ),
+        niels(
+            "ab5081910c0ee74d7f5b14a0c75c1f46240f3426a5e04104bd340b1c097887c1732916fc0e3baa0d5a106e9739afb3fdcaf875f56d74795d",
+            "573d290736cf9658f6141316b8b1fa311db31c83c010c8a4aad758311c7924f20ddaf7e1b84d25fc986f351d6a7f058c9a84995bad922f3e",
+            "4d5949fcb677df1667421cf067a4e8af5179164bee89652f6fb6f99ea6829fa3af9fff6bacf597832ec2d1b6ce73aadd23e432b543ef63c4",
+        ),
+        niels(
+            "7626155a3f84d6e35bdde895327da09075c61ef4e332a0785b0b90eb2e31bd31a8fee676ab55e7971bff3ecc0a1eb1a600504210720ed4f2",
+            "13b95f8af518857b470845bbf5fd132230ad4a6bbf1b0064633bb09fa8eedbe916b3734e73fe7941032b70845ca23d192fce89843e914b40",
+            "5495761f9869bd3d219aee6af15775bad7688f14dc36305eb27cffe875f8506135b4e180ab3bb7dec362aae58c0d03752a17ba9316ab161a",
+        ),
+        niels(
+            "cbaf48ba6019dfa6eec78df7e81eb49aa18af34745ceefcff4e8ea2e80aa2d8fd39dd7afd78ae1c2cfb8092afc0b17768176aac49af4eb7c",
+            "18b4b60d7265c43cff55bd702c003e6601a5196a5fad3bba936a07213873c152112f89c9cf6e4f0a9f1addb5c2c0f129f106590c22d6b98e",
+            "8bf431dbd4aa15a6434aba5533a59af8dd7d31c8b0a2711b45986e8a5c667a6fbba24bec4c0c84bfe63ce8c13f2594db5676f308e6c743f8",
+        ),
+        niels(
+            "d8afad965d76edae5ebcb510ab746c027c6be2f35965671613e86637b0d794b6501c52e77c680f30af8d4cd87901d2f8dc250d253cfa03ba",
+            "0ac82c850bb241a6dc7002951f25fc6d6e4b5685e5316cc8fae78fb7f3d75dd5ccfd5e34e7641036a73cb70311192d135705e68500753672",
+            "187bd9da169f808828331140ae661001f5f45b78dff45a5954f48cf35cf0ae1d933c6509c0698017937d0ccaec9863f00829a806058b3204",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "6cc05660df01c7a8d103294cfa6e2c569aac8f7b1c23e224411635323893093a55887e3e6553b994ee5c56aac41f43a166b4437065313871",
+            "3447254319d7868e6d9bbaab3ce9691d7840fd5f842bc6917cc2ea57ab37a13468f1299a7a26da1b495034dd68e4784b0910368cf76c6a9e",
+            "7139d18d6aca2ac389ab93e387922a737665b83203b42dbf767eec1ed2330801c86598adca1da25747c28fca370f9e62ffe4ecbe58f4aa91",
+        ),
+        niels(
+            "f4fd00f416ad2b51da4ebf94a478071fcea825f12be4cf033807a340ddd565f363fa4f563954a77e35dbbea88bfa870ee03deb7e72603998",
+            "6035db1ed954b89d5dc1ea9ca87057006765f9ca5af110ca116c665041a561b5169fb9ebb46f5bfb0f981d14bc1efa452e5ad059c2cc7147",
+            "18921503203f7ff290ff59719920a6343d4a05f907ed9c134f0c6eef484d2f6bbb83bb41a36e670339d50819c47aa620002c129481b3e7fd",
+        ),
+        niels(
+            "4a352b0f4b7bf4aec9e275936601e36527148dbba77a9987ec18c605b551c14560232c0e12c03f1a1e44d0a5e96ee94f7e1e36d70c7a748c",
+            "f0db7796026e65265e208c6289a906b2f1ecb3c88f965c3d4f82a63e63021cf6d4a51bd80e549f892cffb032abcb20c93a69761bd239d3f4",
+            "1d082028743e098d073c92d682392312d2229e8ebd31aceabe27969ab701d19831669cd73704141e584446350f613c6a0f217cc72194d2e3",
+        ),
+        niels(
+            "0b79d213f33e141f468e250d74abe532bfce67eac457ba6d7024a5eb33acbd7f9234550e4141870529f7e1eeafc80f289ad9fb5bd38597d6",
+            "842c20ab250e85d9131fec4891bfd582e51aca5875fb8eb0c13b970e089216a2d8fa7e607cd473154c66ee9aabe9d6049568e3147e104759",
+            "c88b887cf53e2dae555b9e045f8cbdc6b284a470811e56c96755a8652bcfd93a54418a540311739f7861e6cd05c3ea9a861e419bf7d6cae4",
+        ),
+        niels(
+            "a38c5001b772c58ed22b8bd3aec9bf94bd74edccd529dced6c156f560bf1f112676ca6de6a4a20dc8cf876db86a5f773828686eee9c12307",
+            "6401006a4dc88744e1090887862fa07ec08683a750249ced8e2e7ced06e8cb740277f0dc9cde06020be328634a23ee874d9b193b0b92810e",
+            "32347fa141a662267e8c512a315383ff7636a96906cfaa2006c87e27a4c1d681d28aec809813a0f22f5c6ec5176f406c153f25b187178d84",
+        ),
+        niels(
+            "c2a516d2cb1c52e5780a78d0f9e163bf120c2cf40e3391fb4e34a7430f3647245b357892974345ed577ae270fb33459bcd4ca3d07f4db38d",
+            "5dc33ba1d80f59290a6d302f255ae7fbb98342b837711bd5334ad905a5a8a2cb84aee6dc79a73cbb124cd5651cbe2c15c800432896f2cbc5",
+            "0ae7013f411bc466b329216fc72b50865368b0e3f1480f17278be71a5268dc931aaf1db70b2bb9abd47f4fadfd291882be24f77c31e7927b",
+        ),
+        niels(
+            "aa38fe0879338d33e010b890695d2c6ac81cd2fef99c905d5704e3e0f0afa1e85ab165b0f7e4f570f06d2649cb1d01cb95cbcd5f072e3002",
+            "05c67d022245ff68143a03f4583d1c7ec77651b462102573dedd6f96bfec7d88ef640bbb945010c189946419d20701436e2eacbad3025a53",
+            "bfee1ec87d1397e6533a5c9120f1d57887c961a53afe5353ccbc7da6a82f67136261755282bc4787b942b140ec60d747748edb788a796004",
+        ),
+        niels(
+            "77d196e9227094f35e90827e0c7a68229d8cb9efd9f99b38704fec8e91c1352606ea7978f1f36d5f5da85e89a0cbab21cf9179f387a750ac",
+            "926c1278593aa623b9e377bd19156711487128c505d61bd2759265229af86584ff015a929c28a871b37db0a3c11e740bade28239a92c4d57",
+            "7aff28f12ea42b5316f9fee41cd5f66b8f49a89371b6115436150d59af71fcd8d6c47fb6261ba9b6583e0ec7140d59f96029661d8adadef8",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "0c56f08cb6a3f972b9ef9268719da1ba8c41b79c35cd6d7b10f56a76b5d6e76ad6c0c85a38a146aa5b5b98327090f46d3c012c95c8563931",
+            "3ed7306f0e08f4e616c448e5e5ddd71388ee833021308d4f34034b50f6ac425459bca52bef8045e50b43eceab89891bdaf1126698266715f",
+            "481021960d1a3f5d6bac933586ab49bd030151baf197cbf6ed81788027fef727c067c20763be2940ccf04e6460c4118b328cf29a910dc9d6",
+        ),
+        niels(
+            "307f59edd1851a83d3bade863fa78992c32eb7d420a0e96c4414163a7cf3cfd04a71ddba6cc66fa5cc61ae4fb11ce3638031c4e832417134",
+            "5bb9b4e51e6e6714cc4de1cb8da0b487b23d78f23276de5e3e0d3633111196bf21006eac3a34360ad5b23aa6c484d37763719a473d0d03da",
+            "6baefbfa6dcd6916f769005dc4813703ff7347289eab85b98edcf0fb14b8e83dde5850f418e3c2c132a469e7e92a08a648e09ca3307518df",
+        ),
+        niels(
+            "25755e163eb05a760bde67138e526814504b6866e938a6fe6d8a41e8fdedb20f81463e7ecffbfbbd33551a0f84f5af6bba6f0d8f44cd9849",
+            "7d2535c9bd0f979ba455ca82f0359728a9f413b983ac1380e99d69cfb30dc4a9ff4a92028d5f41d30e952fec8a2ad849e004ab776b24f257",
+            "f1cf6004c2aaea2fbb2f2803980c0e5ac942a7b920366d0bf1a32e760101b6b69fb96a97dcd941b6e362ac1f35dc7ffe5eabe359971ab105",
+        ),
+        niels(
+            "69427086768e1ecede598e9b871164d828ea75816485fc2d83694dcefa39b4566b41c0ffedc4dd86f5e0ac17dcbc92f97e80765e4e148698",
+            "f2cb6a028db1aec4db8ec8ce266639f20c124bf8fef14d78fdbd07011c0a65c69e15cc89c870a3d514131b2a68459cda0b57d13b9b8aae67",
+            "19459502cfc64fe1f4f3cfd2f57996efab68c8712795f360edf9b984f6041f577eaef2d9add74fe80f8919295f8bcceb281d14f5f9635c6c",
+        ),
+        niels(
+            "a390b8bbdf69729555ad2e05017a21a130627fef47311935d93d9906246fe3a1945e91411b03c5c45206117963d5cd96d64e4722e0a1f308",
+            "b161c1f75119a6788b0a3d863c95686853cf219df9f566b16b8ee3303be896c0b369b2c14a8ecf589557981614794000a89ed2d3b66fa526",
+            "eee4c9cdf12641300ffea1aad5e3db5c9a746de4be2f44ac2c875f6b7f3d0fa906546d28e720a159601ccc80a29b9daca3e29be1aeafd2b5",
+        ),
+        niels(
+            "aa77f726dcd1e0b95026014681bec586af11984507e18a9722db638885a2a3324604b44a620ca28c50372b55cc1ebeabd9238e0b192c1e23",
+            "92ec6a58f00e5eb3d89372f451759aaffef77f70591159b29a0a5664a538647c23569ee11586e0ac214eb3f22141acef16470c16c9df61a1",
+            "5f7a14fc76e72958d7bd982d0a4b7ad2eae487b27bb78ae62d8fc5114447d3e034ea96e32538dde900158af1c4df6065e215a4d8e9998089",
+        ),
+        niels(
+            "5055885b0ca92afca8988006bbe8b19dc59a229987a42ca946e0dc6d19a8dd86a8d4eb488d425012dda880c5eb4e5b0e239516696eb7c3a4",
+            "66469742e80f59a692b284776b22b70d95b21388aae5453f3b6996a1bf978b57245a446ce15bc2b98d710668abe93d1fcceb17c51aca6a08",
+            "971189c4e15ae63262c13bd34851fb337fd1b088067f9b0b2aa55d2f41e996760b0bf7e0d5cd8b1b069c57dc38600b5f44f0f6c00fa6dbc3",
+        ),
+        niels(
+            "50766604ee3843fb0263d7a0dbbe4eb54282d7d3ef7cd9fa6a78d8f5ebe14578b1bab80f402e2887e5688813f26d1126df90186c9acc3cc9",
+            "fd999325442609cda08704288ee6c12e7dc1df44b9f1526ffebf4f2ef9da985a66553cc4c9158789f934b66a2e71cd1b0c5616088cc8e64e",
+            "c9a5c7c71783e14f5758e818a4f3cac34b8d28a2030f88d9bacf8d9027e24cb0a161147e24877a674e101274af1641a8098572fb477317a7",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "6d5466f8f5f35ae107e6f86045957b4fa36afc23b1936fdc25c703d3599d8eacd547cf5db94adf3f6ba14bad9e268e56b8db68dc0eef1b48",
+            "b66cfa3c19972c985a9178c8620f04f7c753d65eed1a666db1b4db4d61aad0a8367b7b102fa91836268b25498336eff703cd8b78a8cf2b65",
+            "5ab85f127afd2964874d152b993c4fc1f10a3723b7badc0b133e1eb69024f6fa85066b574b45729c821e153e6e10dccf67889f97e05d92c9",
+        ),
+        niels(
+            "8d52bf33d00849bfc9641b3c81ddc598d19c47c9da7d4f365e98a10fe59f26404801db89e1ccc539ed3e98fb365ba2c13635d28a482c61c0",
+            "e2a3482e4ad327070398967c958b7884ed00eed274f0d17991a1612f54b953f8d92175b483b216869a78a828523091343a19c60f823a5fe4",
+            "2d39bace901a8aaf39af81e78bdd5aecb881670175e8d4543a66bddb115e7cc141fceb7d884d486f522255eeb1e528feb76fd90c3a4bde85",
+        ),
+        niels(
+            "7b056fdc6a7ede069bdabf5127aac83a1e29fe81e0715807362ab9261a3602234d522b77965368894380002234e8df91ce5cd2b65bd6457f",
+            "5ab4fd9c28fe69a94eb60d84e71ec58515ac8f39c0e2fe4b2ba0a0d265a564d776060cfa473c5e762afd8d018015529f2a256a937454af9c",
+            "c29592ce61e649f9155182d4e695304e10c8d112fe7ad45fd93ec3662220e07c9379f36320adce21ed3d253396f1d12a9a11ce790bf7220e",
+        ),
+        niels(
+            "f7daa434102aab8c3bf1a9d5e361277c219f78d8bce26f90224b8d448eaf1cb852749d7f0489477e4e10c2af28256297364fe30c2f42c8a0",
+            "48f3d3891f9cfc4eeb5fe201f926bd4a0c60ab6baf2d1e2923785cdcb20b01e988ff6017f618f98e51e0f830f671f52bdb36d966d8d03ee7",
+            "e6cf26b2cddfa2e951c2af1c169402d337a1e435b20d285e01279eee6142176ec22f392ae7b2e1d78489f8d8a703b7903510bad515c3c3ca",
+        ),
+        niels(
+            "87c70c231ff553f4119c3c2b7a4ffe8d58272068fc275012501927ee9d6757a96aa0170b99e7821754190f55f7dbbbf1393a57fc1d496540",
+            "c929e319f23ee538831c38e7f863e36439336a5d894cbfa7854bc83d0734d64a4c617a68e59bd2da0c2b028d0243a94d8a4d37335893ab11",
+            "0cb8e5f867710d3d48135f930ca70db51fc25081b50bf341c589f3ec30e58c615a20b4cce0e8bb2c69cdf1473aea5e9bedd425c4ec130fd2",
+        ),
+        niels(
+            "c8260f86168c4eac958aeb7599295d684ec705c59587007a7b05048da9771a44c7425517222df0486277e1f66f3555bbdd6e8f744305b2fb",
+            "9e6258d9f622a93cdcbd0584b1f804d9c2afb01b597e06445b357fba766a6a7b1ac530a503c5a60d02bc9f4974b5cc1d3dcd6fabbe1aeb3e",
+            "37c93a9e5f455f34ee8cdf3e4fe673faa5bebfad8ca21c5e936f3b4f1aaadbdd5902d6e88297e77377b86e3d40c0de4b5744c54c48ac614f",
+        ),
+        niels(
+            "b6d8b0d699291213722ddcff2cff6cd5529fcf52a2e8ac3215b60db3a2461d8f65c4eca601a33efc20a66c54c5c9e7b919cea2158cd12dd3",
+            "9840e29970efd086fd5b26b206f0ff9e2037d0e28a48e3917f6c5c05ee1647fe981ab40e71688aa836dffce3b9c221dd5ca88a614b1907e5",
+            "9f61a3dac2f933e64962c2b04dd908739eef0525078a77d8f2bd18c10a0bd3756922da318b28caec90f2be1a655e7ceb9d0029c7d4eb2ff0",
+        ),
+        niels(
+            "68e63f819804633b263aec61a2245ac2d6bd0369b37afe2f7d9a14b1e0f11c5c10a2306c2d94d586f2160e8f297185eeb4cb133784624c7b",
+            "b6449cec4e31deef131a3a5d9a9b7ba75fd616acfb49ff49c3c609373b7933d1372ef43bab9c2516ea68f36d9751a95febf7c3d78d99f420",
+            "1a74ad65170cae712a0c2d32780572e6066a51d12643d0b3d626d24c94c52463829a2975295af788b2d396b33d3ae12c70a250c1cdccb8aa",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "ed7028cda1420011afd6bb34928bf3ec81a29221f37a12f1fc9fe8629b60ab7ee44ceac23717049f22cd93bb22aeb33ed01f632ac8ecd3cf",
+            "e850c2ffdffdc8f3f814de2076584b291ee9af3e1cefa4d3f5d8ee56f55515c0f1675504da20fbc694e068d1b88bb37123d8b44e51b08a49",
+            "fce33cbdba12724621117ca74388bc22f32efe558f66bb5671da1a361b4469615dffe2fa82860214c9d1486df8c7da81af1ad356afc1938e",
+        ),
+        niels(
+            "7f02be3635f51583b6255b34587b9cb62e806b8a7290fdd046737e0e8d7ebbf17afd9302de4e396d8b3b12e05fa0fe7c038d79a61182bd13",
+            "a618ca42b3679fce8f9626a31a0ed822227d709cbbaadd44fc7fba4ad5ccd292183f27f0285ee3a3e60af34809046e2ef057311850503753",
+            "1c04e1da724319f22d7d29bcf8cb52bca4b04c2e46f9e07fafabbd4f4ce1c0684f6e74a39102848936286b85c54f76ad77981b4f8988b0bf",
+        ),
+        niels(
+            "aafc133ee16b150f8898a605113cd8e00f6c173acac58dfd8552c10d82f3c0bb306df306897e58ea0df1f429b7b02436ffbb98e10f770890",
+            "c376776b66efa839ccadefd71753f48aaa176300a6316933943e07ace55f3ead72e0661294a6054b2e29255b3d7563f420907e8cb920c7e0",
+            "d884415f4f285295a3560b667b56a5c53a163712171fa197ee7504d3b5404d5589871d811d71f3dd766d869c853c9fc3e29b65b58898b671",
+        ),
+        niels(
+            "b56958d5476d5048d033c0fa9f93025ea12c3b99b5ea1d1959968df8471ebf0a56049603eec3b79ff74b74c2d1eb96debdfae07236bbfe64",
+            "d04755de506ff60e845a5587f6471672a3d586ee9c130e23a409c161730b23fedb340658153cea1739578441d523d007f2a731acc98e58c6",
+            "2433255e084e86be8adfbe2c90910b90dfcc696216d644ced8f97a2d24af885fc7b724ce4e009d8c3b72c1a4d1b3026c732819284b40ecc9",
+        ),
+        niels(
+            "3313feb70abfa3fbcfe9c50d704636a6501dae2bab44d57f10ab29822a185bcb919dd9ed8b17b1081b55adb13efbb02f09693af901e81263",
+            "04211ee1e50bdc8fb4f5270beacd5d216173bd754a815231cc89d71cd1f0f7371318c6967dedba937c7e6206e4df51b4733fe9851978dfb9",
+            "299bd4597f710cc36969f01bac1a5b4bf6ab172e6db3276ecd81c76392e3d8ed0d753abe5a51b6df4b6d79ed7906b243ab9fbcf08be77d4c",
+        ),
+        niels(
+            "5075470f13575b69b8f878c6b114e30551bfc2ac09f9cdab444df4328939ff2c64467bd539e38eea34805f891a88ad49adb22dd280cf8641",
+            "86502e20383f22d67195bdb2442a0e2243ba89c3b58436a1a8bf9a3174ccb52484c4c9ae161d976fdaa4b81119196a4fbf68b62bfb016143",
+            "edc7c53d1bdd9e214a831b9493bd425a59a32adf997b5cea7539e6b117454076dc16a4fc5ffca66106ba8d8083d1807817a2ccbd8475adca",
+        ),
+        niels(
+            "474e5e0011db24fe6294d0560a1a60005b3601ef2c538400a1f0e881c345abeab577c77e9bf02c2fdbd68881123c2562d30a393441faf0ec",
+            "2fe8a177e9bbd1f4d9ec7110712bc7e5ccb6bf212a802421831717d7a17c64fb600757dc06138acd1159c2b365deadfea15406fa84b6f2c4",
+            "e21f54c654087d72a881ee61ab095ede03ad09aae3c98c4f157865ab7921f7fe5550fa5464a95f2cff34d56da9d08039483b2c87a881699a",
+        ),
+        niels(
+            "88cfd252a8dd20d5669afafeb5ebb2b04821c7fe4e87bcdfff287e51cc8d2af9842cb2fa47720107fb56596b2104028f469621af829580c2",
+            "b43e9a7b00516855bd5d3b403fa0d349fd66ea2784a5f0c7db62c6a5c1e43f0af26925ebb3541426d0af192fbe0b6ab0d119bfbbb5a3c92c",
+            "ce9fd655c73680a54732801e21e6b60937e50983197650094973c264494fbf051545d8d76f49535047c94162f80e5134eade2611fa4ec9c2",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "8ce004517e339d7a66b9556aeed12600b9647644453eb40573f5d29a0ba151c7d4bbca1439c8cf2036d584b47f0757c35bd659e0fa5c8c47",
+            "b859e57fc85cfbd178e9b0c792c08c09f87b421cbf227a67c820d570f16ad34903e6b156872f980c6ac5f8003734e78f736e22426f293fbd",
+            "2b103d07b0031723bffcdf7e5c8439beb211db67af3ff793fe1b41c1ac51d58ec943f67045d05f54783d30d927cc4ef68e4c4cd1506406fc",
+        ),
+        niels(
+            "d141d0125cb36c70b2e75037adf8e9052887f0cdec7b270e10dcfd243dfc5a4b5b2f9e1ca8a5ea2a53635fe69ccb24f043d0f17fe4b6153e",
+            "90c7e81653c2c985a4c118f599ef9985e22155ba2576453ef8379b9f2ff4f401243049e71b66ffa730062c36802656aee8c02a81fe88d46c",
+            "b1293d14900098dcba49bbbc2167903ead4e5627a74a2db9ade1be4457de45a75941def4e1827fd4bf637ea66607c930b4f269b9bf9878d9",
+        ),
+        niels(
+            "f0d26596d5f8eba9b92f2dfe3cc7122371ce51f85002c1892cedd9cb622c6f7089e1a8c7f081cbe0c5e0b4ee6dbbeace4d907ca2b7dec0c3",
+            "ac591c5bec994247d1a2252b4e0ee0273ff0dc97ba5a9b3ffad6aeb2ec94344fd56f1fedc2773ebab91ccbfaddcd91b0ff272b1db73800db",
+            "621c2f98a01ea69d3613b1fcde9ccd6c739ac467c78171041c18de9892bc6d22bab5d90e94d09d8076aff0a8af5c2f95a45d4b57f6d1dc51",
+        ),
+        niels(
+            "acd81e4712e76d83142a3e88c2c8fd1263f0ddc04c3f22d48c49655ae0041dd7b754ddc0507b3129cabcb1264888578218e958cb6213539d",
+            "65f9bb60f5715bcb921af5d9a1954aef0a276e0e955c7edd5bd519477111a90e8df072771edceb1fd1be94dca330e7b60760c75c31a9e8ab",
+            "b64949060d626a7d53aeba5db3f6d1fff274f8e2aa74e6bf721638a51127301b9d52d673b7da50bdbe892997c03d52b8bc61cb238ec7621f",
+        ),
+        niels(
+            "4548262495034bec73e03bb20a8f2b76a0fa36663d0b4f9206dbc4b0c4c535c79914b308ed794c378339035021f2b1350047f9f1f7ab204c",
+            "a758a0e7a47f46640d0a895dba8ccf7eedf5d5a0687fc2ff4732546bfba700a681ffd6b8f787354f5a4adf1ca7531f2cd666b499cdb75fdc",
+            "c5825d5655c53dfda34c7cfa3f5a811ef1921e3c0fd7f9d26d07f83d25d83dd001066d80cdf01c8532ec3cfc00fe017d318d246d2c39e471",
+        ),
+        niels(
+            "3bc3521f7363308c88467c559a482cea2f2b5f44656398e60e7eae3f47a635936e33e352a1e23b574c87ba2f120182b853937c4d13f1f314",
+            "0d00d5772ebea4f972c090df03c83c68f7a79cfe3294c11ae26bdc307f6930636c317f075385c528572c6d7f496ce883caaa25c0a5101dbc",
+            "bcf37c8f4164f9d617d503f4dfb773b838caf21d8cdd0a49bb2ebf489d65e57a2b8c76d25751bd1b3308d23bf6abb005bcf22e7022387ed4",
+        ),
+        niels(
+            "2e18b03086e03d373a8856cfd94c46751add131a7f7a8989ef2cf94df863ee38150290160e25bdd934d99a60642b085640ded88ae52360f0",
+            "17582a99277f845959efcc0628c966a45b3e493d0a491b2ae8593add4401790bba0a39c979914ed3c162d47feb094b9b1faac059df837bd0",
+            "ada12d184dc20986ca8813d760c7830e54290873cfd5294d56411d35e8090d567c683ba2e526568142b57df5a6ed72addc3784bd5f498a9f",
+        ),
+        niels(
+            "6d4912b3f7ab6636b5b09b9f5b29aeb54d94767073ce2f3357e7f3117d764e1151f2957017bd12b84478a980825f0bf1475e68a05cec1b6d",
+            "27fac525b82a0fb9b294715eaa6e0480d220f60b439372444550ecdc5dd06ab684f153e3f3d9f65d4eecff14fd36c293a64432df39b40581",
+            "7ef7e14c8208a8a1e9f1bd39ea92d67ad8d727f1fcb90fe1632542ca0634cf2c932329ff06557c22b0c3bf5623b5e04294b985e0bc552703",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "4729d48d6ae496818b8204df16c211a3168be9f73cbcb23d48ec4ebed62e39e1052c719daeb6e6b3781d9a937123a1c88f0838b260b5cdb2",
+            "c0431b00350cf903285f7df4be6377abff176bdebd88e0bb529819b8e717f743e27b995b400c2bc25c2f386830bd5f154ed6106d91d0a9ae",
+            "099863332b8987440eac4140f4fc58cb830ee02b6510d251adca612a96dd507a061cd6896f755612ac6e3d1eee8fadb86980d3aabc4781b0",
+        ),
+        niels(
+            "a859d2c0411c84ba7c64a43720ec516e8e1d7028fbeb605489fe4ea63e492f1dc035cf1a6598501c9d85d3a0f51f2a11278955ab3c5d0087",
+            "c4f44836af22ce64c2686739953d8ad6954ed4f529ee8013ca4811173b231a0f6956978d7dfbef8f9bc70f3b63d99cce6294d0f5f40c87b9",
+            "2188a0d17c7bbb6617d06a49d63c355ac4e0ea35a5d0445feb1aadacad6db9d16c1ecaaa00199c46469f2704cf069581d5cfc5d45cae9758",
+        ),
+        niels(
+            "03f0ff7a1da338c748617d797351687c1a4f5d64ae6633f0ca756c088f8ed71fd5eed16c38a14275878d949031e76614f23b1ec9689551ef",
+            "a5958a01e188c2b43f96d2a0c624e49c91b655d8dd51e913dd656f07c2f43ba245c6ed9acde991c44f104075b285049ff2e876d31de9fccf",
+            "0f6f73eb29376c54070f75bb7031a3276c566972830ffb53d9e987f0ac6609f2b3876c1b1c949cea111b22cfff746874ffdde457137aeafc",
+        ),
+        niels(
+            "50d3478b348df111b302c1f7ecb767c43b8fb71afc2ad62f35373ed33fb67b8931cb8c5ce5b5336ad7fa373b948e46398c6a8c15ca242253",
+            "1af8eab417949d27908860d8313fa77e6da7ad3a89777424cc856df1226c1a7058d5eb97659d358c5d66a7d7bb6c514efe006a60038a31bd",
+            "0068c368b14cf7b80384066a22c42db642020f857beca87ba2018f96ea4bbff120fa58b6300f0ffa0c781f327dbec15430d75a10b62108f7",
+        ),
+        niels(
+            "bac2291305f5d74ee4d708a20717da2c0529c3f54a9b735459b09f9b005434605e8fe80e9c9a37a546124327bcc903cadfd2b9dd1a41b929",
+            "aed828b76840ade6d8a2f67123046af273ac72db4a9fa4ae51a491d6b4bec4be31904e948ff29f82c47d95cd7df99d6ccb1402a57fac5339",
+            "4816301dd66f93c1779a93d70cdb071cbc7a885e73e0ba7ebf00316d30b6948210fe9e1610bb5347893ea4335d9fd23c707499fc2184090e",
+        ),
+        niels(
+            "9c724af828313d1073e1a1c5aaff03c9ec76881fecce1f2b0696366eb140ef51029e3cb29e6639dc42e0e3bcb9fcf773d471815af3c40ee9",
+            "afd36d24eb6aee24d580cd1c1e36b53b422134452b253f22c48a91c4a7d59bfce642ed6322980b4cf02daa022bb8e56848e3435d1f2e76c1",
+            "2c34115cc1d174570020312b2c3d2638c4a030cb01ad362ac6ad01c77c959344d40d6676015ec5e72a86c96d85a0f7457b8aba2be6e8e1a5",
+        ),
+        niels(
+            "5581846e8df9196fb5eabb8221a4ebf94159f5b0169a5e343b55f63d37d300e077c3397504fa7f96720a41d18036475f099369036d1eec35",
+            "f62039e5e2c323a52017503d583f3d2871e54b5cea2293cc29d3ba699c646ab375f3ab7cfcd8dd5b277c98ffa57057869242c00649c3344a",
+            "e71d8ba219b78edaa23fe4b38a817fe4642d226d111b3220be768974feb7b10bbaf0f42b3cbd9f8e8d5c6fd260862b094059370ea84294a2",
+        ),
+        niels(
+            "4e6fb713cc278d3ffb5379c2b114828c7d3a3fae70cb3dc975fb229b5b80eb648b8cf3bb400a9b84724c131b7ccf97cf98c3fe570eb51484",
+            "4c95893bff53f4a9f045570e4fda0fd06ff351b84513da1a301006fd9a45cb4c8d310825b77c9d51d0e5241a6ae58a2357b4c755298b7501",
+            "6b1a64413c0520668cc7666b2a5777dbf45ace7bbc75d2c21fa09668052cbea754b593e3f2656ad05039d3fbf0aea7f506012095eb50cfaa",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "b83a84d5ad91f0bfe6dfa5ba70de32d8d9cc20a3adaa078d02af80988dbc85b0818aa7a8a0fd92ef5ee01c63f649c236af462afeb37efdd3",
+            "3785c286d54eb7c5929e36c5afbdaff81aac73cd10a0b9ba5742870592eaaa710be4b254b2d3b4a00f597dc3601c0ff7af77667161e48286",
+            "1c7f032496a3203f84d5178aa3bf18a5d810ab6d2b48f2b3b14c51d2d5ba806c243a5012f9eb5b08c1ea388eb7a64c95f436abe1d2df463b",
+        ),
+        niels(
+            "86fff785e20a8e336ae3334f258831329bacdb1302c626bc45ffcecd63e0fee8be13b2b2d3486010455fad1a9c3a1ac7e825cf5ba17adb97",
+            "01de3faf404d487648520210df5b4a617ec2a4facd08c8c8fc4a820895739e277301fb5448bc45068387eae43c1141fe58677d5d232607c5",
+            "cd6c5b018010e57d84d218da3b27d8aec8edfca1e34d2afd3c59d7892e1eecd3cb6847d4ec7a2cf5786d7e864bc589defec28d9d15874b3f",
+        ),
+        niels(
+            "5730978e07aefcd8dcf6e983934380b646c2b2c018c7defe8301a73e7329328847c719916b30bdb1fd2287ae7b829a460c282d9aa13b283b",
+            "42a5e6cd156959b134c16b5aaf946c97211f8da6bb0ec17e9c5d839620260d5d262faae2a5fbd9c00baf1984ddda59940dabc6fb4d8c2305",
+            "5cabc1e71bd65ff0b660e2a3a18b7c9396a6f422bda0a964fa839095ddf6cc2eb30c7b52c9f29d9bae541ef72455f63ced8c193a4084bc31",
+        ),
+        niels(
+            "d2d784c9f8bb26a56aab94f1a93397409694b4ae719ad024e422c5e67d58bbfcc9f51d26702b9bfe47d9bbbc4b2782e3ce8c18996a8888c0",
+            "56c00f2a4ce406b45d9f79c25d0c92a54e2e5c2725a1f53ec41006f65ff99435f891f35003db8bf9ce5ab0601a0dc31fd4e5eee14b3bb586",
+            "7ffa4457b7d161471ed06307f500aebf02736902b6a6cff0152288ea6d845bb83c72f7cedcfbb9f3779eb73fff374ad094b7c894563bee70",
+        ),
+        niels(
+            "169ce04a2fe271ba5181791d33b9414e8fbe0cde0d6c4fc848a8ef4df5fa6d1420ba6429c8ffe98ad375e9c129808a6f82bcd17859508e7d",
+            "60264f284f44f466769511a589bcc4fd0a298169ddebf202aefe699ebd610121aab6983a6ce5b77b504766ac2d842986f78a6420d816742f",
+            "385fbbfdd7eba3abf744832f626064c5019b09d921d0ac05f99db33a8eac5282ef140f0768349cd39f77bd096acc2f1616d79abd3dfc5baa",
+        ),
+        niels(
+            "e2d0e56829a75fe9d5a57e20cf259a11a62a1ed4953b4454cc4513453ea89f80c5b9dc77fc77005d54857583c3aa67e2a8333f2ed1dc7541",
+            "2508631b2bb3a60fdfdd07703514a62c835c5090753074336399c8ab1822984e2ff92c1403422314f51e0d31ad7d50199b2a76d3305af434",
+            "6dc0b44ac53c5babc6cd1915df22b8b2ff9b58036521e2ec06748c9bcfd590946eb196c094dd530bc630e41c34b240df835010f5eb38a8e0",
+        ),
+        niels(
+            "5ad539ef4f09ac6cfa07d2dabf06b6bcc2874eea6ddc05c150257364d361fb32bb2002b542d0384c1f4b5cefab7abb3d36ed96b69a71f619",
+            "46490d4f0c3daf873c5aa2d4c5d6ac42f6e28b0b05703237cccba1fcffd4e5e4e2a7c658fd9ccdf17780be96f7a9607598ed0021f3aa82e0",
+            "36c3e82e1e77164c480e51552415060fbc655b2a4146f8e9d3dc41b52f6726f1ccfe00c0ed80470190b138bad2d28a585df6c590ebd9390b",
+        ),
+        niels(
+            "dd56f3f4a23e357184101b42e90aa353f15811c9d342b1dd386aea60e4750eaceaf29f2798afc44b9e535acad85c33c5fd27e61341e2c74f",
+            "a24b602e71c4b60965a0f6415d1d94ef6bb9d7ea480ad45553209fc1e7c48adac2313b80831d545172ede5c7ec44bae771f1800ae2a4e394",
+            "677fb092f3983fbdbcb791fbe9c2a379ce52baad06925a419aac29016a85531fa72b46b2460b55b534cfcce1dfebb0eb87f0a93478b9ff60",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "8bf85a93807774b8fd619dada689712f9b807a1a6d3770698d1dec42f206ad151548471dfd8d9f7f930f65a5a26006c9e0a1f95010e7a181",
+            "40db72dcb34471af958bed34023670baefcfc2943c5ffc6c4e3dc441c613ba678ab94bd1b3106a47480851ca35bfae7ef496ab66c3d02487",
+            "18714b94454577440afe70d5576ec0130ca6e37acae14ffab4868656cfd2d40f1d4a639da34ebce87e6b3b65b078fa81976dafc41eff0779",
+        ),
+        niels(
+            "26b052295841439830e2c869c85284f3173cc8de111755364225b74d1795d763232f155c9e0473b6725397b8b2dbd8facd2770451a7d1cf6",
+            "2b7cd192dc6dc9e5754e282311706365b9542e121f31450229237701795d897a250631f0bf1eb815b2d22698f8a1cf88ff08a37bbe002e91",
+            "728c317b4a3adfcaf327a1155b911117a075ae56c2833d6c89bff0dbdd19af9b03f2248ee2a6785a451d0ed009f002216e416556efe5dc29",
+        ),
+        niels(
+            "bf421b0abcc9c9efa792f06722b4dd65b4fadfd5f1791a0198d716743359dbb014568f10e102ba072a19746170b4c93f525dd31301862e97",
+            "68d0c6d894a4c8cb31b43e36b1fd9447b3b681e6291f3f17f2782a4561da051bea1d392f26295588a346af6d955b5acd340b53d970d6522a",
+            "df6b5504298a8fbf6faf8c734f9d426be09b5d33a337f45aad3a9b232f313539ee0d949d2a62370158e6546bb90d929837d78c8670fe369f",
+        ),
+        niels(
+            "e08490563826f8d406fca9a2f686157d23d31f30264e73075cd3e16b8de367cad874a901299453cd017b1ed661a7b75840624981aaaf8c1b",
+            "76c30431957fe3b5af1d9922865f4e9540dc6d15dac9fb4764d68e9485c0818ae66db22e8fadcb978a8a75e7b73f10c24be0afa3cf176078",
+            "2784fcd8fc1e07dd602f78044106bed47890be41b868b9c137016f30b688a79003044deeff47e60e62e64a2a911772ab5504fa9292f80fdb",
+        ),
+        niels(
+            "341f909168fbef22f2eaa7cd430221553cec53c4b2bf5105b2e5b13c1b86035d451955daf177af3e72748529013c776465926f18ebd9b5a3",
+            "0b73f0961f01360870f81c223c5609fa4a95bde8b40b5210ea4d71f7e33c2841fc8ecd484b346954fa9f4d0f893d80770c131fe27416a778",
+            "48e2d8fd3cbe6b8c4c9334e7522affbe35e2788fec6581e40604db474fa9bc5e780a3a05a9fdb50919e26ad05f057745f6df89008a44d72e",
+        ),
+        niels(
+            "1403d839b4ab2fa90d6dbc74541cb7576af3982c2e259b337f5fdefdb0d72443c80b3457d609bcf48c6246aecd03b764b869b5a053cb4008",
+            "7a696b3ff61e893fba8d61b128c2496e7fb23830a374c71c7ffbfb2d70b0aee0af1e6041b2da70ecd7c9d90d321013341ebcd5ddb7823104",
+            "6811b8c7ab97c12a9c5d1f26ddf53c18fd0f61aac36a6bb4cd30b3d843e39dd84597fbdce510e0112cc62f181c9a88da560cc123c35c7319",
+        ),
+        niels(
+            "082813955e095b60bb3a1255ca05f72dae53e645452be8ce6819fb3fd7f4329b885f5d56413afed2077e487ee1dc3a8eaf4103135f523d39",
+            "7456d583ada788b82b0828614a0fdafaa63a5a62f16c1c14b5d4a8caf9702a1d7df42e6780d4dda20348ea787f5c8ce973efdb6b0106d1e5",
+            "7854b718e5f0f0a31b47406db084bc8406229a7e1f899850295d5cdc028c99a46b18eaee3a4f5d938298108ac69f13001e405b5047d0b2ad",
+        ),
+        niels(
+            "c29f9d60b2ec68d09ac6fc237d7c183a261b7de10b7bfef64b1f0d39da5684c8274bdff0ff016f2282f91170dc5848e9dcc2e214bdb0c8e3",
+            "fd2b8b4d26e004870489d1f5ef688fe7f4d81a58fa9d3d81d2aec21cef7c4e4e3bade5af91bbf7cce9343fef610e5bad3e24015d87d80014",
+            "3a777448ffd517fcaae3324b6da87ac90123d0ca07f0fbd1c9d75d81608fe2fa112c1145d68bed36bd770276b373148146851cae247daf20",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "1817cfc34273328ea263c68db4bc2f006aac211248a06d9be6a914c98883b753022eb13385e8a09e51cdb716784e3396a69697e90dd8311c",
+            "56efec57c0824169f85fd2c1b276b47697ba59ee9ceae83a5973bb346562f7b999935423ac25bf2ad968ffa9461db78c43f0fcaa17232cd6",
+            "63aaeaa9653c6f15316c6c11cb6f4790eb2b8cce51b121c93ad9eda0d04681edd5034bc39264901a4c22dce4e40d9ff3887152f257d9ff4d",
+        ),
+        niels(
+            "76ea620a4dd1cd7865fc980bdf8f6090b37c827966086d1b07f4b7b0a37cf1c0c65e4bca8b43be96003c41b6343cff7d7e5805d792af2f9f",
+            "34e634af36c716b3cf1a0dcd1b731269886022403dac54bb1588d98c295c4a15bc6ab8784f6b253bb7c026057e164cb1708d6f50b44c861f",
+            "540a4f6115155e778b701a05fefed221d349f8eb82623ea81919a61eaefd2d1031cbf5af02c5d96d8d596dcc8ece65c717c75dc5abfc2c61",
+        ),
+        niels(
+            "7a80531bff2bf44e503bdaa28247b0ed4b2500a6c8a6a1d26107d14cec5336c05c66a3cc1cac419c310711a9cc36cf9fd02cdaa85d8b15c5",
+            "1f0ccff4bd6b71368ba107837f021673987e1adf92993e1e511475c7d496317894ef378fa0d9f10590d0809ef8639f394708b8cd93a083ed",
+            "1d9f9ccc140c0e0f0d62c35eba741863357bfecbf02fb8c385667b3fa139041f22de92db9030081f71932dedc008dad6599c44f54bf5a632",
+        ),
+        niels(
+            "285d4c2c8e631b4ff5669f17537457b5c3285a79ed878e0b48b55065acfcb04af4a2fa62047d71842a0278fed5b7802b3c9f19cc77799b10",
+            "0285e7fac9cc179bcfb586597ebae59adb25ffe51e0d28f4de759f6f83ae47334fa6a9960fe7078cc6b94b1c5caa428becad920dc7923fea",
+            "f25ebc2f0440cb04e7472ff24a8e7d68edc734836b4ebcb7a29c44b698039a1f87b550bcf15b5335bac8f6e979d9b12bc2fbd1989fed4dd7",
+        ),
+        niels(
+            "dd09cf81d20bd336d821b72dbbe4933e5473552e9769e3b5e58e43e77dfb3b2b691cb2bae1a58c755909201bc839da034147c72ec4fe65b5",
+            "e65a1ba80f1e79330bb1ec8118c2668573418fb7d775c4c2b80f2dbd8d6e91a74dc557638ad7f64e5a91a9b8ac700f546e6ec30a5bdba258",
+            "93d4a7bd04a3a9a049380fc936c433c6528ea89678e63679d9f5f5a2374d98319472b5fc48aa687903be969c4cc36005b9b4570d2e8861d5",
+        ),
+        niels(
+            "55a601e708ecb80eedd02b81f1f87441c71c2c5adc1682776ee7fa645c9b4602b8b7d8f69101e3cce2b47dac269ee9e8f5cb65e4bc83e6ca",
+            "419a15fb80b92ad299463e6bb12fe2a9efcaeacb81d9d43c1a5043803f754699438627da59bab0ca24bfa2bd1e56c43d76fd9fab2838da35",
+            "ad16170c6fddf5f9a467a0e1b3c017e64db34be8b38244b4eb59d14ca04000f766bb91b258147813c1e54eb66993490da321678a146d93ec",
+        ),
+        niels(
+            "8ec5c3a11cdc7b6f0284bb36ada6a5a6d49e6bf1e1127add436f28ee7107ae11e3c5225676621c3abeeff59465a127c02631f6bcc00f00fd",
+            "728df7232da9faf2a79bfa90b80ea7b903db7da82bbfa5c22725e95e2871273fba656734b466ccaa530eb62ca89272b8c2c8204714b3f35f",
+            "4d11759ccfdea60d4768ba3d8670e5448baef776a53474dba30b0c0b828e2f80146b3ab199de53820298a3cb2a6700e5743f54f7e5ce28bf",
+        ),
+        niels(
+            "6bc82637152005d6bdfe5ba784e6135667d7d77d5ad33a9ebcb208e03cb708cf4d65a0b98d9705d0b27f63dca15eb1f2f299ce8452f27542",
+            "37619fac999be01a468aa126fa3132c38af65b84b63ca4ddcf38b72bc1e7935cd8bfa5211ae9846f6372572f4b289b4e52763d19d3b3705b",
+            "adc0037f888ba35bb3db655fa7b9263710b479f4827b7e74a7764d5a908b08031d470f8e90cfd12be09f0c84ddc2e55e5a9291f620800786",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "3906c4d377ffaeaf1c4d550de50a27294c57f68adebbd000b3f7d5454d60201c80e69a1005c8d009b4c73840e6ec1fc4af0cc432b2326422",
+            "861bfabdb544804b1ae6e6fb1153a69cd5b656a3ef65c85cc7e1af9d6bf9722dc3d582fe4970dc72e2d0eda8b2e0a591d2958a39980c5d7f",
+            "3a478e6f36451ac91367ee927770962c547d79817227acf037a96e4e7c0d6657965f59f483b5243b8485fde3717b9d93361ddf7bc6e4c22f",
+        ),
+        niels(
+            "c606d73b55c4f5eb1e7c79ef5cc02f936414a5e6f058bd532076ba101035b1194befd6d1f7dcf4e6864acf76a090c9013d85fc5cbdd51c8a",
+            "2dc33e0f66fba24d1791f3d73a6fa2a1ca161a790518131727b395f2da5d9296529a4951228623baa0426884fcb481338394836e8b4d27d7",
+            "59016055905be1615c618c1ae8ac7c1ca141b5390ef62c073aa86593dc823fd9bd8e44cb2d026ca2baecc407b5d99af44ded26ec39b73d5c",
+        ),
+        niels(
+            "766631ae39813653210270f5e62be2d3ba7d393c958a5685812cfb9012a2cd54ea75b320e91500b6340a8674c72785a87e9d46f8e12090e6",
+            "5899a835fa0db8adecfd2f692c800118d52430157c54dbc2e0f225b032a517c070314df2de470f5000e443a9bd242c6e920e4717b54bf6d9",
+            "8372461da0483dbd0add89e4f041540a08694085749b0a67f13bb1b53c3441e63e451e4c95e1d2fab3844966bfcfb29f88aaa719a41136a4",
+        ),
+        niels(
+            "7b296212fa4a3514dd594bede6abf014056fdce65c2fd85611cee8721b67482ce63cafd0b8031bb47a02d007202ab77e6731a29912d6e723",
+            "f967fd6af05078960eeac76cdf1c1fafd9cae33073cf888bb7bb2ebb081d8c7bd8d68d715e7132ee7df8dde2ad1e2442dd81a62e7c08781d",
+            "a946f147dbe7ba683caf47db23e83463384f123073a5f1dc41bc4f8504c60d8b25bffd585bbe8692f43b949cc5fdf3647e71f669c1ba58dc",
+        ),
+        niels(
+            "1304cf9f1ef3f6a63f464bb20ca39a381614eb6ea0928927bfe593e3ab6830f2f5e78e0720264c9b771dc6bb9f24030cdaa321d294673307",
+            "01aad9611c5795a559f2c731191a0a4329a71e2f60dcc6136b64f5fc0b0dfb5cad946cbb60a2f9fbc780d213b9cec8f3c3cb280a0f7af789",
+            "17db0c5c654b5bee70aaeaf38015d76f9336d6e0dca5ece719d25452f783c5cabc042b274d291a91ecb66953a4830116691b271039c9429e",
+        ),
+        niels(
+            "06609a798e55d62f27f614a82d640422c8d676062ce9a1b69c523dcda1900815b34947965a7208e233a4e3595bd400029d033707bb48b20d",
+            "f75b86a3eb5c3716fc67c5b9ce714bbc1ec10f4a86f5005835feb8458feede28f1d9e77a107bc4f1e1ae9eaaae1c0be99f91a6342e9294dc",
+            "4aca3f8612be91fd29e7eb79d15abdefe8f0d90362648a3432d1a67e6540083cae80b6006422b79221e4735c208843347ca53d92e7fc8aa2",
+        ),
+        niels(
+            "cad78a7e00ccddc2a397272f26d5404e0f90a945d138ed2e20a4254791b6ed4d8aeae9478bb6895870db49178bc2d7034d0a0d3e2bc4c820",
+            "aaf357a84d4f9784900cb8770fc7399ac8f504c3a20363b3072dd4af2530eba4c765d398cad35059741680db503933a4f23c10c49f96ba81",
+            "23b6f438d6427fbe8f91d8e11156212310ea1febee41d4bf7d9389beefd16b41bbd4b4218d0d28beb827dece7ffbc77bbda4a08be6920b71",
+        ),
+        niels(
+            "a98a878ef479490e307bd55c06d1620bda3afad2da51631b3f1b1a721df6980ca26987ec2acc3007168158721f93aa9211de58ec5749fc36",
+            "87ec6f79efd64fe76164b67290bab1fe03bd354a08206238c8d12bdf0c82d8eaee1fbafa147dde8c34fd21083eb5aa71f77b337388e3c394",
+            "c1b39b6c4488d5aa7db1cc3eb44ee61b4384ef877022f967e94ff2154cee76f7a4878f89917f673213663652cddbbd7c8dc94dbae671c065",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "457fbd8031ef396a905f91803e47696a824012e2eef25c8b0059715896ae6948d606a27e0a9afd0638569e6618e76658bb992da686d83195",
+            "2837d2cd55417974002693b98dd8598ed684c0f7fa38ee910c7a8faf02f4b194f8f0d60ca9b9ef06fc293a8d1d3977433df793edd2eb4af4",
+            "69bda72d44a4ba3313e0fb78f0cb9ac5ce7a11d54fd0ac0368a22301064b81559bf2418bc6af007e7ce476811b0419fe292f4bb69ce006b7",
+        ),
+        niels(
+            "ad0b783d707a0f5a2b363d95828e39c0dcee5e7530d37f4c5a86b3894123c15d670545cdb47bab742c36b676f0ac04f4090003b2b6e450f9",
+            "e2b359f51badc0251c90c9888d1b57d2e04f60dbbc5dacc74d931885f939e6275a97e980db7f0f7898870b4b9b98a40218856df1b5604859",
+            "77cd4dcd0398a7cf3c9ab9b5998737201a2c9705802ea995a4bf4b1620b3bcc75471a84d90a7134a44d95652a7a4cda52f7a1392097007a7",
+        ),
+        niels(
+            "e5da1b3d48dc2d65722de769f44767fcfbcb2dc77995e611904f3c36194d5727a44a7b27b207f1cd741685ca73177c7d422ba8a5aaa70488",
+            "13bd9a062470f01f30cabd91ef07fe5ec016c23eccac999979dfd794fb1068b0f71543e2b8bc0d0f3c2e6f48031020e974ba16edf8b75d85",
+            "bb860673d5a18e9cde9d588272ef6b16608b40cb70181eba4f39118389918fe7ada0d1bea1f58862c494596cbd850b4d0cd3f98e9f26889c",
+        ),
+        niels(
+            "402e6ff860a2610eb00f0d600814384b3b833695f8a1d7821a8054748940244c9fba03a55e36fc730265c2f827a26338dd73074343a55965",
+            "f9ba95a9530cf3368b9f0dc5f448d1ad05eadb8b0447715d0e62140424f9bf8358adb76eb25ea17de086e7a4bf77f0f8325a08034000b6ab",
+            "c7f9f521a08c4a3a3c7ec2c71622fa69cd67832024d25d1f9c2a50d8fc5e895b1723cfb9bb543092ee3f5e945eb9d64aea1b7d7ea7ba698a",
+        ),
+        niels(
+            "cf22aae121a2aac3e6f4dc3f0adf5c880c66d32942fc5486d31ea24f6a779a2b4ee81407b6bed503f61df1711ed3de4a70cac81e080d70f6",
+            "0e263e16294ebe2c767a496a6733170c842f4c1dfd42b6231835a571345b46907617cc20887154fed15a0e2f1def666322e973163653e197",
+            "dae30ea05ad2b6f24e62f85d31390d1df20a9badcbef9e61cce2337ea58fccf8ab504909940ec939659843da656c357758f7cdf3c20384f9",
+        ),
+        niels(
+            "6198a146b567e7c3c5c3bfdc44f4f224a513a878d11bb43501a3d743c22b9bb50f53a300f8a78567a2d40ab70b8cf9753d60355acb77c728",
+            "423ddeac593f895b2c0dd9e2c01fe941071d71c2157b6652d1f39ef0cda7c28cb715e4263ff11969eded9ebe3001d0314e3172d9c1cd10c3",
+            "a422b607dcab8f828911a98ed042f00bd539b8bfad3891ac20a51d034b2b295edd063ccff3e1d9dbf7a97e2718f2e86d3ed476467740ad5f",
+        ),
+        niels(
+            "fce04253aab167d5ecf616eac6e4ee69d0cd0c366ed6cf93e2203e9270fd94e8ffa1111b92797149a4e5435643d0087beae39678a41d5e49",
+            "e36d414bfc6f8e477ab8b2fc772212f34ef9d1321fb602c633a5837a997d96f5b1ee82e69b62e0e9323f4430bfa35c23bb1b00e63ea5ecec",
+            "4b4415e91ead80eccf49b890e5004a5ba0c3234dd7c09c2bee2fbcff62c77c2a032a8220dafcad4982c5c5a42544c280e62d180086b491f7",
+        ),
+        niels(
+            "50a159e92f38d713953ae4ff9f64b2d849cb6a60bcc0bbea81a19c0e6d8b2f4c7c04b132485267c621d32ed84f76cf2da8e60d4d8d1215b5",
+            "127116a3f44ef9ddc8dfbb0fb08e72e38e1be04610424fcbb75daf133c4ece67470ba6bb512cff85028d4789afd125049ce3ea550217f76a",
+            "5500a2a2570a3bdeb6d5a1041ff4de168fb00c8969e11350dc5f9a80157ca927f6004e2ca6ad54bb82fed2bad0a55fc5f51338ad35905439",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "f99f1e7d6dd2dce8d7c5c1e945b0263bbe7ba8c29b75a88891158ac422d1e46ac06593c9828d58c112b29e323825d10a0d007bfc3452cdba",
+            "ef2e87fd981324ca36c29436e3c6186af38129d7c1dcec14869882e13dca668ccd4882a9466fee66e4a8ea02cc70b61790b6b5c033af923e",
+            "50b541e56306bb4be6c06c05a043299210dc7c4aa21d2e98b0d35ad736ec7a2c577727cd16a8f765fea5780fa24849674592a67bfdce8761",
+        ),
+        niels(
+            "b454b45adcc75475719dbea36269e0234ffb591c6a41e7ef49727dd6670273678ecc633b88bb3916642f96f216cf5150f6aaa387cd36d314",
+            "2a1defd62162a29ba0eb7f47350bc9178ce9737ede2c56aef8c447c5f2ccb57eeb79b21d608aa5a81212f8ad7fbb7b38af36f3b0accddad4",
+            "34d8bac97086ded26ee75079c1f155360769ff3ebf5e4101bf80815a99d3f52d76bdd12a1484afabc105fdfb296b0c03e7e8444e7bb89ca4",
+        ),
+        niels(
+            "d4aaaa59ff22c039d6643d4e73ed216ed87a66215ab4ea4d7b0c05a77e3c464d76a875094eab34042f1962b5c9159256eb97c3307117f9fa",
+            "e0257ad8d587f8086d2fdb07ad309d3aae51a3318261f984a0889821b8c6d07587abc443c3c8bf1effa81ae1176aa0503b2e6bb1716b7894",
+            "942862906ef8f116162e13a7756c9bff13371903bf4b8f1743bd31381a932aa6aa3185b3bc08b364c7e1c45e716d72fc506621a0e05103b6",
+        ),
+        niels(
+            "b8e296165058995ecc8bea5f05b26c5a7f2b9c8792da15efdb22b0d2166c858870d7bb324e41f30f7c2555a21e8da97fb6851a4d63a7e114",
+            "3ebeed7d554056f72f4abe9dea2e368cace6b765a72167e65524064d5f7cfea93d45c30d219fd94e045af93f33201e5719ffa9ceb3e1893f",
+            "bc4026e73c3d864db821723ea47c08c4b8889f55672e81fa9a4aff5a729eb3b7397f7a9d963533de406e7baf9d663c80c184ac750e0454d2",
+        ),
+        niels(
+            "5f834d55137b6995e2d751c029d41e70e233b3ea28c4fc2574165ec34ce7953291d9a51226146455ab0dfe30486fb643377fc27ebd10c88a",
+            "96729a9fbb90f38a7fbd2c8e77a1dc555acedc78991ecd1369a1cbab03c962f5b161d10fb2c5baba2265d829e3516af5ecaae45b33e77dee",
+            "52949453b7a02efd56e56d6d0801f85880151c06d5b8213a89926ef2d5551ac72db90b7b10a6b83bc6675a86542bf796a25e6535924edc8d",
+        ),
+        niels(
+            "673d1ceb0db861b3d9a6548a091d9240d036ea5d539ff8f72e3f6f2ed43b2771f8ee09c9e2a92b90e504a5c6c17047c42031500dbfd60a7a",
+            "9a59e17fff8493aacfe85d8e65274a0e55cb2b2d2bbe55a4e7ade9472b98debaa060634013e94625f3c63b30b6bc1d08564eac3f6752e1d5",
+            "8ab974752d4cb0ad9641ed5cfb3706428617ff05b1c5213708a66acaa7ed177794cb74fcf26a58658d1e32f60da1ad9ab5bcbc99af10c47e",
+        ),
+        niels(
+            "71f7beaf10d153280e126261ae24bee88961e77ab8d05e7207d01a5cdf7ee6d36f84bf2882289b7eaac898019933fca8e39d473eb22ab134",
+            "8c480ebd168ad8c229327edc91c2ce42079aaf4c29edabaf3e3e4018e7642e63759439cae7f5ee2687f7e8ebf378ed5196c40fc73dd77bd4",
+            "46e97ca2b549e260a8ab7b8fb2556670aa671462c8280f7a5896f80fa98f54b71254e1d561351f42c09874d91a07f45316030d3bec4219ee",
+        ),
+        niels(
+            "465ad1f9726ca81121451a54765aeeecb5994cce675592598ddffbce3339a681b07d89c26453c02bf422666838b120b485489a4c2f5e5e29",
+            "04f752c3e1daea6c5ce1b96adf8d3e4f41a208d051d121c8091d269754f0872164d0d441d4a2ab6c7f16be31eb98b9bea62363584c280f3b",
+            "3df8f8fbd8d74bd59b3cdb219b6dfbf3e6bff8f23fb019d6892bc1bd687505d9bbeeb7946624fa2d603bf529b562da47cf48b41c6723bda4",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "692eeac9ea98d7ea0129a3035d2b5813c1d2142f8215634650c011d6dbd9f04f288f4f770b1b3ffbd54e6b8a7d67590d679953bd9094e27a",
+            "2d1b1eeae64a3b3411ce735f16fdca9bf7861b2f55d7a9fbb33a5868403762a55d69e60b733f9960e76752585390fffd10216751df60ccf1",
+            "0622ab39c146305c49ec18a1041e0664aa514fd686581eaa901e737d44508260e4e552ffac9ace2c21e423765c70eac7b088aa59520e938e",
+        ),
+        niels(
+            "1dc7cdf0401a31de4d2887487fcdf485b93a6570b1b088ef58fb677a910c1143fb6e23ead8a10676c72689c6d5db9610bf90827ec909acf4",
+            "7b36cafeec98d90ef6cf7a5d4be186ce3ff3a0b5b60af5ea9371008e9be1dada78cb2b2ed9a6d550737b71477c8c38f066dc48bbce9d43ee",
+            "c62af76f8d987a90251f78ff99a863224bd86a9009a4c329af9f79dd1bb1759d38bebbc2cadf2171fdabf75f0ead5f1e6523795ce8dfb2c7",
+        ),
+        niels(
+            "bc2fb9ac4105ea611815193dc4b3904976d9060d379bd932e3035d69a2d2759c24580bc423f1081e11cf180f652e8f7ec28eba2337496999",
+            "6181591af595b5c7fee725f936e5e0927afd03b47994145dad260d422a006fa6bab9420afbb8ccb4046b96fcae1a6de08b36758bfdfbbfe3",
+            "9548c979ab47fdb1a49aafab2770b4780ec955c65797ef52ee4e179529e5ba2e99933e3d522bdd9f2d3b288128288a77290f53c997306c2a",
+        ),
+        niels(
+            "ef3d224861b13e94fb2a44a33377b78c37765155b9378f6ce7ff0f7cdc80fc6aea4cf9e3c0d75fcede07546d52e0d2e1a1ea9833b1b99cea",
+            "7f5c753537198bc89f4cc705d2234de9b837d2afb52864d2a2d18e883b0d306627099445a7c5b5b6b0b9885e78e0f28ba4cc69ede3dbd811",
+            "d1d0ca53c354ed593c6445ed23ee56ea257c27c36bc9d944a15ba2c0fd23f0cd2ac1f6dbcd68f03b10eb390d64c068c9752c52c11d81fc45",
+        ),
+        niels(
+            "6dc2a346465cb07c54b8a333a12bda5f54355a343a0672f982739f0a9c7b938ff880426a22528ca2f60ad7569339aedbb3e2d44e521b44ad",
+            "fad0f6036842970e6bb5881cae9b1579c4b5159ad06489b24b205e4b8e5ff1cfeedea0743158a8c6dc3cbd887a4ca996fc49624e1e09fef1",
+            "e94435c4231e026b2b9b8ddaa8aa30c71900f4d0fec67fd73bd3ff63cae89cab205f072d4a606631af1f52b3d2962399d5192001a91029b7",
+        ),
+        niels(
+            "bc2d4c4effdceb8511e2c4ee51ba3024bb0385dab163c22a57e1af9d5c47f00f314b50ff1b878a43e0a9b013f2abdd1dbd571ffddffdb772",
+            "dce70759bf7a1591739da83369040f42345a94a44125605ab9f65088c10c4ec91ef1c1e06c9727c52e5bf023bcf820456a18ea8fbe336992",
+            "9e85166fe9e6fd43f7d95cabd2ba3a4a78264d8623ff4be954305bcb9cbdc36f920436203c858920f1be02a9bd8eed030dc53789561042d1",
+        ),
+        niels(
+            "bbc018803613b59dc8557857d0d5de6d2f447e8dfef344db9fe3e5f38eb91fe25c36183c900bf8250c372ac33e53e67d13da2a600097bd31",
+            "577c6394dcb781e4128e67094c1ed70bf918265dd2ff4c7a0352a823089c3eb2ebd682692250c3877ad17c4a557aa2e310f4c969cfd16cc1",
+            "4156a33cb4a99439d62fd4307fb5d6279a5dca102b31a77cd927e7f6299ad316f64433810985d63a470c633eedbdb7106570b61fb5a0f2bf",
+        ),
+        niels(
+            "0a004dfad5ecd0d30a8ec12628e49fbfa55909561f726d54c11f81355355265175f19c807ab101137d6074b5798c353d340390b5b6dc9680",
+            "76817cab84b7a80fde8b640e83e2d9738b17eaeaf812a2da8cec50d3a094cb81196a7d42b05ae88aa5b74c22da089ce4a1c95dd478d72999",
+            "e65e23b28886b8da0c95b992f05a66d0905ae3a06873f21e45c1b4732a9b7b4049c15c9f84f488c84997e257c12d75a68c963f62de88a57d",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "e4271274337b8655924a5344313dafd847d4be708c03f55779dc287746c033f977c98575e41cd592cec782e30cb40ed0deb54bcd54d8670f",
+            "0ef174d73c76c3ba943e2a6bddac4dafa62c669f445d323b2e4eb0163b1d705d6e9a916db1860653c24d33b87d87a09152e766c4936b4c5c",
+            "af8a864aa7995af6a1f0a251d30a71509bc14bbbe7e4841d28d2987ebb29d20d8b368b7ff7fb95dd007ef6eb5bfda59b7f933fc8232fda1d",
+        ),
+        niels(
+            "2af47eb61034af316bfddeaa81fe9d7516f9f653609ab8cd3ca969a74cfcefd9215415264045f907e0f9a13cf3aadcc59f9cba20d00c69e0",
+            "ee875ab3aef4eceb9e85cbd8e9961faafce005896d90bb3e7ca8d25dfd2fdc0faf78f7009823986bbf5e90177c286f07539d430f3ef36cd6",
+            "9963a279ae4539b377fdbe21b1908235422676a4fb17752cf22b3b5841b05b41ef354126469a188a04ef8eece89e3d36e361a76a2c2bb6a8",
+        ),
+        niels(
+            "2e2102cc101fe53a16557a4ac267cc6f75fc6d4b4613f4262bdd6b5f44892c7cefcd9fe97c20a83ce96be75c6e0d4fa5612fce70272e57bc",
+            "af2ee03dc14b50865eda22dfb9ac3495e5c59818a3d604dc666511b703cc9727d59213bcb6c4516ec8d1c32d628ef830dbe3d11ad4caa3ea",
+            "423512cb89b85776ab455758dc2eed5ffa7053dd29f33db92a2b3815cbea44c047ceb75bbcc0b68fb0f57d289e427291444797f9c387d9af",
+        ),
+        niels(
+            "c6774885acf1e1c5fd7d1eb0ba32492b3f9f78906eb19f214577a9d9d4883d0c0ffcb4c68ed8a91ef1aeefb30d1e163f10216e20fccea19f",
+            "51d23de982c8bd1c8c9dc3ded3b5292671179ab7cda933b20d4842f61249f58303166430632c24d4605aac44218dcf9e003025925b48186b",
+            "6675030d802f881f163d22a6e97835f708074d88cccb9c03ded18af0d7ef2f47504819046d8b3f34e13d52070074927c10513f22e4f71ca3",
+        ),
+        niels(
+            "e21f86a175da0c1a6e86cb1db83d219dd46a6148c8174ef0f5ab46be1adef106f1427bc348b31c80a02912a3340a95e6c925fe9fff63ba0c",
+            "9af1b105f4fbee69da6aead17d9870fccbc51abaac65fd705f1fdc6500831b0262e182eecb80c5be0d7ab35078bd91b6adbdd61fc321e2ce",
+            "e39a5df025d05d1e1154c17ff311bbb9f45bc5b147ab3788934871d211778891d6b777dd934aceafd7a4daee8d69741814f91a12990101e0",
+        ),
+        niels(
+            "dae817b41d73a488bbd5b798fce62e1a10475fb217d1cbfbe34b51598a22586e7a8b5b7ff69b84dae09710423dfa39dccbe15cd68264b2eb",
+            "2be67edd579012cb2afa0c0b01c641c18cb888e46fb4dfee6b19a39a4e5eda74f41b733efa0fbf2bf0202ec840349278590c8b48c4e61de6",
+            "93ff34a9bf7bdefa8651498e89bb52d3629c4ea09b585a81b69515b8ac249ed38bac0b73066f970889f2bf47fc634489eb970778ddc47ad7",
+        ),
+        niels(
+            "30899cb073058a6048ec02356e2bbeb3d5451cbf82f7a74ef6132928e7f416156bb7f2105f34e0ac7c7bc4cc37b4a678bf7163047cea3496",
+            "e5acc378b0a9a5821d52ec8975f5597d331d72a1fe6bc46901fada30ef534411e028c3179124b4890ac1ae7e069f64a00e83e5f6dee161bb",
+            "11726eb45ae5e4f6042af253556be56258a251e7e9e034aff9b197db606b305c8689ffe554a0eca3f310a6ed0975736f018c73756b31cca4",
+        ),
+        niels(
+            "49b976f1832d4e44516b7bc3547bd5913b0baffa69391b18a3856cfed4e0bb9f34d9594caf9756cbb486be2af713a28d6c7aa15fb1c53ba7",
+            "72c8b5834631c7c67180ba7a34395aa38fe369f9cf91c054566967835b420f5e7cf2da85e5f3a762ad1fbbc856ee48c916dc51c8d790362b",
+            "df57b41891e48b197767051d2c52df02523dbe476a7b18c10b8d64f7d8a46fe5d9132286d35fe92aafa66474ea14c5b697619d02c7752557",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "f565bd74eb6318486ce8a3cf172c9a5059f7458a859f577a2e43e751a151aa0a50d98a93f119c380bc7a692b0938ddfe50f6fc0be7509399",
+            "d580b7cb512879c5cfc8a988f8605c3240d255d79abfe514d11d8465e11c823a4e46b94be47b34bb1f6be3639d1154b78c1a61d4b6e7e814",
+            "a8eae54f2165920a265e3badeaadba99766b9883e5e38528101a1989797187382e06bc30313bce0dabb03f5883db3857267a928c549913a1",
+        ),
+        niels(
+            "2d42bba45abd9ebd2bd42037a7087eea4249cd74cfee11581dd8c1f30e2e48dec1d88081354791810ddf0d09e0f9c0601f96f6ac100a442e",
+            "10bc01b8256d9607900b99a7397026d37df05f551bdd05618d4377c7fee9d2fc85ef1374a58b3bd46320a236b26aa8e480db4b9776eb7bcb",
+            "57e7f3e4e47d4e980cd834c4261c296b96635a293709db29981f235a89cfb6fe10eec72cb17290d074b625058e88b4cd7124e4ebc4e41411",
+        ),
+        niels(
+            "47bd26ef2729b39ae6d5c260d2f50d0b96ae89a5e88e35dd49496b291bb319340a7296e2e5de617e17734d9ff856372164b43e93898dae7e",
+            "fac24b4d3a6542238d792a0fa71c5fb8ceaa9512f26ce12e07bf980e5e1351dc03dccd2c6640e50eaa39471bd5baee808d7e4457c321b997",
+            "f348b244a692d05fee22f49df3d1c7b38a23ea39de21ddf7d30dae798c7905bea9544b84c85b4bac4eda4991982f31c1fb0381942b52a98d",
+        ),
+        niels(
+            "6f22ce1ea985782967c3e65737a3c96de7d60bc42bfdcc9485d51ac3ba60e3cd584e5a1f3f79a7dcd24037adc7439c73f4e07c475f920d41",
+            "31000a3e7c452b753d740f4ab48bdc4608e380523887468debc1fa24af2aa6e3df2ba829c70f1b09ddd130acceeb3a6b95f4973ecbb06210",
+            "7bcc4c8a39249fce43b0fe09f57482d71b3e875543badd1cf6d946544a9aced7c0ac031a402fc5a12e6ae43a770f0e93f2824dae42ba29ad",
+        ),
+        niels(
+            "b56fa3fd8f144e6657ad80c9b56e47d54582dfbbb2bb5b2d7b588293780c452d2831ed1b666c2018b6f8e90f20ef059ca7d010d6da6a56f5",
+            "46b3ef26032a6a1d157c76781266d9d7dfe0e03e3f7133106839d99d8f6ece5f8e0b3928b0f2fb452f31af941a8e12f0fdf1962999433b09",
+            "3cecbb04e41250dccc5f74a5e28ecc6e20e50e2f385b87c888c9d799eaa11e82db5277f668be3018a4afc0b660bd91fa1b546f0219621fea",
+        ),
+        niels(
+            "178fe8ba191458586ac8ecdf021dc6451edc8fdcfdd2722af791fdb3bc84e41f178342e6b825af5c6186962e48ffa08e6097804f12b8bece",
+            "a2eca52cb9e99946e51a32853fec20a40d45045debe65f6941d4280b961e342876a47f1b980778c348a8fc6454965405199872ffbdfff0fe",
+            "00a5952b6c157704163b1b74ae306cd62ca4fc67c5bf558d5819bf01c47386f6943756e6604d1a66a22d23fa177ae216799637d466375038",
+        ),
+        niels(
+            "1fec53c06f1f234f7fda7678b311907cae6eb9fc7c36b4b7ef35a56373d75d85e2cc6f23431e6e241235726385f2803417f836757cd9c19e",
+            "60811162b34623b680eb04d261a5ebea5a7a7179dad9c040c6440c04d3c94c0e8d8c983ee3145fc8d90f77b60a416514ce616f229c88de86",
+            "a7111704d017f8557df51205d1ff7556b13fb2b730bc6814875699ad4050f9bf8983f15272c305693114ebcf52c5bd0e1396a6fe429205ac",
+        ),
+        niels(
+            "7420b273ad299e5f8653a789503d2211453923fae93be891a491b52a356c15091b28bbaca454177c49dcb71dcb3f734e2e3f73f003fad450",
+            "fbf80712749d0cfd32ee6e5e5624da19c4c479ed24678395e5637ba0588256274b9a5e2866b1e6f1b1ec95d2c38685530f7545aebf68f8fd",
+            "e87216375c323f44cdcc25314cf8412bde201ea4ada1bc87f6c864c1bb49682bcc8f9ec7bda320408a5557e68c44e76395e532e08d2ed31e",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "75802a736c8940229059ca612d02b69b3c75cc5081f43ffb846fff32a599eac7a71f5bc9eba16b2f555f3561337ad0efe4947b2278d5d1e4",
+            "f677bb19c03390c5760a94892c2edd03d2e2c7aedf92c670083ea8af426870a7b54f5e67a486a320ae475313c944477441e46e9b9cfd4fca",
+            "7f23825a6a306da9fadca84b2d1a36b2d905e46e8e4408f985ecece3e2f676b577baed65d4908fc07a145b473a71b9a17b76e401433c06b9",
+        ),
+        niels(
+            "da98fc60639b456f06bfb6e060f50ec73530aecd66ca32f666abe20f10670747f833e73dc54cce98077452e99066cfaed32bbfbce1f7c018",
+            "a96c14f4df02b634d00a243d84281cb46d0e6698bc8bf3b4f7beddd4636a6a0778a46cc1b44756cf01eae0196eb2234f6d3f9bea559f1b25",
+            "53e052b10c2937081cd0ec3304707a4992e4dcf43da1b3da763fe0b2b9f74af2852f073490c13314eebf3942feab574f231b899bb3359f16",
+        ),
+        niels(
+            "35fa88e5838e06cd222b0c9b9b48dbbe4dec38baf84a050a55a8573b773bf0079edf916c61ebff0b492571aff2f6e3107eda6020aea7085b",
+            "1c4cafb1c8d8dd113ee7a546829df1b7cc6a97a046ea376e537968c8e2ae474afc2de2aa7470364f36ac479cc371f423741bed4fbcbbb706",
+            "2763ce2bf15093865bfde37de4e32eb1f077488da78d8e3cbee6a408dc09e821ba6d3f5f55ddf1f6571cb5e1f2c76f0b050b320191cbfb16",
+        ),
+        niels(
+            "3225cf1e4c0b0e37966b8bb5647c76a369ea568c1548bd681802dfb65de68105b0b9c73e066985f3959d3b950d88ee7c2989f1d523a8ee9e",
+            "c3b17952d0409ab73445861276d8c1235042736e957f2fbc4f9ee139c3782f0a63a6940f15aedf6e95a6f110711481520d88c2a83d2a56eb",
+            "f0b0be803b10625b1e4a255c33161af98311891928feb57b967218e7f3e4eea9e540b46d6caf4c8772797fbf9b00def041a00428f875fe9a",
+        ),
+        niels(
+            "45cca74f5d4d10d8077f615b197ad3bb9ba379761c9e2506253bdd5c17a3de3c95f3a10283afd1a23b6f8b33ccfb1b78e89554dbc9afcb3a",
+            "6ebc3ccca2c344f6f41fad3c9116e2c8c1c74dccae227cfbb1e0b1992fa62e0a92134b1fad832dffac48bc4f6a0fc7f6f4fc14552da556ae",
+            "bc15822efd209476e506d62d43d4774119fcb6c66557f1c0cb7f21faf9b96260259da7e8c241da8a17c4fea9e5d3eaaeead1a70949832ffe",
+        ),
+        niels(
+            "7540e60d79b711293208677460735d2a590a6c7335642e93a2f1021892e02c16da1f2363b6bd72e357e0a7989760b728b2f0317dc5fbf03a",
+            "f00a418c1f8eeb8005f723ea27df9f08b533a8b303bd10b618afa077d6efd292863fff4805a4be31c9d8f7768b6d9bcee70b14a35cc57951",
+            "60a108bdab8e2d9606ff2a3a1f406ecad25d56da5dc772b0963103436346095fd6889fec36d92a3ede32c8332d8762e79a0185d863447b26",
+        ),
+        niels(
+            "d52499793ddedae971f4de7158393d4d5d8f06e29be5b881e22157caf38e2e754d80b23cd21a8a63da41b217e925cad2bfb50bc851d0d030",
+            "202878a7cfaad776e4540a4801246ae32e56050e112bcc656173c9e1d77eb7a1b90c02bba3d03e39144a0a57ce9627711b4a6d8fcd43a64c",
+            "86fb527c0035c277826275cc60108fa17f879e3851cc2f7bfe220bb7e203460b7bd6ebb3a9623394806fb37ca88755d392dbebb5172f8170",
+        ),
+        niels(
+            "465776419c3c025ca4dc9303750ddeb66744725aeed35c7615e422fcadb9099c2fd1149ffda51c9c11fa93e93e50b0273646001814388afc",
+            "ff503d62fb01b597f255aed9783800a6375ad862cec1b30cba81fae5511b2b7beb385667212021f07409ee9a0a4c7c85307a49149d185ee1",
+            "78a162f0db44daa13361c7d54ed51836f86f3762cbb8e353bb1479abc5fc19fd8bd6df35c59fc0b61bddbaa8371adc142cccb9db2dd35cef",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "684d53b6236afd0e6c42faec07c400db40bcf28db649834fdfd6b392e27c09b0d6c99890f7f430b61995c14311e72368de77f59ce7f34248",
+            "6792fd272c3dca9fc04de2ebf4fffe6a1aa2848c31a448d400da26ac7d631db9def21cdc9db7b31ddd67bdc488ecaa8791bec73df07ce496",
+            "551224d207f97fa909f8b1693df6b857d56cac7b0222fa1cd3505d355d3f76214dad2fbdfd3442733a6a68f318ed52032e4c8151dd746dee",
+        ),
+        niels(
+            "09e42e3258d0059953a3a3b76c42f6960879c7fa268dabe2dcb03657ec10042632e3d9e0f10c426ad0ac21eb2d006fbf1ffa3b269bef3018",
+            "f6005b92874889eb93d4482b7730ace6106ff14db2a5e89fdc64d4e23b3ea6967baaa2112f59d63f324272fa2dbbca4ca02dd0815d7ce9c4",
+            "b9ec8cb151ee4e590c1f0b0768d685b636b1d7eb5b697700b1bf3f763387342136058607a79cfed4d494cfd325bd94800fe91fc095e83124",
+        ),
+        niels(
+            "4766491b3a487acaa78e5f05b91b56b5525478f5359b987882d7d740715a6537a5d68ba7d16c4c5cb381c5e9764202a7ad87f7db8b0cd26e",
+            "9177ecc4ef9b49736cf6de434978aa4ab827611594c05ba8ea54bcd9e2c65aac051a32ec1476efc62ee967260ff0731966b8d790acb83dbd",
+            "5bf7032e6f1b91b0854c984649ec14762c98febb9bf483bc6da9f33e5851152fc1728f2ff0f3ba0b5fff49e002f5806ead149454c9788267",
+        ),
+        niels(
+            "b0be267c0eff3c372981dd1219fb57ec1c5b7105dccefc515f15443f8b2f8f2766639cf0a3eba07a383df8014302f953c5b79ec49e399312",
+            "022049e9f44c519dd83bf710e9b9416b14149134cdc83a5839d3d72df94048fba56a08d932a0638a024a806914f146031b09e72a0b822581",
+            "c98f4792b09f06ffe56c2e2eed3d8c3d126920b4b2033a70d2b314b9ebaca7693ad3a39288a5928f8221d7790e07c004c5de6e84788e54c6",
+        ),
+        niels(
+            "4cbeb39540e412798963cd9f6c15c95732cac1946377441331705753a19d21742ecdce57da5476de5dcd197621138ee06a488ea8ea6b1421",
+            "3c83cdfefb36c25cd3ca900ba2a933399bac5b65fee7f5309dfa4316a38d6be94ad02de5559f9cce0198d922bf770c6623aec199f78aa69b",
+            "3cfd3969699badfcb03abf75be240b80cbadc6830da53c48c44a708f964e7bef136dcd50fd508136d38b33e9adcf5481e64b18a85bdab97b",
+        ),
+        niels(
+            "6f9325370eade18e57220554c5b7e93eca1f415ea04d9adb0ac197ae391f336134a93356fdb8a4155ec20f8f7218d780ff5d0fb71a05fc35",
+            "5b46b3c17c074cdb6c26894b7dd9d6c5e68efbeda6cef2776d0292c43232103876f9297e1639d6bbc00f57c6328f536a383c2c20bdd4f74d",
+            "f61f371306e61a3d938f0f386426583e7dab076524831d144103cd6b2e59f0553fba797175374a36ffdb9a0d7cd61b7e9d629fe5761a29e0",
+        ),
+        niels(
+            "c1affe8e244246b4a6b6d6f5a22b0dff68a6964569fe477bd6b4be07dbd75732f3a035cb1f3216a0f923ba146006eaf82e34cab386986c77",
+            "e5275af58d6d4c56cfa2ec0c9a7f797495570baef0316573f2f86f3d391d9db0a432774ae284a08a429530a7b7c1e809b4b376c24a274d95",
+            "4024e383aaac33b8485cc1a749640b0cc8db2ed9e0902d7c5fc0efcac4f03bcd44d590ce632108915b7e35bcdc052f1baddbb8143287677f",
+        ),
+        niels(
+            "e8c5a80f8a17e16d48d570c82a7bae2f24185d51400da137c002a67ac3fdde03de1b2e258e42dda1b7767ef66bdc469bf25b659a0f20078e",
+            "3c0bfb119b0076ed3c824c73705bbac6d06e6fc695045d920436fc44c819fa6b52bd968944ab1c76f517b510dca9349d1338168f0c65551a",
+            "400f0e7f1e7ff28a5e27c7b08626253220e882d0ec7b817f51b17f3ed322cb8c9a1e316bcb1f58eaa56e0eb6363fc170f75fa8060e2c2301",
+        ),
+    ]),
+    LookupTable([
+        niels(
+            "9b075947020f9a5948b5b1e41fa64e3d8d3c27c9f5b6466d43fbd7c8d5b323f3e72498c421b439120ef30e9002dc700ff000b3f3b5aa75a3",
+            "e4c364ea846ac87f51a2f78db0e261039c1193391f0797e50c6a68acda0b1ba5cc3d5bc2fb3b587e2534e8c64ebed2b2e4d41ec0b95d690e",
+            "44558502979a6f46c5f0ae73c502b28aa70391b7e693a8cf61feebc98624c462f0ecacb61866d30720a63a45d3246018ff0661f3cc4ac0d4",
+        ),
+        niels(
+            "7c825e0f69b1ed0e5f896b93e6e24d4b6a57ac34b3ff13e59f1b7b6308fb22b430328f747bee7d5236e8e8377af50e598e302fc57d6c719a",
+            "9a7b2da518e41d53337a28a3609315d7bc1cc8985efbca7398c8ead87984431dfb798fde5a9013223f308b9b7a26c1700372a86fd85a1fcd",
+            "332245b2d722335ffc424f8c8331285e84479e340eca2bbfff061631826682f561e75fae3468e8366af4759c7e25dbc5af630e9ccb2a1341",
+        ),
+        niels(
+            "98e89e9f16e30ac9fd0b2794a653ba9e4ce19e60877ffffd737f60e962b3778b203e19c1a1ddbb5dcc41d6101ef75e937f7960f6e9bc3dc2",
+            "8b343578a3bd926d4f564b91a848f1d1b4224ea5eb03ab30ae834437e0c365263f14ddccbba1b2f41ae7a0d9db990dc5119138caf4e3a01e",
+            "858f3b05bfbe7784993cd64cb1bb55c2c8c243ac234f675c0caa1cc7511b903c011462ead8f311e451f1402feac59451b8f9e31ac548ed71",
+        ),
+        niels(
+            "d5879b2abdd8aacbdcabf0c92c1b21990692aa6377236acedcc291da2688ba6538d3008f95f26a19438cb33dd935329194e7262282ca3383",
+            "36019f339be52d9d35d5ac8870fb58457551a5eb8c34415eebcd3f614e0be8dd56f877d16bd8f95759a72db8e140acc479f68a42e1dd9669",
+            "a67cc1a9fe83bbc46163695e0ee1c407af5f0eed1c3bbe27ca778a0e2371890385d97b76708751c7bd9ad018ebe3769c6bac52da51213e24",
+        ),
+        niels(
+            "0a4d39cddaa26a945ea284568a660e78d766160b90699de75d2e3098b57b2717b8959d6c6bbb626bc67a0b910753611e479d62f5a076e706",
+            "580dd20d0c027151cc20ba4d46fc73fec4de9ef6b338eab9b6124c6886add9df2e0b84028aaf7bba10afb2044865503dbc9a5406c1dece04",
+            "1ac826a339dc2ee3139389152f17a188489df91bb0c7e61f473a5c7e593065bc64ef646e08c5b941e9e54dfd68dba54748986a9b59fc4844",
+        ),
+        niels(
+            "66c6a03430264e7851ff998f0be05b6b9077c03cbf19a83e0e4876f34eff73021f313dfa211af68d53120691858a50a714307373b226dffa",
+            "cf4708b4b9c30c1bda3e452c2aafc2995b7aebef08b20c261124e49d6a954b1873c3c2979d1c04358815fe7a72bb760e0f05ca945f5f096c",
+            "6adb8f9b053eef0c4034b077d91adaa7a516e65a41c8fd9b2b2ea47c9d9216b0252991fcad60b7a4a2a64acd9b639a96ecd8a17156ae8f8d",
+        ),
+        niels(
+            "56138080591199a9513a58ff6cd6c42350087e87337ee5d70ce564826824624ec74ecc41086ff751728f3e564be821067899f8eeac2290ff",
+            "1042a7636e64e196d0f7ed05d107000d0c474f2db9e2a01aadd815a86dfb3f69572534f7dc0ff576796c6be9ab9512bd11321a97ae41c105",
+            "e8bef8dceb8d025a793444c273b36a014b98868dfedd86472b042cc776d0863fdd465b84c4f9329db30eac778fd81bef31cf7dd4baddb150",
+        ),
+        niels(
+            "2c764133fcc421481fd80e0f128890d2023edbe416345cefda0dd2b0ba8914185477b58d03aacabecac1e6e852d9709b9503c5bc31ba4719",
+            "3d7268723b9cd7f204ae7f0d5525a46b0163c543df82cbccc3e09b0776f183c86fa120c25ff7d25e4e31ac2670c686f52448b3c2d659662e",
+            "6676ab595c5c2a6afb81e6b6f28e0f616a4f3a38819c7f895d264f12ae8263248d772b6b1ba35c7d29561f9ed460bc70042615a7f1404d28",
+        ),
+    ]),
+]);
+
+/// The odd multiples `[B, 3B, ..., 127B]` of the twisted basepoint `B`, for width-8 wNAF
+pub(crate) static TWISTED_BASEPOINT_NAF_TABLE: NafLookupTable8 = NafLookupTable8([
+    niels(
+        "82846f0a7821436a468360983c651204029321b828263a61c9ea92156822938a0a0c0c226b9fa4728ccd860f1d5a3850e4303cda6feea532",
+        "02846f0a7821436a468360983c651204029321b828263a61c9ea9216e822938a0a0c0c226b9fa4728ccd860f1d5a3850e4303cda6feea532",
+        "2affd38b2c86dbf557e6f84b2df8571f306c9689b4610bdb69a167f36d2ff8ed39073e1f6789da3cb38cb0eb141a0b0e8bef8e22b275198d",
+    ),
+    niels(
+        "eae7ce1cd52e9e5623cb459586b9a8d3d51e9ca839bfa5696cc69b34f103d2785945accc9810ba7c920ae19533e49522840c3b1019d474e8",
+        "d27f96d6b143d5540969f4d6e1198046c795bcc5e50cddfe557feea45a195ba05a876d74c283b3e67522821612d69f1862cea0fc8d2e88b5",
+        "1e87bb2fe2c6b244691725ed47579ee57566877bd38435d96374a7b39c20f43431a65aacbfe5efe105392cc3844c69c42f05a178751dd7d8",
+    ),
+    niels(
+        "cfb79b22990b3962946159321bea1c163f6922e3ed73ae0b2c9296e9e77ac09ef126d2f4498186cac0510949a5a0525d0d3ef83af164b2f2",
+        "9c21f166cf8dd1142472d344181937a827287cf2897d558cc7d656cb17da1208c4fe722e9f96782019152ffa45000470ac0cedc4debf7a04",
+        "c1443e6ebbc0c4718397b7a97895261f92ad15e620bffd919b5d749c79ac4f94487f9252e8114c2f67472d7e5eabcc9a3ab001431ca9e654",
+    ),
+    niels(
+        "c16abed6d64047b2116d5b0e71b8e442a1eaa0e84433e9a9ea3cea2fa325a49a8836301bb6c035245ae5ab2e733c919c943f5f9d7ec4777b",
+        "74a5173a225041ef15b76fb4092e57679a991839f34bc18c9bfc22cf9fbd5b3470844d04cb9ba11c93dc8977935b149fbeeacd90c1e0a049",
+        "88c7d2790864b8b8f637c8fa1c8b17476d89d42f6d4faa9518914e35cc12bc9e9b8cebe221865710f9101945adc5d65094c560b5ed051165",
+    ),
+    niels(
+        "d6044309555c08ae9889da4820eb66c12ad71486bd2833f795850c8bf717ae1e14d1262efa9d1d7e8972a3eff38d8912ad99c73a311beec2",
+        "487b2bb1162b97965c7b45640c3c1b430b85d51e1647c057893abded4e77de5e1e7fec2c8d5b450cb6f585d3855778fbeaef7eafc1c69be6",
+        "8814ffcb3cb9d8b313d4470bdc376b0aff965eb6643eebaa964c78b1e1d67b83cb6cec04390233f762a1669d8ea308ff754b1c5283d15e41",
+    ),
+    niels(
+        "5187889f678bd21c3362afe912c530957bd500fe401ceabd92a24661e6ca5cb1988f4633741c258de1605a37eeb637ee9570ff2d864de5bb",
+        "319568adf70486ed84715b4b02bbc5a7febfa30a99ddd86492c3c977299748e04c7c6dbdf4b89c0abd737678b5ed006f3d9724b8ce68db70",
+        "e9d4be8ed48624ac5ae16de8256ecdd8b3f8d74a52fc7ff6ba05c22295c78fcf63235f956c71f8d007839c3516ca4910a186835fc62bbdc7",
+    ),
+    niels(
+        "85c0aa2a404c8f9a39b694cc3f2f6d4e2a77732732ac8bc2541ff2d96a21fc6a8d39a1846b7fe84346b542d89af07d3a4ff6ee0f98974239",
+        "ce76076b12762381c4e4d3645be13e0f6a0dfef9af3a7b409d56eb66b8dbff4bf8143d2410172e1ac6db4e3f37b293d7b6c0ce11405df2d8",
+        "fb9b0cf1c695d2eac3132b9f3fe1aa4c85999dce67d61027f8e82ba9bc0b09f8ec83ea869da0a77dd268c1cae96424277bb261101a218548",
+    ),
+    niels(
+        "ad9056cceeb1f4b9b0a9405644725ed4084b23af9b17ded7e10249b3f28012f0606d2805986d43e78ce988e19aa868b27fa84d7c8a23b491",
+        "e864d6d26e708aac891b313761aeafacec96650cd09744eb4d31b3ee34cc9d1298e4fa3ee2842a80737846a009861758e043079295512f0d",
+        "3c61c1e1577b86e727be147ee1ecaaf5fc580f8c15d1ead3c7b9ef7e7686124fe11aefaf4931a0195574a1aa544b15359cdb91b357fe755e",
+    ),
+    niels(
+        "cb81cfd883b1b29050760257077871728c7ae73947f53232e7974d8796ce72df301ac58a9d55f5f2cc69fceac7745d265280ed9bc9b07338",
+        "534bc7c46c9033d76c92c045820add297a0d9b51c6fa01251b2838c8d217669808280217590409d27f1dcd11acbc853dc49d3fca983220cf",
+        "eae458097cb4e0f1d596b7d5ae25b0e323db4c13812bac1c8e68ca315152d31e5a87cff20a6535fa6d713a8578ec8960345011aadea373da",
+    ),
+    niels(
+        "f6c8a922d41fb450b2227472d8cdf0aec3310d9b7fc629ef25da2403b3b0820247314264abbcb8080bbb0139e01118944dc1513cd66abe8c",
+        "541914dd66a1b2341acaa0380cb32070422a73b129b26463993a42bb02bd4b317b752e86d37849b557d6f80f7f73401246920ac80f9b0d21",
+        "24603c40269118af3f8676c9feef1584396b6b11018992770766f09d9ef4f48d2d384ce9491ad262da79bb6bb2131704331075ccf26b7b1f",
+    ),
+    niels(
+        "8138819d7116de651d5e9c7766a415c87408470ff7f1f4ad010c60b066e80c3736349710ffac1d0c851cf714311269d45f9666e24f693947",
+        "3126a75354010279b7d49a6eb02604262ac72a6f6b754b1d3928aeae66efb8d5b02fe6fa54b46ef9673a1e7ac948d873a99dd7b31319527c",
+        "2fae73e5149b32a1a2bfd1cd84dcdd389ce4e9e129751c27b946bc2480d57135764e63e54e99c7a16198ef308edf21f46e3934b11c57253b",
+    ),
+    niels(
+        "9e1ed80cc2ed74d9cc83684eb145a65daa03b29a278e75ce3d27b1fceef4d79b19bb368aae8ef9a14f99a0c22d946ee18ff2ba36a41aa144",
+        "d1ede3fb5a42be5224afb2f62e65f401dc4b797dea43cf86f7bd21e77062100bc64e70449d075ec467ccc100b7cc553d06911657dffb4cdd",
+        "e8a188a60bff9f4cc091f49c9906f6f5942818db0af5d9c1d0b34322ca2df20014a787871c5963fb6365b93ed175a24128bed953d1997988",
+    ),
+    niels(
+        "4152f337efedd8917ee3f1655126cf663301237f988ec711c5b1a1a8bfad5af285e6c28b64ddf0e45d7e5396221039734b7c4b47ca7c73e7",
+        "036465129214347359a39d0794301368cd9780369f8a1f46c6e1570cbbf369b76c2aa2f767344b6e8d85e4087232e0bc2132c7762032fae8",
+        "2244ba0942c1994939a2d8eb80e0c7fd2d166fa99514f5f17c8657865d05b9ecd2473ef6e78795e200b10a6664f273701c07c7edc9305daa",
+    ),
+    niels(
+        "9556ea2e92aacd16da70187e954b3cdddcb2879088bcda47586d7f4935cdd58ea88209e8889b7d6dacd6169efdbd64fad3f09758e11e3985",
+        "067fe0ab76365b78608ab1554c8d1ded5ddba6eee21db4cce71e2770c339196acd622fbf2c94814f04372e57d776caf68e321e767f0262cf",
+        "f759134cabf69c23938e98d44c4c25c08d892874a52b8ae9b805ecf34fe7ef94c589f4ea8ed1e46dfdc0b389972cdf753f8cab16bd1ff897",
+    ),
+    niels(
+        "2fba4d3c74bd0e44dba9e3bbb74a12535eaa395434773b74068bd16708198572aeb67345a9c3ec2cab141e9e3f1c3f182d1ec59db3d9590e",
+        "410b8fd63a579f02acaad76b7af030f5775a8aeef41062ac5c6a7a18da0a6f293c64b8ed0225af4247617a194aacd1868f6c2a84678e4b3b",
+        "9f54ab7bd4df857eb78720c9d2b8abcb36974b30adc74cf1d912fe66f601586b42bf1cd75946004aa14c3318b8fbb49e8c42bf08fe66922c",
+    ),
+    niels(
+        "60f4d02810e270cc344294cc93a883652eaf3011052f13d6b57c34a4cc4497d85cff6bb51b57c338a3fd08dd0469500c1642e6268b861751",
+        "d77dd69e5649675beb8ed2e90204ec661be0667cf4837daed353d2fec36ff3fe7925b8132cb65aafbeb2fa8b3746ed8ee9db9fc948f73826",
+        "c0f92d07cf6a57c530f3264169f966996d8ae1f2c343fe24f82332851b1a5b2a96e50065f46a4adaae00ad171124bccb7ba8954363cd518b",
+    ),
+    niels(
+        "251aa504875d6e6de6e340448b7b00998840ee5d710d3551b10775234392d4c3b9551461b4f5a5313cdebf459766fb5f52d73bfae5e00844",
+        "1a4b58e3031914f2e59e736e9caa1a72adb235c06d2f5e781ae33906d883a4b92db00a2e0e1e8ff7318f8c8d1def993db336a55c6815d943",
+        "f1523fdd25d5e546dd8e7c1635fbbe0df9a1f198c2ea3ea8bb71d63261a34ade5703bc03b893175a8314adc0a78642b8c53bf343427ac342",
+    ),
+    niels(
+        "1d5eaea3fbaacf5f7ac561ba8669a125d6cfe6bde31491b2a6132147338bb78db5aae940f637a93015e27044ee275c634029cec55273f70d",
+        "cd15f863c293ab1eab0144d17916b064290efdc684f2406a550650bba57cd7f0df189160c6e970f2d621e713ff80e04a43633f63fc9dd406",
+        "273e24c36a4a619099e8107d4c854ff519b061991bcdc7852eaebb096fac50bd81fd98d05b1c9e3398410e43f31e2872be054e9635e3be31",
+    ),
+    niels(
+        "8bd0f7b823e7aa86231ceba914009be6f03958c7576a36f9ef30b331e48940f5d032fe7ab1030d8e37f45e983a35dda1c7cae8aaff388663",
+        "cc1109dd210da38682ce57e5c919d781a78ff6cdc31916b1f2fa7a748a67e0b3452e98bf0b8c3ee64adfefa5b02801a109070b4441ef2c46",
+        "e3b3b401e41aad894ee8c23896fdd9e927ef177d7071d6031c3c15aede2b2e4c775b8490da9209cf26a04ebea78e7c6f2ccf881ebef5a45a",
+    ),
+    niels(
+        "6dbc8d6dc786aa7a105d44d069fb70ea5312323cbc435776fbda3c7d525034d73faeb2f6c4ac8965721855cc2048c698f05d9a8353fdb2db",
+        "f16e1ccd9ede5ee17b70138f601beffe3af313cb601f26e64c761dc226339a6f34df78bfb4713ec97966819269c5dee0f8204fef26864170",
+        "7652d7ddce26ddfd367114db7f8693a66c0c93b24ae61778523fcce2ddf36ad6874264e6483970dff548fef7b81092258717cff19cd394ec",
+    ),
+    niels(
+        "0ab0b516d978f78375b3423d9af8cdb1595aa2d7d49c46b9d0d38cec823b59b024712ef1d536a841513d5713b275f2ff68a67ee7cf9f99eb",
+        "28ce087b4d6d96e545f08362ab7a421113ab45c0d93a0b0507372953a628dbb6a80c4746639693354f7baea9c7771e86e7d92ced7ba12843",
+        "a4c989a3d4b9184ffc04e1095a0643a487c3b919393cd00282848605c47e568c4fe3c73736bf1603687a585607ea24bb4e428dcb3c510b0f",
+    ),
+    niels(
+        "55544f178ab975f2ab67f538b3a5f0dcb8dac1c6e7cfb68f399e9d47cb469b9a07f53464b994d7e3e11753ca52859436a6cf4f6069a658e6",
+        "19445b59c6922272f322240e7215bb63b662033f69b4708f9a771cabd5107bd1a12d2716653b609da3ff4c02b059105a68a8778d0e429f7a",
+        "785f8651362f164ee42f133545b3cb1a72f54069e5172fca07ad6717089276d5c50c0c3adac8ce48dae3e2f1897b7c001899b7a2685d538c",
+    ),
+    niels(
+        "c483f734a90691bac4624a3520f9354f437f1a32a3c600141ec6b35f6c20f3b4ef54ba055f5e3e0444462cabeb18623c012c7b7742d1dfd9",
+        "526f373a5c881400c840da7f5c5db207318ac1f5d831b6d944e4f9b0496437344045a5794fb0b5840f1c15234505d42cdf49cbac38509e11",
+        "7885dfc2b5afeaf8bd6a35ece13d5d1697eca16ead69ce3b418e84c6f38e3d9158a1b8c360336dadb04cf7fca1d875862553a737d422918d",
+    ),
+    niels(
+        "357cac101e9f42153238210f3423cd7e5b0a3879c5fabd3c8050865a098b629a6d1c8da879272412fcb95294f28df516f95b67690ffd41d9",
+        "c888d1b52f2b05e8af1139e6422c1439102d7aa9b32246b62d919782ca6505a5240244a1a7802246706ab20dc3e96922d7c3617ae260776c",
+        "17f515ac3499e8bd75b48d74e32b7563362039cb361c0360559bd37409a4228601d254a6200a6815152df59247c97e441b8917b454444fb7",
+    ),
+    niels(
+        "04d7eec791dd33c2bb2737846e5977f19d1ab75182a922bcba02684582efe4ceb435946477b96073f146a55aa0dce7cc6cd54e802082d42c",
+        "44994a70b3976daaf67e290479020b2713ed8f7873f1923bf27d3f4c8a7b70f3c841a3ddbacc7b678a7beb1edce358d6bf1532a7ffe41c5a",
+        "1dabc08ba4e236a5d604b4dd6a4145a056ce063fdffb083c100fd97928717cd2871123212605350dc57eb0a9097208e4f844588d1a81d680",
+    ),
+    niels(
+        "e82457e1ebbc88fa733eea28edeb62c97b3af8000531cc81925e3070563484e03710d1fdb9a458a471b91611e91de2e1358ede889ada7f06",
+        "41cf2215442ce30d2c561431242339373abd2f3c67967c39151965621d323d374c096645911e15a15d01244f98fb45784ac4887198d7a7fa",
+        "a871c993308d761e03a28f1b4e3d48fb0add6dfe59e73a46086fd058d1df8855b8c3574a7cedb6f93250a0d4ff46040d926a0df5fe9b6f59",
+    ),
+    niels(
+        "d9fab5c597220454f00079796de72c9784ca34917deafcd1620969cb4e6ac67601626318f09db809e750198d60af0ab6fb6cded841e2a862",
+        "31d1a790938dd7aec67a110f1c4309abf569f5fd8ad61231b05f6f5b63408ad0e89014d1a34f9e91c77c586c0ad138555e30dbb2d1766ec8",
+        "8c40be7c45ba3a7696c1817165e091e27065c27b12a018e977f78494fb3631b645c3f059287d7ce74800b571e62b0f83bd4bd0fee2438a83",
+    ),
+    niels(
+        "369add117865eb65025b0175e9893f8d056e67e7f7826d360902a221b413fe4fb2b686306172208b1e2b2df0c7609e01c5d61731caacf2cf",
+        "d05eb5c09105cc4c6b24b4e337035bd6d954c6fe9ae926d5d48c2a9df8f801674eddc88c1c81ffb112d11c7d0f672680ffa0f326327cb684",
+        "7acd8eec2e88d3a9d610f9f3e615ebe43e183997bbaf81058d0b90e53f8ec4864cec145b1c794dad050b0f892bc313eb52aaf895aec2fa11",
+    ),
+    niels(
+        "15ce6b29b159f47bb6865b303f3e16243ea02ab2e56c1c0170c512bc7e8a14c1aff71bff0d351f23ab939412768194b6a712b6fef7aeb1ca",
+        "c47ea42fa23dc30997b93cab887b6a45daad79e3f3b158ba38d1e588047d0e2ad4bc510177fa578c8d6c846db32cd6043149b2fab13812a3",
+        "75a31af2c354096283783092ca3563f4dd9de8d5a769be6a7aeef2684bccb4ea36edcd5d51f69b5e1a1de2035d3a1d08369961cd02e68108",
+    ),
+    niels(
+        "19d536a8db56c85e65a73a437a060d60bcf9d4dfccf99a3d9a71506426ad7ca0795a0aebddbf4ca48b2cc8acb93950132d777463e308cacf",
+        "ed8d7df87571bbd2eb96b127a687e98e0a8d477a4f99c0314e8b326feead6b3a8049783e6543babf39e2012283fb34388ac412365162e8cf",
+        "08011c530f39a9f7e26c96342e5faa17e48f43862b20c47a2bef5bce46b4b3be74fc5c239f1a412dc1e727cd6a8323ffa7192d7dd558d135",
+    ),
+    niels(
+        "0257d2a1fda9d944ea685892de0de9b0a660b28f8551061886717ffa7793df84341a6e1f3936d19515a699e11f60357e6cc4545be4a54004",
+        "89c85ceb843a34a80ef223af1012d7bba1c74b580584e15df770e6fc31252584a3306a48551369a5751aa67adc90b27740aad4ac569bf0f1",
+        "baacc748013ad6b7dc3e64acc85f998e5afdd346f9231777065840c539b7fab82991a1f1607d3e36a5706cf3b0b5ca13377e8b01b24ac8a8",
+    ),
+    niels(
+        "3b6bc98682907c12431b2ae28de11739caf71695b7e4d94d8b582dc94a357a64414062c3f638b307b14ec6afa83ddde2e8a53edc023ba69b",
+        "80eca677294ab712b42ab8a6ce917b6e71951caac8a4018507fb6073452327f477e0475fe409ac74d6145fa1953e3f03788ea6a4177580bf",
+        "501e953a919b871979c0df2373169f596e6c1a9e3cdf4ab3d9c44f401c4b778249419ab0cfb134ba84c879fa627cc699c88a9a93be1f99d6",
+    ),
+    niels(
+        "9517368ca0d233b2272ccc731daa2c07abe51c36cb33dadc9890dadbf73a4c063a488394319060ac107d3b5af7bdfac6ba654c303402a961",
+        "975d950b1f2c9fc110eb9f40e65665ebb4c2b505f4d6b0a86cf18f1d7ef8125018d243f284b604b3b4363ec446403679fce413c68a4586a7",
+        "e6c3e0f2a040aa839b2ffa3f4538bb51a741e57275bb996d47b4c1faf6d624e7fe6427bd04d8ecf7c4aaa0151496ac455caafc64264023c5",
+    ),
+    niels(
+        "e7151a232827a6bc87c04beffd434c2c5897f4ca57c63661bc49cdb213c1770bd8aa0dd2ec57ba08476225e14bc23faf002b782e91680258",
+        "fa79db26c1f27db987d512f48ac77e1a45ec1f6b421ab94fffe77a639641b8b0f11daa9ef224bb69e58317e65d4bd11ccfff75c7d049cebd",
+        "b4eb3b309a2dd9a2eeb07cc0aee86a51713b6066e860d03a05425fc2f0b5e8b6a55f0ff2e8ad568add8ab8f2b3a783eb8a039d8c4841ca2a",
+    ),
+    niels(
+        "90e5485a703e9ed34a72cd61dd97263c308b87d72730f78155052d86b17f3a323da58cba83c1adeebfbfb1021b66f98b8fafb55844c3d2c6",
+        "0ee94dbe6a9d0ccd0195a56a0200e044575faa9c2c439bb6b23feca0df9f211c01a86721c4294fc0c017511d68c15fd4a9b226f5656b4f7b",
+        "464c0d3ca529decd6db2e56a2b5018a04bf4bdb92dce2be5cc46921997370c38067764ebcb584f53bfb4269af0d7a6f93907c4d9669edc69",
+    ),
+    niels(
+        "3fef63a26f7cd1f3faa9d56985be98681dcf12192d89342a483bb1c606a5c4e5cd4cf43d157ebfe136411df652e5eef576d32fdff5184da0",
+        "c808421a2e40177d16f89d900fa4012f6814165978ab21f56e6ede5360fc08929492835c28338e5d81284113d6927443857c8e212000ba8a",
+        "d7f5c52580304fdd2e0ba752f476d05a8d1f80320f32ea6bedd88c35a52844786a78a2d2cb5fa43311ccab520fa3c80453147150439d4884",
+    ),
+    niels(
+        "2f1c5a6a4efcc42e83620dc43155fe868d9102eff26eaa7bd38dfa167efc3ed6c9e0f9cbdcc0d10a9d8432f6475a462003a5d64f3361d64f",
+        "216c4722e7dedac7d5538161e1fb946eebf1d5ae4135d2af379e319f2d422458057222e7dcdda0b21a7cfe2ac8e5989b8fb2f04df59255a1",
+        "27681df7a2e221877506e59bf26c365452450306d7123247320cab9738e166ada0c890adeb1f700db5bdb4002ba77bb154b88e94d17813b5",
+    ),
+    niels(
+        "090af6548b2e66ad23c46ad5d792de2a7f451b81af73bd5491bf0b8cafb34794b5d6a5bf1383cfe6d8e88d3503649571780a0c82ddc1fbb6",
+        "7153a19202ef5dbd7ff96701fc36a9c5ececff29e48a1324edb49892a517fb77431e5ea46fd2ecda2929ead872f2c82cb562b9acbc033fa8",
+        "ba7f8e0c3cb628cc04cac5e18f98513bdedb2037c2252afd593613a77f36e2de81eacebda8e74e4db53ac983ad67faad8307cf5670ceabe0",
+    ),
+    niels(
+        "727a8f4a084993e4f6499b2dbd01034e6f9d1b547f108e04987ba7126d78eb0f53030f2da968ac01658b2530be422af47f43e68a3a85180e",
+        "6dc8868b09a2ccf9f9d9dd71cafaaf2ea6e8c83c7132a48f6f63bb6696aa8f0df64475ba517259fed111e7c8c16ba4cc5a47bcecdb9b6d68",
+        "45f060182bd44f3e3d4cbd2f2e06a5e91a877e5aecddeaf5df1210135ec2fac9b5d47cb1a9e3bee6a947cd456ba935857847e6ea5faa14e0",
+    ),
+    niels(
+        "0c5999ac9b93a1982f4243db663096daf8a477703c2c0fc41fb25bf603d1c2be4dc31cdf69828b6d49a43effee1eb536365149c050637392",
+        "61d34af8de987b17850eaccf2a57f4deecd963d5aa2e85a29382ec81e19120474b0109f7c61fe70e2ecaf59beb7144bbf894e56962712778",
+        "9dd7b6e051878590159c97a96d335c16878c369c1716ec7470e591e4cbd08540ad01d4dfd625fa0b9e8e4728fd8fb2291d5ed781e94a0565",
+    ),
+    niels(
+        "b160f08a7ef7055eeb2603e3c440344cc7c5eb91fb82e7c9f1fc33c5174728fcd125b5399331b50cba2c73e0be96b8b40cde62235c6ab9f6",
+        "19e46855d2c1ebee5d6b512761f6c80c29ace3f7e128404350ad721c5202629723b6e207a06ca71c63cd9c0409a1f15e0e44931b5c5b5041",
+        "1f5c4d40dbdb3e2f1638fd7f2105743433abf100bc256f3c4022a6b3c4d267d9d916386b84e0bf4bb2d1356f6b3ea5943f85b642a0f1f14b",
+    ),
+    niels(
+        "b3a6d3f94dbf25b47efeb2e0d9a89199e62ee390d11e24ce7b695f8210b3507d4316f13957b0b77fc57df29485d541f43b2b108946e14b76",
+        "494a5e5a5be83307cafd0cd4b6388fedb18ac01220b1bbb041403f139b0a9b5f807a8fdd0fc7e158eff6aa8c5941b985adbe95b07f00ecc6",
+        "7bb05c8e5060c73f38bea563f50098f0a93b12ec8d70f657917dc4a3f822553b7c8ea39ddd7ca73fa96c95cbff716069d32c4e6b1705e778",
+    ),
+    niels(
+        "1a83b4ddade56e00db365bff3e8594a6cd2fba678996dbcd44c19e51f5e3ec8448e6d06e4742bb2aaa16a4f1b80f0e69314d7bcc0035b9a3",
+        "a1cd18f059cfe638ab8b2e3d033f5a4a8f54791ca2c308bbdb5cd1ca4d12e59dcc1d8103cad477b18f438d3086ac6e2add155ec7bd28cbee",
+        "5b8ea15d5e0dcd0c02152ee95e9f5e68695bd113e37cc5c3762505f824c62dd8eb793348f75168434e5542c4e8a1081011f28b9dee3f3a02",
+    ),
+    niels(
+        "e75eb6dba9963825a4509ac533511c25c191c5a9532e2c0117fb0ccf1c3d9af863c48ad763e37c894435680239585cd7ecd59359eaf0f1cd",
+        "a6968394d665e77844165f6add238905181870f841424255474e8d3e40d326f1e1c3b9beb00d5298b6ee71cc5952b802243a2f88163d8944",
+        "aeb7e809725fd81cdc6922e4a7e7aec4f969e4f79468edfd21bbe8c49c7344d5d78e50b243fc7fd9b7e97db85463fbd884e6d46ad132ee44",
+    ),
+    niels(
+        "ad624347adcdec3b98ecbb8b92f0791c7c4f89e3474ba43f0329dad86f9ee64a5e4120108b10daf36b565d526c0a7050127aed4375b3abd4",
+        "70dae25154091660fd728bd1a610d052bb202ae958a24252bb23185ae1e7a835f4cf98367c3a34265eb1c8000b916e809a3a71e384b38cff",
+        "84a16d0a5de921d84aaf7ba3760a6d4eadb0ef0a3dc187f28c6e4d5c5d335f77e40727fd8757e56ee70917444e96fe2aa15bd1d62f6526e6",
+    ),
+    niels(
+        "396d2b07502fc71c156fc5f93eedf7b85ae51e2ffd22ac2bf74bb595327e568b7ddbba0fce2f5f06fbd1ff3b1aa2cd869878828fae951616",
+        "b1f6abda4324bbc7368bc336370f28ad3974abed503262e4526d5c44890814bfc9ae0889c4da07b68282ff1a33aa4af26219f05b71d75738",
+        "58edcc7c4e56415f8b6b8c14a32a933e3694f51cf2e339bf1ffd88369a606643fe0ab70f37c4aa44e4bf96624c7965f58744c203e3b01d6f",
+    ),
+    niels(
+        "1d8432c369de288212a5d5a773bdd72ead32bcc50dbe3efee0a7c9aef037373c97892de8d9068a6258bd63f94afce07abb58ce78e4a12941",
+        "c88cc090f21b99f5667c43a72ed6dbb7d61092adf1f697684856be435cf00d85205cb296830b70acfa593dd5972f9c0da196e0fed8cab38c",
+        "fc8192af89e90c3229e8e0385ef0d1ec3f8469102c3635a73e0b9c972286db9c68534896df0a20a3bd06fea66c19e57e0d438df92cbe8a90",
+    ),
+    niels(
+        "0094095446b1e448ac60973c8908c443854830ec81707507e7de7a2598c9b0ff897f2a4e567bccda707df92709fa31be4aa5b6ebff72b4f7",
+        "424f55830132f94a9dfe45d06bef968dd442baca4b1da686e692582775a3b1017f3105d25708826b621a0ad4d4bdcfd483abd1aee4589249",
+        "d7bed1ed7cdc0482f83e606c5e34a9b215c139f0ff9b5e6809d499dc7eb769ad2e3a7fca54acf4711e3733d1f6fb888fc5b7f4692e1dc87f",
+    ),
+    niels(
+        "fc6d7b8d35d91f4d12d37d06dacc3f1faf5f18e3f4f6c779c88cc2caed02cf9bf5995db6864c89804337df3d862131f966190acce9243e96",
+        "75263831021c3bfce080af5e499686bfbef16f6a3f871bf39a9d737f80a160e9e87009128f0cde47a91598c893819c96f394fb1bd7f8715b",
+        "e31e3e8080350f7cd5d30af0329cc75356a05fa563a4b7a3a82dc211dfc67179dcf16bf13bc397532e5ce661d4be0ea953337c5ffc7b33fc",
+    ),
+    niels(
+        "27f47fd40df0eded40f78be13d5ac6658b19f468121a59baf781a0b0684fb2fc8d3c3445c1642dcdcfd2ac9854600b69ff772d9747bba1f7",
+        "abec58c011c451d20715d4200af8ffa6afd8e09d63b836eb90925c55038de48b3886bda27058f2385c432c94dd347577ec2430f4b394a5fd",
+        "56c3f938804b92459d02e2fd345f40a1470d2293c88ffccb6c9ee0badbca5aef876a2eab35f1bd4955a4ff4967b72ee2df2bd1f6ad105160",
+    ),
+    niels(
+        "0e378160eb7b9348a01b02fe595bc1cabd008f67719cd642d96d8d2683aabd983e7159dc67f2423e4f061ddd88bfbae06e6c395f64f042d0",
+        "1c0df00ca72048754705936d55275a794d38bc776c95c019cacfded2c01733f969c2af6f1da7063148ce66561b76a530cb98c7d75eef9bfa",
+        "ceeddd2b8c34216a88f3059b3538a75694183bc5166740e60401d0cdbe7f902ca1a77f8815cb47705cd81dc6fec10c16c4f27612f6ece95b",
+    ),
+    niels(
+        "f0edf124d825f10876bc4f04e71f8c1a8f048f27bf1145af44a06d5352d64e188b4fd738861784e9567604af90d417c6ecfd510d0e6958f1",
+        "d459fba5961be7b5b23b46ea30c4766afd18439471d0e6ff84f5b6c283d3f2426cb82d1762dde9468fbcdccd08d752fabdefba96c47d81ab",
+        "9c52a1d52676c295fe70fcfec80f3f521b7438f2b911623f3f3d4d2b7c1d680182d08c377775b1a8c82305aa01c7fa84ecd4d776d2a07c2a",
+    ),
+    niels(
+        "87686efaeb83956ddc423463219c87d2cee9407ff0a2c55d54773e94880403a7137802b7687d0c892215ae4dc3397a8416c1bce233c99768",
+        "0cc4ec55ba92d495551189a3d6cbcc2f775ed2db90c0b6615ec75ce0d47998a10f055570c41471d7d1a96c7d22c5ab82901dae91b3e451de",
+        "b471e929c19a67504196f0e7325b0875f5b93b95956eadeef123380e979f023a40700a565233779b3fb1b5b29503b76f6ca69b9c77db0b99",
+    ),
+    niels(
+        "8c784ea92e41b4406cdc308fa53202571b28f04734a3f47f383b524f4bffae3995c317b0b552bb6351bd4c7c474620bed6d575aed321e12f",
+        "b0e22bc9146bdc8e830c5488d41fc2037a319f04839e6640522c2987f411004914feac5600d8b37df1cae4809d8dc21e542040a6cecb4737",
+        "c3f1830625615da1b2e8154cd00c825d48c7b35a8dc9915af0ed6a6c1999854fb55bdeacd2c5a786393fe3538cef84827265f84aa06799d6",
+    ),
+    niels(
+        "58b2e224bb751456820c73e6a90998b40e4feb1c7e270420f2275e7cf2ec7f48419a5a3cd85daa1b65923dbc2edec0d68aae8a76c3edd8ab",
+        "4c5e545ec88c8aeffab448dd343e8518950bed98844626a6e76ec54aadad2d5503a0f3a3d69bb57e49d7b34e6035440974337475459c17bb",
+        "4d0bde075185a48e054a5a6e875f703fce348b9134991094a7bdde4366a274a3daa441b9d3b1c42e31aed5fe4832490bca60c9d65b245e41",
+    ),
+    niels(
+        "e6d9dc84290c000fc8dcd186629a3d27ceed19d102269f749557cb27b8b5e5ee9f3549ae7bc4ee8663466910622644fdc263ccbf8dd5e635",
+        "6ebb92ee1e3172454fc5d9bd2ff35c487c23eaba59b2e3c8dd4aa356a2da26cc1d3a96b50094162c2b28f301372d8240e324582656910b7c",
+        "9f68a508ae7759dc259bffdd05317895819f2972785dd6cba70dc22cd1cd6fd668aa7a91dda454e2e1ac72835459a05f2ca0d5c185126b85",
+    ),
+    niels(
+        "a89abfbabb048218e1cdfef0fa830f377b0d93dad844de217586c689aaa356d93e9468cf075bdfda22c1da7d56ebc7105a0bac4bed91c54a",
+        "e144683d1857303a047b55d11df88e073e2b38c775b7ecab414432c0226ec4f36ce2df50750c268044537f2a9fc3f1d1866c0276b0565e6e",
+        "0ff56b057102d278e53b5f7b12c0a0449743efe5364af6d920477f04b30f6307f56337910ac70bed60ece3ba22b83dc44a2af05096b91b83",
+    ),
+    niels(
+        "f21a56ffdad921ca270007fcb4149670e9325914cd4a9ee136eb51b4f5284b8dbcedfc293fb05750383cf23b5da23a0bd9c2c9c3787867af",
+        "4c224a94cba8c630db214b1e2975a3b8ed24d8d900a2530b4334a121f65084a523906c6115ce266e39dc27d34cfdabf437f888a49d1d5974",
+        "3b59a54b91d6457efe6fbaaf27f4abedf93d1633a4ac50dc4cfba6cf43ad1e213034e63943432230b74ae1d642e5254e9ce44023e593730d",
+    ),
+    niels(
+        "bb3224fccf839d792e7484bc471b3d5eafa4cfed14fcc580724604aff476b20550b079ef2383739b1817bd4beb50c77557663971208c2a52",
+        "dd30eb788049b037f4c8029cf158f0c74316c45056a4d1c29648cc339115e88b6690ae8316df8469d7d9fe4a291a103857703d0b90b0e031",
+        "282f59460bcf32e77fa5ab52dfd8e047cd0c59c228cbeae8fa834cb75c7299d91d597c17ebf41723b02d241753f96ebe7a6a6a6b11582bf2",
+    ),
+    niels(
+        "bb1495e6aa214f98baf24c192ada445dc761bc03aea3a7bf92ea2b361fdb14701fed04198559e76668c80febc6d176004090f846b8e44a2d",
+        "d6769d1dd689d5f4e47e0d34b53b6cf63c0e9dfe3b6ba16a46a69cdac6dea4106f5c204527c8df9408cae7af7054c33e19411a58479b2254",
+        "676183c255cdfc2a6b2de6fa63e9dea168de46e0bb1bbac91dd03347b7a673d170dc1b0676f8d1c32365f7c82f3be9e6fe5193aa5acb6919",
+    ),
+    niels(
+        "19f71c959bae3b37d82af144c94b980183a398c8f11ee1d5e7fc39aaa040d006e7647d9e91af12b78d9cc95ed23cbaafb331561c7b2b532c",
+        "2cf92e1bbfe25ab9a6a309a4f8fd044742b79b50430683ea8aa10f15ced568e79967d31deead68e68d000a48a1fc231c21a6f25ea7ad5842",
+        "90bb2bb962edde24047a721a43f2e96ae6faa7bf330859ef00aef8d864f50212df845ce86f5d5da91f036657e60ee029325e552f9702cf02",
+    ),
+    niels(
+        "d2c3ed25504aaf154dc396bf07c7e6d70421530139c74cd0e88d70b201412a4835017a14eb550b33d370e58a0381eb7cdd01bf645f644528",
+        "966ad51fc22ace377d1596d673c03055dfdaaa5f20fe1e02d4da9908ac9cf6431810d3879e5bfbf8e41fa07c587a81a6b43ef7032f196cd0",
+        "eaebd558266ffa89e498895713517035b84bd58d019b066cd146111da3d933fe6551080ded79b6f4940513b6a923c22d73d7dc22c67da06b",
+    ),
+    niels(
+        "c1839506920e6d0cbf223b4393c2678b1cc022c8a610a223524900ace2177398f92e7bf436948e7a2341d199a32332c2a5ae03c31ffbb51d",
+        "8bea72cec73b6b8fbfb7e056653163d9f2d19eaad1c3f319ddbf937b3fbb5be10eece69b3f617c4a864d07b5fd5afb801ba316611a09c0e6",
+        "f81ae7781544f9e40e5d691ed21a434c3e9bfef6126f5a68ff0fba43bf349f5a40060eef256f2bfc9f72bd29f6573b34645e093892995237",
+    ),
+    niels(
+        "2b746fe5c3cd89187a0fdd3efec3776cc1a5130e6793cd8cf3baaf340c431599191b437b021a2c130a8677df24aafe2789f223bbd96cc532",
+        "46587de8ef8a38c8140897dd226163e166afae7dbd4e2cc48f28406a210b16fcc7ad0bbfbd2f99c5fa15fa424a4361633de4007117649995",
+        "52a7daad00605df1d1f0cdef233f711dc9d3ce1639be6c29afa2e6e9886508882d1aa2d0254b1a2f41ac691f9151b0568abec3f5173410bb",
+    ),
+]);
@@ -0,0 +1,144 @@
+#![allow(non_snake_case)]
+
+use crate::curve::twedwards::{
+    extended::ExtendedPoint, extensible::ExtensiblePoint, projective::ProjectiveNielsPoint,
+};
+use crate::field::Scalar;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// Computes `sum(scalars[i] * points[i])` in variable time using Pippenger's bucket method.
+///
+/// Each scalar is recoded in signed radix `2^w`. For every digit position the points
+/// are sorted into `2^(w-1)` buckets by their digit, and the buckets are then summed
+/// with a running sum, so the cost per point is about one addition per digit.
+pub(crate) fn vartime_multiscalar_mul(
+    scalars: &[Scalar],
+    points: &[ExtendedPoint],
+) -> ExtendedPoint {
+    vartime_multiscalar_mul_with_window(scalars, points, window_width(scalars.len()))
+}
+
+/// The window width for `n` terms, balancing the additions per point against
+/// the cost of summing the buckets
+fn window_width(n: usize) -> usize {
+    match n {
+        n if n < 800 => 7,
+        n if n < 2000 => 8,
+        n if n < 5000 => 9,
+        _ => 10,
+    }
+}
+
+/// Pippenger's method with a window width of `w`, for `4 <= w <= 10`
+fn vartime_multiscalar_mul_with_window(
+    scalars: &[Scalar],
+    points: &[ExtendedPoint],
+    w: usize,
+) -> ExtendedPoint {
+    debug_assert_eq!(scalars.len(), points.len());
+
+    let digits_count = 448usize.div_ceil(w);
+    let buckets_count = 1 << (w - 1);
+
+    let digits: Vec<[i16; 112]> = scalars.iter().map(|s| s.to_radix_2w(w)).collect();
+    let points: Vec<ProjectiveNielsPoint> = points
+        .iter()
+        .map(|point| point.to_extensible().to_projective_niels())
+        .collect();
+
+    let mut buckets: Vec<ExtensiblePoint> = (0..buckets_count)
+        .map(|_| ExtensiblePoint::IDENTITY)
+        .collect();
+
+    let mut result = ExtensiblePoint::IDENTITY;
+    for i in (0..digits_count).rev() {
+        for _ in 0..w {
+            result = result.double();
+        }
+
+        for bucket in buckets.iter_mut() {
+            *bucket = ExtensiblePoint::IDENTITY;
+        }
+
+        // Bucket `j` collects the points whose digit is `j + 1`
+        for (digits, point) in digits.iter().zip(points.iter()) {
+            let digit = digits[i];
+            if digit > 0 {
+                let b = (digit - 1) as usize;
+                buckets[b] = buckets[b].add_projective_niels(point);
+            } else if digit < 0 {
+                let b = (-digit - 1) as usize;
+                buckets[b] = buckets[b].sub_projective_niels(point);
+            }
+        }
+
+        // Sum `(j + 1) * buckets[j]`, adding the highest bucket the most times
+        let mut running_sum = ExtensiblePoint::IDENTITY;
+        let mut column_sum = ExtensiblePoint::IDENTITY;
+        for bucket in buckets.iter().rev() {
+            running_sum = running_sum.add_extensible(bucket);
+            column_sum = column_sum.add_extensible(&running_sum);
+        }
+
+        result = result.add_extensible(&column_sum);
+    }
+
+    result.to_extended()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::curve::scalar_mul::straus;
+    use rand_core::SeedableRng;
+
+    fn random_terms(n: usize, seed: u8) -> (Vec<Scalar>, Vec<ExtendedPoint>) {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([seed; 32]);
+
+        let mut scalars = vec![Scalar::ZERO, -Scalar::ONE];
+        let mut points = vec![ExtendedPoint::GENERATOR, ExtendedPoint::GENERATOR];
+        let mut point = ExtendedPoint::GENERATOR;
+        for _ in 2..n {
+            point = point.double().add(&ExtendedPoint::GENERATOR);
+            scalars.push(Scalar::random(&mut rng));
+            points.push(point);
+        }
+        (scalars, points)
+    }
+
+    #[test]
+    fn test_vartime_multiscalar_mul() {
+        assert_eq!(vartime_multiscalar_mul(&[], &[]), ExtendedPoint::IDENTITY);
+
+        let (scalars, points) = random_terms(62, 10);
+        assert_eq!(
+            vartime_multiscalar_mul(&scalars, &points),
+            straus::vartime_multiscalar_mul(&scalars, &points)
+        );
+    }
+
+    #[test]
+    fn test_window_widths() {
+        assert_eq!(window_width(500), 7);
+        assert_eq!(window_width(799), 7);
+        assert_eq!(window_width(800), 8);
+        assert_eq!(window_width(1999), 8);
+        assert_eq!(window_width(2000), 9);
+        assert_eq!(window_width(4999), 9);
+        assert_eq!(window_width(5000), 10);
+
+        // Every width the thresholds can pick, and the smallest one supported
+        let (scalars, points) = random_terms(20, 11);
+        let expected = straus::vartime_multiscalar_mul(&scalars, &points);
+        for w in 4..=10 {
+            assert_eq!(
+                vartime_multiscalar_mul_with_window(&scalars, &points, w),
+                expected,
+                "{}",
+                w
+            );
+        }
+    }
+}
@@ -0,0 +1,134 @@
+#![allow(non_snake_case)]
+
+use super::window::wnaf::{LookupTable, NafLookupTable5};
+use crate::curve::twedwards::{
+    extended::ExtendedPoint, extensible::ExtensiblePoint, projective::ProjectiveNielsPoint,
+};
+use crate::field::Scalar;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// Computes `sum(scalars[i] * points[i])` in constant time using Straus' method.
+///
+/// Each scalar is recoded in signed radix 16, and every digit costs one addition of
+/// a multiple selected from a table of `[P, 2P, ..., 8P]` in constant time.
+pub(crate) fn multiscalar_mul(scalars: &[Scalar], points: &[ExtendedPoint]) -> ExtendedPoint {
+    debug_assert_eq!(scalars.len(), points.len());
+
+    let digits: Vec<[i8; 113]> = scalars.iter().map(|s| s.to_radix_16()).collect();
+    let tables: Vec<LookupTable<ProjectiveNielsPoint, 8>> =
+        points.iter().map(LookupTable::from).collect();
+
+    let mut result = ExtensiblePoint::IDENTITY;
+    for i in (0..113).rev() {
+        result = result.double();
+        result = result.double();
+        result = result.double();
+        result = result.double();
+
+        for (digits, table) in digits.iter().zip(tables.iter()) {
+            result = result.add_projective_niels(&table.select(digits[i] as i16));
+        }
+    }
+
+    result.to_extended()
+}
+
+/// Computes `sum(scalars[i] * points[i])` in variable time using Straus' method.
+///
+/// Each scalar is recoded in width-5 NAF, so the doublings are shared between all terms
+/// and each term only costs an addition for every nonzero digit.
+pub(crate) fn vartime_multiscalar_mul(
+    scalars: &[Scalar],
+    points: &[ExtendedPoint],
+) -> ExtendedPoint {
+    debug_assert_eq!(scalars.len(), points.len());
+
+    let nafs: Vec<[i8; 448]> = scalars.iter().map(|s| s.non_adjacent_form(5)).collect();
+    let tables: Vec<NafLookupTable5> = points.iter().map(NafLookupTable5::from).collect();
+
+    // Skip the leading positions where every digit is zero
+    let top = match nafs
+        .iter()
+        .filter_map(|naf| naf.iter().rposition(|digit| *digit != 0))
+        .max()
+    {
+        Some(top) => top,
+        None => return ExtendedPoint::IDENTITY,
+    };
+
+    let mut result = ExtensiblePoint::IDENTITY;
+    for i in (0..=top).rev() {
+        result = result.double();
+
+        for (naf, table) in nafs.iter().zip(tables.iter()) {
+            let digit = naf[i];
+            if digit > 0 {
+                result = result.add_projective_niels(&table.select(digit as usize));
+            } else if digit < 0 {
+                result = result.sub_projective_niels(&table.select(-digit as usize));
+            }
+        }
+    }
+
+    result.to_extended()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::curve::scalar_mul::variable_base;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn test_multiscalar_mul() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([8u8; 32]);
+
+        assert_eq!(multiscalar_mul(&[], &[]), ExtendedPoint::IDENTITY);
+
+        let mut scalars = vec![Scalar::ZERO, -Scalar::ONE];
+        let mut points = vec![ExtendedPoint::GENERATOR, ExtendedPoint::GENERATOR];
+        let mut expected = ExtendedPoint::GENERATOR.negate();
+        for _ in 0..4 {
+            let scalar = Scalar::random(&mut rng);
+            let point = variable_base(&ExtendedPoint::GENERATOR, &Scalar::random(&mut rng));
+            expected = expected.add(&variable_base(&point, &scalar));
+
+            scalars.push(scalar);
+            points.push(point);
+        }
+
+        assert_eq!(multiscalar_mul(&scalars, &points), expected);
+        assert_eq!(vartime_multiscalar_mul(&scalars, &points), expected);
+    }
+
+    #[test]
+    fn test_vartime_multiscalar_mul() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([3u8; 32]);
+
+        assert_eq!(vartime_multiscalar_mul(&[], &[]), ExtendedPoint::IDENTITY);
+
+        let mut scalars = Vec::new();
+        let mut points = Vec::new();
+        let mut expected = ExtendedPoint::IDENTITY;
+        for i in 0..8 {
+            let scalar = Scalar::random(&mut rng);
+            let point = variable_base(&ExtendedPoint::GENERATOR, &Scalar::random(&mut rng));
+            expected = expected.add(&variable_base(&point, &scalar));
+
+            scalars.push(scalar);
+            points.push(point);
+            assert_eq!(
+                vartime_multiscalar_mul(&scalars, &points),
+                expected,
+                "{}",
+                i
+            );
+        }
+
+        scalars.push(Scalar::ZERO);
+        points.push(ExtendedPoint::GENERATOR);
+        assert_eq!(vartime_multiscalar_mul(&scalars, &points), expected);
+    }
+}
@@ -1,17 +1,30 @@
 #![allow(non_snake_case)]
 
-use super::window::wnaf::LookupTable;
-use crate::curve::twedwards::{extended::ExtendedPoint, extensible::ExtensiblePoint};
+use super::window::wnaf::{LookupTable, NafLookupTable5};
+#[cfg(target_arch = "x86_64")]
+use crate::curve::twedwards::avx2;
+use crate::curve::twedwards::{
+    extended::ExtendedPoint, extensible::ExtensiblePoint, projective::ProjectiveNielsPoint,
+};
 use crate::field::Scalar;
-use subtle::{Choice, ConditionallyNegatable};
 
 pub fn variable_base(point: &ExtendedPoint, s: &Scalar) -> ExtendedPoint {
+    #[cfg(target_arch = "x86_64")]
+    if avx2::PREFERRED && avx2::is_available() {
+        // SAFETY: the CPU supports AVX2
+        return unsafe { avx2::variable_base(point, s) };
+    }
+    serial_variable_base(point, s)
+}
+
+/// The portable ladder, used when no vector backend is available or preferred
+pub(crate) fn serial_variable_base(point: &ExtendedPoint, s: &Scalar) -> ExtendedPoint {
     let mut result = ExtensiblePoint::IDENTITY;
 
     // Recode Scalar
     let scalar = s.to_radix_16();
 
-    let lookup = LookupTable::from(point);
+    let lookup = LookupTable::<ProjectiveNielsPoint, 8>::from(point);
 
     for i in (0..113).rev() {
         result = result.double();
@@ -19,16 +32,36 @@ pub fn variable_base(point: &ExtendedPoint, s: &Scalar) -> ExtendedPoint {
         result = result.double();
         result = result.double();
 
-        // The mask is the top bit, will be 1 for negative numbers, 0 for positive numbers
-        let mask = scalar[i] >> 7;
-        let sign = mask & 0x1;
-        // Use the mask to get the absolute value of scalar
-        let abs_value = ((scalar[i] + mask) ^ mask) as u32;
+        result = result.add_projective_niels(&lookup.select(scalar[i] as i16));
+    }
+
+    result.to_extended()
+}
+
+/// Computes `sP` in variable time, with width-5 wNAF.
+///
+/// This must only be used with public inputs.
+pub(crate) fn vartime_variable_base(point: &ExtendedPoint, s: &Scalar) -> ExtendedPoint {
+    let naf = s.non_adjacent_form(5);
+
+    // Skip the leading zero digits
+    let top = match naf.iter().rposition(|digit| *digit != 0) {
+        Some(top) => top,
+        None => return ExtendedPoint::IDENTITY,
+    };
 
-        let mut neg_P = lookup.select(abs_value);
-        neg_P.conditional_negate(Choice::from((sign) as u8));
+    let table = NafLookupTable5::from(point);
 
-        result = result.add_projective_niels(&neg_P);
+    let mut result = ExtensiblePoint::IDENTITY;
+    for i in (0..=top).rev() {
+        result = result.double();
+
+        let digit = naf[i];
+        if digit > 0 {
+            result = result.add_projective_niels(&table.select(digit as usize));
+        } else if digit < 0 {
+            result = result.sub_projective_niels(&table.select(-digit as usize));
+        }
     }
 
     result.to_extended()
@@ -74,4 +107,20 @@ mod test {
         let got = variable_base(&x, &Scalar::from(4u8));
         assert!(expected_two_x.to_extended() == got);
     }
+    #[test]
+    fn test_vartime_variable_base() {
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([15u8; 32]);
+        let point = variable_base(&TWISTED_EDWARDS_BASE_POINT, &Scalar::random(&mut rng));
+
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, Scalar::from(16u8), -Scalar::ONE];
+        scalars.extend((0..8).map(|_| Scalar::random(&mut rng)));
+        for scalar in scalars.iter() {
+            assert_eq!(
+                vartime_variable_base(&point, scalar),
+                variable_base(&point, scalar)
+            );
+        }
+    }
 }
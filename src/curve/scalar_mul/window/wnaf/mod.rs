@@ -1,19 +1,22 @@
 #![allow(non_snake_case)]
 
+use crate::curve::twedwards::affine::AffineNielsPoint;
 use crate::curve::twedwards::extended::ExtendedPoint;
 use crate::curve::twedwards::projective::ProjectiveNielsPoint;
-use subtle::{ConditionallySelectable, ConstantTimeEq};
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 
-pub struct LookupTable([ProjectiveNielsPoint; 8]);
+/// Holds the multiples `[P, 2P, ..., NP]` of a point, for constant time lookups
+#[derive(Copy, Clone)]
+pub struct LookupTable<T, const N: usize>(pub(crate) [T; N]);
 
-/// Precomputes odd multiples of the point passed in
-impl From<&ExtendedPoint> for LookupTable {
-    fn from(point: &ExtendedPoint) -> LookupTable {
+/// Precomputes the multiples of the point passed in
+impl<const N: usize> From<&ExtendedPoint> for LookupTable<ProjectiveNielsPoint, N> {
+    fn from(point: &ExtendedPoint) -> Self {
         let P = point.to_extensible();
 
-        let mut table = [P.to_projective_niels(); 8];
+        let mut table = [P.to_projective_niels(); N];
 
-        for i in 1..8 {
+        for i in 1..N {
             table[i] = P.add_projective_niels(&table[i - 1]).to_projective_niels();
         }
 
@@ -21,30 +24,96 @@ impl From<&ExtendedPoint> for LookupTable {
     }
 }
 
-impl LookupTable {
-    /// Selects a projective niels point from a lookup table in constant time
-    pub fn select(&self, index: u32) -> ProjectiveNielsPoint {
-        let mut result = ProjectiveNielsPoint::identity();
+impl<T, const N: usize> LookupTable<T, N>
+where
+    T: Copy + Default + ConditionallySelectable + ConditionallyNegatable,
+{
+    /// Selects `x * P` in constant time, for `-N <= x <= N`
+    pub fn select(&self, x: i16) -> T {
+        // The mask is all ones for negative numbers and zero otherwise
+        let mask = x >> 15;
+        let abs_value = ((x + mask) ^ mask) as u16;
 
-        for i in 1..9 {
-            let swap = index.ct_eq(&(i as u32));
-            result.conditional_assign(&self.0[i - 1], swap);
+        let mut result = T::default();
+        for (j, point) in self.0.iter().enumerate() {
+            result.conditional_assign(point, abs_value.ct_eq(&(j as u16 + 1)));
         }
+        result.conditional_negate(Choice::from((mask & 1) as u8));
         result
     }
 }
 
+/// Holds the odd multiples `[P, 3P, ..., 15P]` used by width-5 wNAF
+pub struct NafLookupTable5([ProjectiveNielsPoint; 8]);
+
+impl From<&ExtendedPoint> for NafLookupTable5 {
+    fn from(point: &ExtendedPoint) -> NafLookupTable5 {
+        let P = point.to_extensible();
+        let P2 = P.double().to_extended();
+
+        let mut table = [P.to_projective_niels(); 8];
+
+        let mut multiple = P;
+        for entry in table.iter_mut().skip(1) {
+            multiple = multiple.add_extended(&P2);
+            *entry = multiple.to_projective_niels();
+        }
+
+        NafLookupTable5(table)
+    }
+}
+
+impl NafLookupTable5 {
+    /// Selects `x * P` for an odd `x` in `1..16`, in variable time
+    pub fn select(&self, x: usize) -> ProjectiveNielsPoint {
+        debug_assert_eq!(x & 1, 1);
+        debug_assert!(x < 16);
+
+        self.0[x / 2]
+    }
+}
+
+/// Holds the odd multiples `[P, 3P, ..., 127P]` used by width-8 wNAF
+pub struct NafLookupTable8(pub(crate) [AffineNielsPoint; 64]);
+
+impl From<&ExtendedPoint> for NafLookupTable8 {
+    fn from(point: &ExtendedPoint) -> NafLookupTable8 {
+        let P2 = point.double();
+
+        let mut table = [AffineNielsPoint::IDENTITY; 64];
+
+        let mut multiple = *point;
+        for entry in table.iter_mut() {
+            *entry = multiple.to_affine().to_affine_niels();
+            multiple = multiple.add(&P2);
+        }
+
+        NafLookupTable8(table)
+    }
+}
+
+impl NafLookupTable8 {
+    /// Selects `x * P` for an odd `x` in `1..128`, in variable time
+    pub fn select(&self, x: usize) -> AffineNielsPoint {
+        debug_assert_eq!(x & 1, 1);
+        debug_assert!(x < 128);
+
+        self.0[x / 2]
+    }
+}
+
 // XXX: Add back tests to ensure that select works correctly
 
 #[test]
 fn test_lookup() {
     let p = ExtendedPoint::GENERATOR;
-    let points = LookupTable::from(&p);
+    let points = LookupTable::<ProjectiveNielsPoint, 8>::from(&p);
 
     let mut expected_point = ExtendedPoint::IDENTITY;
-    for i in 0..8 {
+    for i in 0..=8 {
         let selected_point = points.select(i);
         assert_eq!(selected_point.to_extended(), expected_point);
+        assert_eq!(points.select(-i).to_extended(), expected_point.negate());
 
         expected_point = expected_point
             .to_extensible()
@@ -52,3 +121,27 @@ fn test_lookup() {
             .to_extended();
     }
 }
+
+#[test]
+fn test_naf_lookup() {
+    let p = ExtendedPoint::GENERATOR;
+    let points = NafLookupTable5::from(&p);
+
+    let mut expected_point = p;
+    for i in (1..16).step_by(2) {
+        assert_eq!(points.select(i).to_extended(), expected_point);
+        expected_point = expected_point.add(&p).add(&p);
+    }
+}
+
+#[test]
+fn test_naf_lookup_8() {
+    let p = ExtendedPoint::GENERATOR;
+    let points = NafLookupTable8::from(&p);
+
+    let mut expected_point = p;
+    for i in (1..128).step_by(2) {
+        assert_eq!(points.select(i).to_extended(), expected_point);
+        expected_point = expected_point.add(&p).add(&p);
+    }
+}
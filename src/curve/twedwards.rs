@@ -3,6 +3,8 @@
 /// This curve will be used as a backend for the Goldilocks, Ristretto and Decaf through the use of isogenies.
 /// It will not be exposed in the public API.
 pub(crate) mod affine;
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod avx2;
 pub(crate) mod extended;
 pub(crate) mod extensible;
 pub(crate) mod projective;
@@ -1,7 +1,8 @@
 #![allow(dead_code)]
 use crate::curve::twedwards::{extended::ExtendedPoint, extensible::ExtensiblePoint};
-use crate::field::FieldElement;
-use subtle::{Choice, ConditionallySelectable};
+use crate::field::{FieldElement, ResidueType};
+use elliptic_curve::bigint::U448;
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable};
 
 /// This point representation is not a part of the API.
 ///
@@ -31,7 +32,7 @@ impl AffinePoint {
     };
 
     /// Checks if the AffinePoint is on the TwistedEdwards curve
-    fn is_on_curve(&self) -> bool {
+    pub(crate) fn is_on_curve(&self) -> bool {
         let xx = self.x.square();
         let yy = self.y.square();
 
@@ -72,14 +73,15 @@ impl AffinePoint {
         }
     }
 
-    // /// Converts an AffinePoint to an AffineNielsPoint
-    // pub(crate) fn to_affine_niels(&self) -> AffineNielsPoint {
-    //     AffineNielsPoint {
-    //         y_plus_x: self.y + self.x,
-    //         y_minus_x: self.y - self.x,
-    //         td: self.x * self.y * FieldElement::TWISTED_D,
-    //     }
-    // }
+    /// Converts an AffinePoint to an AffineNielsPoint
+    pub(crate) fn to_affine_niels(self) -> AffineNielsPoint {
+        AffineNielsPoint {
+            y_plus_x: (self.y + self.x) * AffineNielsPoint::ONE_HALF,
+            y_minus_x: (self.y - self.x) * AffineNielsPoint::ONE_HALF,
+            td: self.x * self.y * FieldElement::TWISTED_D,
+        }
+    }
+
     /// Converts an An AffinePoint to an ExtendedPoint
     pub(crate) fn to_extended(self) -> ExtendedPoint {
         self.to_extensible().to_extended()
@@ -95,6 +97,12 @@ pub struct AffineNielsPoint {
     pub(crate) td: FieldElement,
 }
 
+impl Default for AffineNielsPoint {
+    fn default() -> AffineNielsPoint {
+        AffineNielsPoint::IDENTITY
+    }
+}
+
 impl ConditionallySelectable for AffineNielsPoint {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         AffineNielsPoint {
@@ -105,11 +113,20 @@ impl ConditionallySelectable for AffineNielsPoint {
     }
 }
 
+impl ConditionallyNegatable for AffineNielsPoint {
+    fn conditional_negate(&mut self, choice: Choice) {
+        FieldElement::conditional_swap(&mut self.y_minus_x, &mut self.y_plus_x, choice);
+        self.td.conditional_negate(choice);
+    }
+}
+
 impl AffineNielsPoint {
+    const ONE_HALF: FieldElement = FieldElement(ResidueType::new(&U448::from_be_hex("7fffffffffffffffffffffffffffffffffffffffffffffffffffffff80000000000000000000000000000000000000000000000000000000")));
+
     /// Returns the identity element for an AffineNielsPoint
     pub(crate) const IDENTITY: AffineNielsPoint = AffineNielsPoint {
-        y_plus_x: FieldElement::ONE,
-        y_minus_x: FieldElement::ONE,
+        y_plus_x: Self::ONE_HALF,
+        y_minus_x: Self::ONE_HALF,
         td: FieldElement::ZERO,
     };
 
@@ -127,7 +144,7 @@ impl AffineNielsPoint {
             X: self.y_plus_x - self.y_minus_x,
             Y: self.y_minus_x + self.y_plus_x,
             Z: FieldElement::ONE,
-            T: self.y_plus_x * self.y_minus_x,
+            T: (self.y_plus_x - self.y_minus_x) * (self.y_minus_x + self.y_plus_x),
         }
     }
 }
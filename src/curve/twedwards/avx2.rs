@@ -0,0 +1,327 @@
+//! Twisted Edwards arithmetic on AVX2, with the four coordinates of a point held in the
+//! four lanes of a [`FieldElement28x4`].
+//!
+//! The formulas are those of the serial [`ExtensiblePoint`](super::extensible::ExtensiblePoint)
+//! code, grouped so that each step is one four-way multiplication. Every coordinate is
+//! therefore the same field element that the serial code computes.
+//!
+//! Single additions and doublings still go through the serial code, as packing and
+//! unpacking a point costs more than the vector formulas save. The scalar multiplication
+//! ladder keeps its points packed throughout, and is dispatched here when the CPU allows
+//! and the backend is [`PREFERRED`].
+#![allow(non_snake_case)]
+
+mod field;
+
+use crate::curve::twedwards::extended::ExtendedPoint as SerialExtendedPoint;
+use crate::field::{FieldElement, Scalar};
+use core::arch::x86_64::*;
+use field::{FieldElement28x4, A, B, C, D};
+use subtle::{Choice, ConstantTimeEq};
+
+/// Swaps the lanes A and B, so `(X, Y, Z, T)` becomes `(Y, X, Z, T)`
+const SWAP_AB: i32 = 0b11_10_00_01;
+
+/// Copies lane `k` into every lane
+const fn broadcast(k: i32) -> i32 {
+    k * 0b01_01_01_01
+}
+
+/// Whether the ladder should use this backend on CPUs that support it.
+///
+/// Four 32-bit products at once beat the 28-bit serial backend, but not the 56-bit one,
+/// which gets full 64-bit products from the general purpose multiplier. So with the
+/// 64-bit backend this is only used when opted into with `avx2_backend`.
+pub(crate) const PREFERRED: bool = cfg!(any(feature = "u32_backend", feature = "avx2_backend"));
+
+/// Whether the CPU supports AVX2.
+///
+/// This is detected at runtime with `std`, and otherwise decided by the target features
+/// the crate is compiled with.
+pub(crate) fn is_available() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx2")
+    }
+}
+
+/// A point in extended coordinates `(X, Y, Z, T)`, one coordinate per lane
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct ExtendedPoint(FieldElement28x4);
+
+/// A point as `(Y - X, Y + X, 2Z, 2dT)`, ready to be added to an [`ExtendedPoint`].
+///
+/// This is the [`ProjectiveNielsPoint`](super::projective::ProjectiveNielsPoint) layout.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct CachedPoint(FieldElement28x4);
+
+impl ExtendedPoint {
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn identity() -> Self {
+        Self(FieldElement28x4::new(
+            &FieldElement::ZERO,
+            &FieldElement::ONE,
+            &FieldElement::ONE,
+            &FieldElement::ZERO,
+        ))
+    }
+
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn from_serial(point: &SerialExtendedPoint) -> Self {
+        Self(FieldElement28x4::new(
+            &point.X, &point.Y, &point.Z, &point.T,
+        ))
+    }
+
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn to_serial(self) -> SerialExtendedPoint {
+        let [X, Y, Z, T] = self.0.split();
+        SerialExtendedPoint { X, Y, Z, T }
+    }
+
+    /// Returns `(Y - X, Y + X, Z, T)`
+    #[target_feature(enable = "avx2")]
+    unsafe fn diff_sum(&self) -> FieldElement28x4 {
+        let swapped = self.0.shuffle::<SWAP_AB>();
+        let x_y = self.0.blend::<{ C | D }>(&FieldElement28x4::zero());
+        let diff = swapped.sub(&x_y);
+        let sum = swapped.add(&x_y);
+        diff.blend::<{ B | C | D }>(&sum).reduce()
+    }
+
+    /// Converts to the cached form, like `ExtensiblePoint::to_projective_niels`
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn to_cached(self) -> CachedPoint {
+        let factors = FieldElement28x4::new(
+            &FieldElement::ONE,
+            &FieldElement::ONE,
+            &(FieldElement::ONE + FieldElement::ONE),
+            &FieldElement::TWO_TIMES_TWISTED_D,
+        );
+        CachedPoint(self.diff_sum().mul(&factors))
+    }
+
+    /// Doubles a point, like `ExtensiblePoint::double`
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn double(&self) -> Self {
+        let zero = FieldElement28x4::zero();
+
+        // (A, B, Z^2, S) are the squares of (X, Y, Z, X + Y)
+        let x_y_z_x = self.0.shuffle::<0b00_10_01_00>();
+        let y = self
+            .0
+            .shuffle::<{ broadcast(1) }>()
+            .blend::<{ A | B | C }>(&zero);
+        let squares = x_y_z_x.add(&y).reduce().square();
+
+        // (E, F, G, H) = (S, B, B, 0) - (B, 2 Z^2, 0, B) - (A, A, A, A)
+        let s_b_b_0 = squares.shuffle::<0b01_01_01_11>().blend::<D>(&zero);
+        let b_zz_zz_b = squares.shuffle::<0b01_10_10_01>();
+        let b_2zz_0_b = b_zz_zz_b
+            .add(&b_zz_zz_b.blend::<{ A | C | D }>(&zero))
+            .blend::<C>(&zero);
+        let a = squares.shuffle::<{ broadcast(0) }>();
+        let efgh = s_b_b_0.sub(&b_2zz_0_b.add(&a)).reduce();
+
+        // (X, Y, Z, T) = (E F, G H, F G, E H)
+        let left = efgh.shuffle::<0b00_01_10_00>();
+        let right = efgh.shuffle::<0b11_10_11_01>();
+        Self(left.mul(&right))
+    }
+
+    /// Adds a cached point, like `ExtensiblePoint::add_projective_niels`
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn add(&self, other: &CachedPoint) -> Self {
+        // (A, B, Z, C) = (Y1 - X1, Y1 + X1, Z1, T1) * (Y2 - X2, Y2 + X2, 2 Z2, 2d T2)
+        let products = self.diff_sum().mul(&other.0);
+
+        // (E, F, G, D) = (B - A, Z - C, Z + C, B + A)
+        let b_z_z_b = products.shuffle::<0b01_10_10_01>();
+        let a_c_c_a = products.shuffle::<0b00_11_11_00>();
+        let efgd = b_z_z_b
+            .sub(&a_c_c_a)
+            .blend::<{ C | D }>(&b_z_z_b.add(&a_c_c_a))
+            .reduce();
+
+        // (X, Y, Z, T) = (E F, G D, F G, E D)
+        let left = efgd.shuffle::<0b00_01_10_00>();
+        let right = efgd.shuffle::<0b11_10_11_01>();
+        Self(left.mul(&right))
+    }
+}
+
+impl CachedPoint {
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn identity() -> Self {
+        ExtendedPoint::identity().to_cached()
+    }
+
+    /// Negates the point when `choice` is set, in constant time
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn conditional_negate(&self, choice: Choice) -> Self {
+        let swapped = self.0.shuffle::<SWAP_AB>();
+        let negated = FieldElement28x4::zero().sub(&swapped).reduce();
+        Self(self.0.select(&swapped.blend::<D>(&negated), mask(choice)))
+    }
+}
+
+/// Holds `[P, 2P, ..., 8P]` for the radix 16 ladder
+struct LookupTable([CachedPoint; 8]);
+
+impl LookupTable {
+    #[target_feature(enable = "avx2")]
+    unsafe fn new(point: &ExtendedPoint) -> Self {
+        let mut table = [point.to_cached(); 8];
+        for i in 1..8 {
+            table[i] = point.add(&table[i - 1]).to_cached();
+        }
+        Self(table)
+    }
+
+    /// Selects `index * P` for `index` in `0..=8`, in constant time
+    #[target_feature(enable = "avx2")]
+    unsafe fn select(&self, index: u32) -> CachedPoint {
+        let mut result = CachedPoint::identity();
+        for (i, entry) in self.0.iter().enumerate() {
+            let choice = index.ct_eq(&(i as u32 + 1));
+            result = CachedPoint(result.0.select(&entry.0, mask(choice)));
+        }
+        result
+    }
+}
+
+/// Spreads a choice over every bit of every lane
+#[target_feature(enable = "avx2")]
+unsafe fn mask(choice: Choice) -> __m256i {
+    _mm256_set1_epi64x(-(choice.unwrap_u8() as i64))
+}
+
+/// The constant-time radix 16 ladder of `variable_base`, on AVX2
+///
+/// # Safety
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn variable_base(point: &SerialExtendedPoint, s: &Scalar) -> SerialExtendedPoint {
+    let mut result = ExtendedPoint::identity();
+
+    let scalar = s.to_radix_16();
+
+    let lookup = LookupTable::new(&ExtendedPoint::from_serial(point));
+
+    for i in (0..113).rev() {
+        result = result.double();
+        result = result.double();
+        result = result.double();
+        result = result.double();
+
+        // Split the digit into its sign and absolute value, as the serial ladder does
+        let mask = scalar[i] >> 7;
+        let sign = mask & 0x1;
+        let abs_value = ((scalar[i] + mask) ^ mask) as u32;
+
+        let P = lookup
+            .select(abs_value)
+            .conditional_negate(Choice::from(sign as u8));
+
+        result = result.add(&P);
+    }
+
+    result.to_serial()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::scalar_mul::variable_base::serial_variable_base;
+    use rand_core::SeedableRng;
+
+    fn assert_same_coordinates(a: &SerialExtendedPoint, b: &SerialExtendedPoint) {
+        assert_eq!(a.X.to_bytes(), b.X.to_bytes());
+        assert_eq!(a.Y.to_bytes(), b.Y.to_bytes());
+        assert_eq!(a.Z.to_bytes(), b.Z.to_bytes());
+        assert_eq!(a.T.to_bytes(), b.T.to_bytes());
+    }
+
+    fn points() -> impl Iterator<Item = SerialExtendedPoint> {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([14u8; 32]);
+        let mut point = SerialExtendedPoint::GENERATOR;
+        (0..16).map(move |_| {
+            point = serial_variable_base(&point, &Scalar::random(&mut rng));
+            point
+        })
+    }
+
+    #[test]
+    fn point_arithmetic_matches_serial() {
+        if !is_available() {
+            return;
+        }
+        let mut previous = SerialExtendedPoint::IDENTITY.to_extensible();
+        for point in points() {
+            let serial = point.to_extensible();
+            unsafe {
+                let vector = ExtendedPoint::from_serial(&point);
+
+                let doubled = serial.double().to_extended();
+                assert_same_coordinates(&vector.double().to_serial(), &doubled);
+
+                let cached = ExtendedPoint::from_serial(&previous.to_extended()).to_cached();
+                let sum = serial.add_projective_niels(&previous.to_projective_niels());
+                assert_same_coordinates(&vector.add(&cached).to_serial(), &sum.to_extended());
+
+                let negated = cached.conditional_negate(Choice::from(1));
+                let difference = serial.sub_projective_niels(&previous.to_projective_niels());
+                assert_same_coordinates(
+                    &vector.add(&negated).to_serial(),
+                    &difference.to_extended(),
+                );
+            }
+            previous = serial;
+        }
+    }
+
+    #[test]
+    fn variable_base_matches_serial() {
+        if !is_available() {
+            return;
+        }
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([15u8; 32]);
+        let scalars = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::random(&mut rng),
+        ];
+        for point in points() {
+            for scalar in scalars.iter().chain([Scalar::random(&mut rng)].iter()) {
+                let expected = serial_variable_base(&point, scalar);
+                let got = unsafe { variable_base(&point, scalar) };
+                assert_same_coordinates(&got, &expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,350 @@
+//! Four field elements at once, with one 28-bit limb of each in a 64-bit lane.
+//!
+//! This is the 28-bit backend spread over the lanes of an AVX2 register: every
+//! limb sits in the low half of its lane so that `vpmuludq` can multiply all four
+//! lanes at once, and the Karatsuba split and reduction are those of the serial code.
+#![allow(clippy::needless_range_loop)]
+
+use crate::field::FieldElement;
+use core::arch::x86_64::*;
+
+const MASK: u64 = (1 << 28) - 1;
+
+/// The lanes of a [`FieldElement28x4`], as masks for `blend`
+pub(crate) const A: i32 = 0b0000_0011;
+pub(crate) const B: i32 = 0b0000_1100;
+pub(crate) const C: i32 = 0b0011_0000;
+pub(crate) const D: i32 = 0b1100_0000;
+
+/// Four elements of the field modulo `p = 2^448 - 2^224 - 1`, in the lanes A, B, C and D.
+///
+/// `add` and `sub` leave their results unreduced, so that a few of them can be chained
+/// at the cost of a single `reduce` before the next multiplication.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct FieldElement28x4(pub(crate) [__m256i; 16]);
+
+impl FieldElement28x4 {
+    /// Packs four field elements into the lanes A, B, C and D
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn new(
+        a: &FieldElement,
+        b: &FieldElement,
+        c: &FieldElement,
+        d: &FieldElement,
+    ) -> Self {
+        let (a, b, c, d) = (to_limbs(a), to_limbs(b), to_limbs(c), to_limbs(d));
+        let mut limbs = [_mm256_setzero_si256(); 16];
+        for i in 0..16 {
+            limbs[i] = _mm256_set_epi64x(d[i] as i64, c[i] as i64, b[i] as i64, a[i] as i64);
+        }
+        Self(limbs)
+    }
+
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn zero() -> Self {
+        Self([_mm256_setzero_si256(); 16])
+    }
+
+    /// Unpacks the lanes A, B, C and D into four field elements
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn split(&self) -> [FieldElement; 4] {
+        let mut lanes = [[0u64; 16]; 4];
+        for i in 0..16 {
+            let mut limb = [0u64; 4];
+            _mm256_storeu_si256(limb.as_mut_ptr() as *mut __m256i, self.0[i]);
+            for j in 0..4 {
+                lanes[j][i] = limb[j];
+            }
+        }
+        lanes.map(|limbs| from_limbs(&limbs))
+    }
+
+    /// Permutes the lanes: destination lane `k` takes source lane `(CONTROL >> 2k) & 3`
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn shuffle<const CONTROL: i32>(&self) -> Self {
+        let mut limbs = self.0;
+        for limb in limbs.iter_mut() {
+            *limb = _mm256_permute4x64_epi64::<CONTROL>(*limb);
+        }
+        Self(limbs)
+    }
+
+    /// Takes the `LANES` from `other` and the rest from `self`
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn blend<const LANES: i32>(&self, other: &Self) -> Self {
+        let mut limbs = self.0;
+        for i in 0..16 {
+            limbs[i] = _mm256_blend_epi32::<LANES>(self.0[i], other.0[i]);
+        }
+        Self(limbs)
+    }
+
+    /// Takes every lane from `other` where the corresponding lane of `mask` is all ones
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn select(&self, other: &Self, mask: __m256i) -> Self {
+        let mut limbs = self.0;
+        for i in 0..16 {
+            limbs[i] = _mm256_or_si256(
+                _mm256_andnot_si256(mask, self.0[i]),
+                _mm256_and_si256(mask, other.0[i]),
+            );
+        }
+        Self(limbs)
+    }
+
+    /// Adds without reducing
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn add(&self, other: &Self) -> Self {
+        let mut limbs = self.0;
+        for i in 0..16 {
+            limbs[i] = _mm256_add_epi64(self.0[i], other.0[i]);
+        }
+        Self(limbs)
+    }
+
+    /// Subtracts without reducing. `other` may be the sum of two reduced elements.
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn sub(&self, other: &Self) -> Self {
+        // Add 4p first so that no limb can underflow
+        let four_p = _mm256_set1_epi64x((4 * MASK) as i64);
+        let four_p_middle = _mm256_set1_epi64x((4 * (MASK - 1)) as i64);
+        let mut limbs = self.0;
+        for i in 0..16 {
+            let bias = if i == 8 { four_p_middle } else { four_p };
+            limbs[i] = _mm256_sub_epi64(_mm256_add_epi64(self.0[i], bias), other.0[i]);
+        }
+        Self(limbs)
+    }
+
+    /// Bring every limb back to just over 28 bits, as needed before a multiplication
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn reduce(&self) -> Self {
+        let mask = _mm256_set1_epi64x(MASK as i64);
+        let mut limbs = self.0;
+        let top_carry = _mm256_srli_epi64::<28>(limbs[15]);
+        limbs[8] = _mm256_add_epi64(limbs[8], top_carry);
+        for i in (1..16).rev() {
+            limbs[i] = _mm256_add_epi64(
+                _mm256_and_si256(limbs[i], mask),
+                _mm256_srli_epi64::<28>(limbs[i - 1]),
+            );
+        }
+        limbs[0] = _mm256_add_epi64(_mm256_and_si256(limbs[0], mask), top_carry);
+        Self(limbs)
+    }
+
+    /// Multiplies two reduced elements
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn mul(&self, other: &Self) -> Self {
+        let a = &self.0;
+        let b = &other.0;
+
+        let mut a0 = [_mm256_setzero_si256(); 8];
+        let mut a1 = [_mm256_setzero_si256(); 8];
+        let mut b0 = [_mm256_setzero_si256(); 8];
+        let mut b1 = [_mm256_setzero_si256(); 8];
+        let mut aa = [_mm256_setzero_si256(); 8];
+        let mut bb = [_mm256_setzero_si256(); 8];
+        for i in 0..8 {
+            a0[i] = a[i];
+            a1[i] = a[i + 8];
+            b0[i] = b[i];
+            b1[i] = b[i + 8];
+            aa[i] = _mm256_add_epi64(a[i], a[i + 8]);
+            bb[i] = _mm256_add_epi64(b[i], b[i + 8]);
+        }
+
+        // The products of the halves, as polynomials in 2^28 with 15 coefficients
+        let lo = mul_8x8(&a0, &b0);
+        let hi = mul_8x8(&a1, &b1);
+        let mid = mul_8x8(&aa, &bb);
+
+        // Coefficients 8 to 14 of each product carry a factor of φ, and φ^2 = φ + 1
+        let mut wide = [_mm256_setzero_si256(); 16];
+        for i in 0..8 {
+            let (lo_high, hi_high, mid_high) = if i < 7 {
+                (lo[i + 8], hi[i + 8], mid[i + 8])
+            } else {
+                let zero = _mm256_setzero_si256();
+                (zero, zero, zero)
+            };
+            wide[i] = _mm256_sub_epi64(
+                _mm256_add_epi64(_mm256_add_epi64(lo[i], hi[i]), mid_high),
+                lo_high,
+            );
+            wide[i + 8] = _mm256_sub_epi64(
+                _mm256_add_epi64(_mm256_add_epi64(hi_high, mid[i]), mid_high),
+                lo[i],
+            );
+        }
+
+        Self::reduce_wide(wide)
+    }
+
+    /// Squares a reduced element
+    ///
+    /// # Safety
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn square(&self) -> Self {
+        let a = &self.0;
+
+        let mut a0 = [_mm256_setzero_si256(); 8];
+        let mut a1 = [_mm256_setzero_si256(); 8];
+        let mut aa = [_mm256_setzero_si256(); 8];
+        for i in 0..8 {
+            a0[i] = a[i];
+            a1[i] = a[i + 8];
+            aa[i] = _mm256_add_epi64(a[i], a[i + 8]);
+        }
+
+        // The same combination of half products as in `mul`
+        let lo = square_8(&a0);
+        let hi = square_8(&a1);
+        let mid = square_8(&aa);
+
+        let mut wide = [_mm256_setzero_si256(); 16];
+        for i in 0..8 {
+            let (lo_high, hi_high, mid_high) = if i < 7 {
+                (lo[i + 8], hi[i + 8], mid[i + 8])
+            } else {
+                let zero = _mm256_setzero_si256();
+                (zero, zero, zero)
+            };
+            wide[i] = _mm256_sub_epi64(
+                _mm256_add_epi64(_mm256_add_epi64(lo[i], hi[i]), mid_high),
+                lo_high,
+            );
+            wide[i + 8] = _mm256_sub_epi64(
+                _mm256_add_epi64(_mm256_add_epi64(hi_high, mid[i]), mid_high),
+                lo[i],
+            );
+        }
+
+        Self::reduce_wide(wide)
+    }
+
+    /// Carry the wide coefficients of a product back down to 28 bits each
+    #[target_feature(enable = "avx2")]
+    unsafe fn reduce_wide(wide: [__m256i; 16]) -> Self {
+        let mask = _mm256_set1_epi64x(MASK as i64);
+        let mut limbs = [_mm256_setzero_si256(); 16];
+        let mut carry = _mm256_setzero_si256();
+        for i in 0..16 {
+            let value = _mm256_add_epi64(wide[i], carry);
+            limbs[i] = _mm256_and_si256(value, mask);
+            carry = _mm256_srli_epi64::<28>(value);
+        }
+
+        // The carry out of the top limb is folded back in at limbs 0 and 8, as
+        // 2^448 = 2^224 + 1. Limbs 1 and 9 absorb what little that carries over.
+        let low = _mm256_add_epi64(limbs[0], carry);
+        let high = _mm256_add_epi64(limbs[8], carry);
+        limbs[0] = _mm256_and_si256(low, mask);
+        limbs[1] = _mm256_add_epi64(limbs[1], _mm256_srli_epi64::<28>(low));
+        limbs[8] = _mm256_and_si256(high, mask);
+        limbs[9] = _mm256_add_epi64(limbs[9], _mm256_srli_epi64::<28>(high));
+
+        Self(limbs)
+    }
+}
+
+/// Schoolbook product of two eight limb numbers in each lane
+#[target_feature(enable = "avx2")]
+unsafe fn mul_8x8(a: &[__m256i; 8], b: &[__m256i; 8]) -> [__m256i; 15] {
+    let mut out = [_mm256_setzero_si256(); 15];
+    for i in 0..8 {
+        for j in 0..8 {
+            out[i + j] = _mm256_add_epi64(out[i + j], _mm256_mul_epu32(a[i], b[j]));
+        }
+    }
+    out
+}
+
+/// Square of an eight limb number in each lane, computing each cross product once
+#[target_feature(enable = "avx2")]
+unsafe fn square_8(a: &[__m256i; 8]) -> [__m256i; 15] {
+    let mut out = [_mm256_setzero_si256(); 15];
+    for i in 0..8 {
+        // The limbs are below 2^30, so doubling them still fits the 32-bit multiplier
+        let twice = _mm256_add_epi64(a[i], a[i]);
+        out[2 * i] = _mm256_add_epi64(out[2 * i], _mm256_mul_epu32(a[i], a[i]));
+        for j in i + 1..8 {
+            out[i + j] = _mm256_add_epi64(out[i + j], _mm256_mul_epu32(twice, a[j]));
+        }
+    }
+    out
+}
+
+/// Splits the canonical encoding of a field element into 28-bit limbs
+fn to_limbs(x: &FieldElement) -> [u64; 16] {
+    let bytes = x.to_bytes();
+    let mut limbs = [0u64; 16];
+    // Every 7 bytes make up two limbs
+    for (i, chunk) in bytes.chunks(7).enumerate() {
+        let mut pair = 0u64;
+        for (j, byte) in chunk.iter().enumerate() {
+            pair |= (*byte as u64) << (8 * j);
+        }
+        limbs[2 * i] = pair & MASK;
+        limbs[2 * i + 1] = pair >> 28;
+    }
+    limbs
+}
+
+/// Recombines weakly reduced 28-bit limbs into a field element
+fn from_limbs(limbs: &[u64; 16]) -> FieldElement {
+    // Carry the limbs through so that they fit the encoding, folding the top carry
+    // back in with 2^448 = 2^224 + 1, then reduce through the serial backend.
+    let mut carried = [0u64; 16];
+    let mut carry = 0u64;
+    for i in 0..16 {
+        let value = limbs[i] + carry;
+        carried[i] = value & MASK;
+        carry = value >> 28;
+    }
+    let mut bytes = [0u8; 56];
+    for (i, chunk) in bytes.chunks_mut(7).enumerate() {
+        let pair = carried[2 * i] | (carried[2 * i + 1] << 28);
+        for (j, byte) in chunk.iter_mut().enumerate() {
+            *byte = (pair >> (8 * j)) as u8;
+        }
+    }
+    let folded = FieldElement::from_bytes(&bytes);
+    let carry = FieldElement::from_bytes(&{
+        let mut bytes = [0u8; 56];
+        bytes[0] = carry as u8;
+        bytes[28] = carry as u8;
+        bytes
+    });
+    folded + carry
+}
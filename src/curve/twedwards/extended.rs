@@ -105,39 +105,26 @@ impl ExtendedPoint {
         AffinePoint { x, y }
     }
 
-    /// Edwards_Isogeny is derived from the doubling formula
-    /// XXX: There is a duplicate method in the twisted edwards module to compute the dual isogeny
-    /// XXX: Not much point trying to make it generic I think. So what we can do is optimise each respective isogeny method for a=1 or a = -1 (currently, I just made it really slow and simple)
-    fn edwards_isogeny(&self, a: FieldElement) -> EdwardsExtendedPoint {
-        // Convert to affine now, then derive extended version later
-        let affine = self.to_affine();
-        let x = affine.x;
-        let y = affine.y;
-
-        // Compute x
-        let xy = x * y;
-        let x_numerator = xy + xy;
-        let x_denom = y.square() - (a * x.square());
-        let new_x = x_numerator * x_denom.invert();
-
-        // Compute y
-        let y_numerator = y.square() + (a * x.square());
-        let y_denom = (FieldElement::ONE + FieldElement::ONE) - y.square() - (a * x.square());
-        let new_y = y_numerator * y_denom.invert();
+    /// Uses a 2-isogeny to map the point to the Ed448-Goldilocks.
+    ///
+    /// This is the affine map `(x, y) -> (2xy / (y^2 + x^2), (y^2 - x^2) / (2 - y^2 + x^2))`,
+    /// in projective coordinates so that it needs no inversion.
+    pub fn to_untwisted(self) -> EdwardsExtendedPoint {
+        let XX = self.X.square();
+        let YY = self.Y.square();
+        let XY = (self.X + self.Y).square() - XX - YY;
+        let YY_plus_XX = YY + XX;
+        let YY_minus_XX = YY - XX;
+        let denominator = self.Z.square().double() - YY_minus_XX;
 
         EdwardsExtendedPoint {
-            X: new_x,
-            Y: new_y,
-            Z: FieldElement::ONE,
-            T: new_x * new_y,
+            X: XY * denominator,
+            Y: YY_minus_XX * YY_plus_XX,
+            Z: YY_plus_XX * denominator,
+            T: XY * YY_minus_XX,
         }
     }
 
-    /// Uses a 2-isogeny to map the point to the Ed448-Goldilocks
-    pub fn to_untwisted(self) -> EdwardsExtendedPoint {
-        self.edwards_isogeny(FieldElement::MINUS_ONE)
-    }
-
     /// Checks if the point is on the curve
     pub(crate) fn is_on_curve(&self) -> Choice {
         let XY = self.X * self.Y;
@@ -197,6 +184,43 @@ mod tests {
         assert_eq!(twist_a, a.double().double())
     }
 
+    #[test]
+    fn test_projective_isogeny_matches_affine_isogeny() {
+        // The affine isogeny that `to_untwisted` used before
+        let to_untwisted_by_affine_isogeny = |point: &ExtendedPoint| {
+            let AffinePoint { x, y } = point.to_affine();
+            let new_x = (x * y).double() * (y.square() + x.square()).invert();
+            let new_y = (y.square() - x.square())
+                * (FieldElement::ONE.double() - y.square() + x.square()).invert();
+
+            EdwardsExtendedPoint {
+                X: new_x,
+                Y: new_y,
+                Z: FieldElement::ONE,
+                T: new_x * new_y,
+            }
+        };
+
+        let two_torsion = ExtendedPoint {
+            X: FieldElement::ZERO,
+            Y: FieldElement::MINUS_ONE,
+            Z: FieldElement::ONE,
+            T: FieldElement::ZERO,
+        };
+        let mut point = TWISTED_EDWARDS_BASE_POINT;
+        for _ in 0..16 {
+            for point in [point, point.add(&two_torsion)] {
+                let untwisted = point.to_untwisted();
+                assert_eq!(untwisted, to_untwisted_by_affine_isogeny(&point));
+                assert_eq!(untwisted.is_on_curve().unwrap_u8(), 1u8);
+            }
+            point = point.double().add(&TWISTED_EDWARDS_BASE_POINT);
+        }
+        for point in [ExtendedPoint::IDENTITY, two_torsion] {
+            assert_eq!(point.to_untwisted(), to_untwisted_by_affine_isogeny(&point));
+        }
+    }
+
     #[test]
     fn test_is_on_curve() {
         // The twisted edwards basepoint should be on the curve
@@ -133,6 +133,25 @@ impl ExtensiblePoint {
         }
     }
 
+    /// Subtracts an AffineNielsPoint from an extensible point
+    /// Returns an extensible point
+    pub fn sub_affine_niels(&self, other: AffineNielsPoint) -> ExtensiblePoint {
+        let A = other.y_plus_x * (self.Y - self.X);
+        let B = other.y_minus_x * (self.X + self.Y);
+        let C = other.td * self.T1 * self.T2;
+        let D = B + A;
+        let E = B - A;
+        let F = self.Z + C;
+        let G = self.Z - C;
+        ExtensiblePoint {
+            X: E * F,
+            Y: G * D,
+            Z: F * G,
+            T1: E,
+            T2: D,
+        }
+    }
+
     /// Adds an extensible point to a ProjectiveNiels point
     /// Returns an extensible point
     /// (3.1)[Last set of formulas] https://iacr.org/archive/asiacrypt2008/53500329/53500329.pdf
@@ -158,6 +177,26 @@ impl ExtensiblePoint {
         }
     }
 
+    /// Subtracts a projective Niels point from this point
+    pub fn sub_projective_niels(&self, other: &ProjectiveNielsPoint) -> ExtensiblePoint {
+        let Z = self.Z * other.Z;
+
+        let A = (self.Y - self.X) * other.Y_plus_X;
+        let B = (self.Y + self.X) * other.Y_minus_X;
+        let C = other.Td * self.T1 * self.T2;
+        let D = B + A;
+        let E = B - A;
+        let F = Z + C;
+        let G = Z - C;
+        ExtensiblePoint {
+            X: E * F,
+            Y: G * D,
+            Z: F * G,
+            T1: E,
+            T2: D,
+        }
+    }
+
     /// Converts an extensible point to an extended point
     pub fn to_extended(&self) -> ExtendedPoint {
         ExtendedPoint {
@@ -4,6 +4,10 @@
 pub mod affine;
 mod ops;
 pub mod points;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod table;
 
 pub use affine::AffinePoint;
 pub use points::{CompressedDecaf, DecafPoint};
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use table::DecafBasepointTable;
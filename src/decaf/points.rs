@@ -1,4 +1,12 @@
 use crate::constants::{BASEPOINT_ORDER, DECAF_BASEPOINT};
+use crate::curve::scalar_mul::{
+    double_base::vartime_double_base_mul, fixed_base::TWISTED_BASEPOINT_TABLE,
+    recommended_wnaf_window, vartime_variable_base,
+};
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::curve::scalar_mul::{straus, vartime_multiscalar_mul};
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::curve::twedwards::affine::AffinePoint as InnerAffinePoint;
 use crate::curve::twedwards::extended::ExtendedPoint;
 use crate::field::FieldElement;
 use crate::*;
@@ -11,12 +19,14 @@ use elliptic_curve::{
         typenum::{U56, U84},
         GenericArray,
     },
-    group::{cofactor::CofactorGroup, prime::PrimeGroup, Curve, GroupEncoding},
+    group::{cofactor::CofactorGroup, prime::PrimeGroup, Curve, GroupEncoding, WnafGroup},
     hash2curve::{ExpandMsg, Expander, FromOkm},
     ops::{LinearCombination, MulByGenerator},
     Group,
 };
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+use core::borrow::Borrow;
 use core::fmt::{Display, Formatter, LowerHex, Result as FmtResult, UpperHex};
 use rand_core::{CryptoRngCore, RngCore};
 use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
@@ -197,6 +207,12 @@ impl Group for DecafPoint {
     }
 }
 
+impl WnafGroup for DecafPoint {
+    fn recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
+        recommended_wnaf_window(num_scalars)
+    }
+}
+
 impl GroupEncoding for DecafPoint {
     type Repr = DecafPointRepr;
 
@@ -233,13 +249,38 @@ impl CofactorGroup for DecafPoint {
 
 impl PrimeGroup for DecafPoint {}
 
-impl MulByGenerator for DecafPoint {}
+impl MulByGenerator for DecafPoint {
+    fn mul_by_generator(scalar: &Scalar) -> Self {
+        DecafPoint(TWISTED_BASEPOINT_TABLE.mul(scalar))
+    }
+}
 
-impl LinearCombination for DecafPoint {}
+impl LinearCombination for DecafPoint {
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    fn lincomb(x: &Self, k: &Scalar, y: &Self, l: &Scalar) -> Self {
+        Self::multiscalar_mul([k, l], [x, y])
+    }
+}
 
 impl Curve for DecafPoint {
     type AffineRepr = DecafAffinePoint;
 
+    /// Normalizes a batch of points with a single field inversion, using Montgomery's trick
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    fn batch_normalize(p: &[Self], q: &mut [DecafAffinePoint]) {
+        assert_eq!(p.len(), q.len());
+
+        let mut z_inverses: Vec<FieldElement> = p.iter().map(|point| point.0.Z).collect();
+        FieldElement::batch_invert(&mut z_inverses);
+
+        for ((point, z_inverse), affine) in p.iter().zip(z_inverses).zip(q.iter_mut()) {
+            *affine = DecafAffinePoint(InnerAffinePoint {
+                x: point.0.X * z_inverse,
+                y: point.0.Y * z_inverse,
+            });
+        }
+    }
+
     fn to_affine(&self) -> Self::AffineRepr {
         DecafAffinePoint(self.0.to_affine())
     }
@@ -317,16 +358,113 @@ impl DecafPoint {
         DecafPoint(self.0.to_extensible().sub_extended(&other.0).to_extended())
     }
 
+    /// Compute `scalar * self` in variable time, with width-5 wNAF.
+    ///
+    /// This must only be used with public inputs.
+    pub fn mul_vartime(&self, scalar: &Scalar) -> Self {
+        DecafPoint(vartime_variable_base(&self.0, scalar))
+    }
+
+    /// Compute `a * A + b * B` in variable time, where `B` is the generator.
+    ///
+    /// This must only be used with public inputs, such as when verifying signatures.
+    pub fn vartime_double_scalar_mul_basepoint(a: &Scalar, A: &DecafPoint, b: &Scalar) -> Self {
+        DecafPoint(vartime_double_base_mul(a, &A.0, b))
+    }
+
+    /// Compute `sum(scalars[i] * points[i])` in constant time.
+    ///
+    /// The two iterators are zipped, so any extra items in the longer one are ignored.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn multiscalar_mul<I, J>(scalars: I, points: J) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<DecafPoint>,
+    {
+        let (scalars, points) = Self::unzip_terms(scalars, points);
+        DecafPoint(straus::multiscalar_mul(&scalars, &points))
+    }
+
+    /// Compute `sum(scalars[i] * points[i])` in variable time.
+    ///
+    /// This must only be used with public inputs. The two iterators are zipped, so
+    /// any extra items in the longer one are ignored.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn vartime_multiscalar_mul<I, J>(scalars: I, points: J) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<DecafPoint>,
+    {
+        let (scalars, points) = Self::unzip_terms(scalars, points);
+        DecafPoint(vartime_multiscalar_mul(&scalars, &points))
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    fn unzip_terms<I, J>(scalars: I, points: J) -> (Vec<Scalar>, Vec<ExtendedPoint>)
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<DecafPoint>,
+    {
+        scalars
+            .into_iter()
+            .zip(points)
+            .map(|(scalar, point)| (*scalar.borrow(), point.borrow().0))
+            .unzip()
+    }
+
     /// Compress this point
     pub fn compress(&self) -> CompressedDecaf {
         let X = self.0.X;
-        // let Y = self.0.Y;
-        let Z = self.0.Z;
         let T = self.0.T;
 
         let XX_TT = (X + T) * (X - T);
 
         let (isr, _) = (X.square() * XX_TT * FieldElement::NEG_EDWARDS_D).inverse_square_root();
+        self.compress_with_isr(isr)
+    }
+
+    /// Encodes `[2]P` for each point `P`, with a single field inversion for the whole batch.
+    ///
+    /// Encoding a point needs an inverse square root, which cannot be batched. The one
+    /// for a doubled point has a rational expression in the coordinates of the original
+    /// point though, so encoding `[2]P` only needs inversions, and those can be batched.
+    /// Protocols that want to batch encodings can keep their points halved.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn double_and_compress_batch(points: &[DecafPoint]) -> Vec<CompressedDecaf> {
+        // The inverse square root for [2]P is 1 / (8 d X^3 Y^3 (2 Z^2 - Y^2 + X^2))
+        let eight_d = FieldElement::EDWARDS_D.double().double().double();
+        let mut isrs: Vec<FieldElement> = points
+            .iter()
+            .map(|point| {
+                let (X, Y, Z) = (point.0.X, point.0.Y, point.0.Z);
+                let XY = X * Y;
+                let (XX, YY) = (X.square(), Y.square());
+                XY.square() * XY * (Z.square().double() - YY + XX) * eight_d
+            })
+            .collect();
+        FieldElement::batch_invert(&mut isrs);
+
+        points
+            .iter()
+            .zip(isrs)
+            .map(|(point, isr)| DecafPoint(point.0.double()).compress_with_isr(isr))
+            .collect()
+    }
+
+    /// Encodes this point, given the inverse square root of `-d X^2 (X^2 - T^2)`
+    fn compress_with_isr(&self, isr: FieldElement) -> CompressedDecaf {
+        let X = self.0.X;
+        let Z = self.0.Z;
+        let T = self.0.T;
+
+        let XX_TT = (X + T) * (X - T);
+
         let mut ratio = isr * XX_TT;
         let altx = ratio * FieldElement::DECAF_FACTOR; // Sign choice
         ratio.conditional_negate(altx.is_negative());
@@ -811,4 +949,131 @@ mod test {
             assert_eq!(rhs, expected);
         }
     }
+
+    #[test]
+    fn test_mul_by_generator() {
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([5u8; 32]);
+        for scalar in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::random(&mut rng),
+        ] {
+            assert_eq!(
+                DecafPoint::mul_by_generator(&scalar),
+                DecafPoint::GENERATOR * scalar
+            );
+        }
+    }
+
+    #[test]
+    fn test_vartime_double_scalar_mul_basepoint() {
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([7u8; 32]);
+        let A = DecafPoint::GENERATOR * Scalar::random(&mut rng);
+        let scalars = [
+            (Scalar::ZERO, Scalar::ZERO),
+            (Scalar::from(3u8), -Scalar::ONE),
+            (Scalar::random(&mut rng), Scalar::random(&mut rng)),
+            (Scalar::random(&mut rng), Scalar::random(&mut rng)),
+        ];
+
+        for (a, b) in scalars.iter() {
+            assert_eq!(
+                DecafPoint::vartime_double_scalar_mul_basepoint(a, &A, b),
+                A * a + DecafPoint::GENERATOR * b
+            );
+        }
+    }
+
+    #[test]
+    fn test_multiscalar_mul() {
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([11u8; 32]);
+        let scalars: Vec<Scalar> = (0..5).map(|_| Scalar::random(&mut rng)).collect();
+        let points: Vec<DecafPoint> = (0..5)
+            .map(|_| DecafPoint::GENERATOR * Scalar::random(&mut rng))
+            .collect();
+
+        let expected = scalars
+            .iter()
+            .zip(points.iter())
+            .fold(DecafPoint::IDENTITY, |acc, (s, p)| acc + p * s);
+        assert_eq!(DecafPoint::multiscalar_mul(&scalars, &points), expected);
+        assert_eq!(
+            DecafPoint::vartime_multiscalar_mul(&scalars, &points),
+            expected
+        );
+        assert_eq!(
+            DecafPoint::lincomb(&points[0], &scalars[0], &points[1], &scalars[1]),
+            points[0] * scalars[0] + points[1] * scalars[1]
+        );
+    }
+
+    #[test]
+    fn test_batch_normalize() {
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([12u8; 32]);
+        let mut points: Vec<DecafPoint> = (0..5)
+            .map(|_| DecafPoint::GENERATOR * Scalar::random(&mut rng))
+            .collect();
+        points.push(DecafPoint::IDENTITY);
+
+        let mut affine = vec![DecafAffinePoint::IDENTITY; points.len()];
+        DecafPoint::batch_normalize(&points, &mut affine);
+        for (point, affine) in points.iter().zip(affine) {
+            assert_eq!(affine, point.to_affine());
+        }
+    }
+
+    #[test]
+    fn test_double_and_compress_batch() {
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([13u8; 32]);
+        let mut points: Vec<DecafPoint> = (0..5)
+            .map(|_| DecafPoint::GENERATOR * Scalar::random(&mut rng))
+            .collect();
+        points.push(DecafPoint::IDENTITY);
+        // The same point with a different representative
+        let two_torsion = ExtendedPoint {
+            X: FieldElement::ZERO,
+            Y: FieldElement::MINUS_ONE,
+            Z: FieldElement::ONE,
+            T: FieldElement::ZERO,
+        };
+        points.push(DecafPoint(points[0].0.add(&two_torsion)));
+
+        let compressed = DecafPoint::double_and_compress_batch(&points);
+        assert_eq!(compressed.len(), points.len());
+        for (point, compressed) in points.iter().zip(compressed) {
+            assert_eq!(compressed, point.double().compress());
+        }
+    }
+
+    #[test]
+    fn test_mul_vartime_and_wnaf() {
+        use elliptic_curve::group::Wnaf;
+        use rand_core::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([16u8; 32]);
+        let points: Vec<DecafPoint> = (0..3)
+            .map(|_| DecafPoint::GENERATOR * Scalar::random(&mut rng))
+            .collect();
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, Scalar::from(3u8), -Scalar::ONE];
+        scalars.extend((0..4).map(|_| Scalar::random(&mut rng)));
+
+        for point in points.iter() {
+            for scalar in scalars.iter() {
+                let expected = point * scalar;
+                assert_eq!(point.mul_vartime(scalar), expected);
+                assert_eq!(Wnaf::new().scalar(scalar).base(*point), expected);
+            }
+        }
+    }
 }
@@ -0,0 +1,115 @@
+use core::ops::Mul;
+
+use crate::curve::scalar_mul::fixed_base::WindowTable;
+use crate::decaf::points::DecafPoint;
+use crate::field::Scalar;
+
+/// A precomputed table of multiples of a fixed point, for repeated multiplication
+/// by secret scalars in constant time.
+///
+/// Each row holds `N` multiples for a window of `log2(N) + 1` bits, where `N` is one of
+/// 8, 16, 32, 64 or 128. Larger windows make the table bigger and multiplication faster.
+#[derive(Clone)]
+pub struct DecafBasepointTable<const N: usize = 16>(WindowTable<N>);
+
+impl<const N: usize> DecafBasepointTable<N> {
+    /// Create a table of multiples of `point`
+    pub fn new(point: &DecafPoint) -> Self {
+        Self(WindowTable::create(&point.0))
+    }
+
+    /// The point this table was created for
+    pub fn basepoint(&self) -> DecafPoint {
+        DecafPoint(self.0.basepoint())
+    }
+
+    /// Compute `scalar * P` in constant time
+    pub fn mul_base(&self, scalar: &Scalar) -> DecafPoint {
+        DecafPoint(self.0.mul(scalar))
+    }
+}
+
+impl<const N: usize> From<&DecafPoint> for DecafBasepointTable<N> {
+    fn from(point: &DecafPoint) -> Self {
+        Self::new(point)
+    }
+}
+
+impl<const N: usize> Mul<&Scalar> for &DecafBasepointTable<N> {
+    type Output = DecafPoint;
+
+    fn mul(self, scalar: &Scalar) -> DecafPoint {
+        self.mul_base(scalar)
+    }
+}
+
+impl<const N: usize> Mul<&DecafBasepointTable<N>> for &Scalar {
+    type Output = DecafPoint;
+
+    fn mul(self, table: &DecafBasepointTable<N>) -> DecafPoint {
+        table.mul_base(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serdect::serde::Serialize for DecafBasepointTable<N> {
+    fn serialize<S: serdect::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serdect::slice::serialize_hex_lower_or_bin(&self.0.to_bytes(), s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serdect::serde::Deserialize<'de> for DecafBasepointTable<N> {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        let bytes = serdect::slice::deserialize_hex_or_bin_vec(d)?;
+        WindowTable::from_bytes(&bytes)
+            .map(Self)
+            .ok_or_else(|| serdect::serde::de::Error::custom("invalid table"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::SeedableRng;
+
+    fn check_table<const N: usize>(point: &DecafPoint, scalars: &[Scalar]) {
+        let table = DecafBasepointTable::<N>::new(point);
+        assert_eq!(table.basepoint(), *point);
+        for scalar in scalars.iter() {
+            let expected = point * scalar;
+            assert_eq!(&table * scalar, expected);
+            assert_eq!(scalar * &table, expected);
+        }
+    }
+
+    #[test]
+    fn test_table_mul() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([17u8; 32]);
+        let point = DecafPoint::GENERATOR * Scalar::random(&mut rng);
+
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, Scalar::from(7u8), -Scalar::ONE];
+        scalars.extend((0..4).map(|_| Scalar::random(&mut rng)));
+
+        check_table::<8>(&point, &scalars);
+        check_table::<16>(&point, &scalars);
+        check_table::<64>(&point, &scalars);
+        check_table::<16>(&DecafPoint::IDENTITY, &scalars);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_table_serialization() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([18u8; 32]);
+        let point = DecafPoint::GENERATOR * Scalar::random(&mut rng);
+        let scalar = Scalar::random(&mut rng);
+        let table = DecafBasepointTable::<8>::new(&point);
+
+        let json = serde_json::to_string(&table).unwrap();
+        let decoded = serde_json::from_str::<DecafBasepointTable<8>>(&json).unwrap();
+        assert_eq!(&decoded * &scalar, point * scalar);
+    }
+}
@@ -0,0 +1,162 @@
+//! Ed448 in DNSSEC, algorithm 16 of [RFC 8080](https://www.rfc-editor.org/rfc/rfc8080).
+//!
+//! A DNSKEY RDATA holds the flags, the protocol, the algorithm and the 57 byte
+//! public key. RRSIG signatures are pure Ed448 signatures with an empty context
+//! over the signing buffer of [RFC 4034 section 3.1.8.1](https://www.rfc-editor.org/rfc/rfc4034#section-3.1.8.1),
+//! i.e. the RRSIG RDATA without the signature followed by the canonical RRset.
+use crate::curve::CompressedEdwardsY;
+use crate::sign::{Signature, VerifyingKey, PUBLIC_KEY_LENGTH};
+
+/// The DNSSEC algorithm number of Ed448
+pub const DNSSEC_ALGORITHM_ED448: u8 = 16;
+
+/// The protocol field of every DNSKEY record
+const DNSKEY_PROTOCOL: u8 = 3;
+
+/// The length of an Ed448 DNSKEY RDATA
+pub const DNSKEY_RDATA_LENGTH: usize = 4 + PUBLIC_KEY_LENGTH;
+
+/// The offset of the algorithm field in an RRSIG RDATA
+const RRSIG_ALGORITHM_OFFSET: usize = 2;
+
+impl CompressedEdwardsY {
+    /// Decode the public key of an algorithm 16 DNSKEY RDATA.
+    ///
+    /// The flags are not interpreted, and the point is not decompressed.
+    pub fn from_dnskey_rdata(rdata: &[u8]) -> Result<Self, &'static str> {
+        if rdata.len() != DNSKEY_RDATA_LENGTH {
+            return Err("Invalid DNSKEY length");
+        }
+        if rdata[2] != DNSKEY_PROTOCOL {
+            return Err("Invalid DNSKEY protocol");
+        }
+        if rdata[3] != DNSSEC_ALGORITHM_ED448 {
+            return Err("Unsupported algorithm");
+        }
+        let mut bytes = [0u8; PUBLIC_KEY_LENGTH];
+        bytes.copy_from_slice(&rdata[4..]);
+        Ok(Self(bytes))
+    }
+
+    /// Encode this point as an algorithm 16 DNSKEY RDATA with `flags`
+    pub fn to_dnskey_rdata(&self, flags: u16) -> [u8; DNSKEY_RDATA_LENGTH] {
+        let mut rdata = [0u8; DNSKEY_RDATA_LENGTH];
+        rdata[..2].copy_from_slice(&flags.to_be_bytes());
+        rdata[2] = DNSKEY_PROTOCOL;
+        rdata[3] = DNSSEC_ALGORITHM_ED448;
+        rdata[4..].copy_from_slice(self.as_bytes());
+        rdata
+    }
+
+    /// The key tag of the DNSKEY with this public key and `flags`,
+    /// as computed in [RFC 4034 appendix B](https://www.rfc-editor.org/rfc/rfc4034#appendix-B)
+    pub fn dnskey_key_tag(&self, flags: u16) -> u16 {
+        let sum = self
+            .to_dnskey_rdata(flags)
+            .chunks(2)
+            .fold(0u32, |acc, pair| match *pair {
+                [hi, lo] => acc + u32::from(u16::from_be_bytes([hi, lo])),
+                [hi] => acc + (u32::from(hi) << 8),
+                _ => acc,
+            });
+        (sum + (sum >> 16)) as u16
+    }
+}
+
+impl VerifyingKey {
+    /// Verify an RRSIG `signature` over `signing_buffer`.
+    ///
+    /// The signing buffer has to start with the RRSIG RDATA of an algorithm 16
+    /// signature. Checking the signer name, key tag and validity period is left
+    /// to the caller.
+    pub fn verify_rrsig(
+        &self,
+        signing_buffer: &[u8],
+        signature: &[u8],
+    ) -> Result<(), &'static str> {
+        if signing_buffer.get(RRSIG_ALGORITHM_OFFSET) != Some(&DNSSEC_ALGORITHM_ED448) {
+            return Err("Unsupported algorithm");
+        }
+        let signature = Signature::try_from(signature).map_err(|_| "Invalid signature")?;
+        self.verify(signing_buffer, &signature)
+            .map_err(|_| "Invalid signature")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    // The example of RFC 8080 section 6.2:
+    //
+    // example.com. 3600 IN DNSKEY 257 3 16 (
+    //              3kgROaDjrh0H2iuixWBrc8g2EpBBLCdGzHmn+G2MpTPhpj/OiBVHHSfPodx1FYYUcJKm1MDpJtIA )
+    // ;{id = 9713 (ksk), size = 456b}
+    //
+    // example.com. 3600 IN MX 10 mail.example.com.
+    //
+    // example.com. 3600 IN RRSIG MX 16 2 3600 (
+    //              1440021600 1438207200 9713 example.com. (
+    //              3cPAHkmlnxcDHMyg7vFC34l0blBhuG1qpwLmjInI8w1CMB29FkEAIJUA0amx
+    //              WndkmnBZ6SKiwZSAxGILn/NBtOXft0+Gj7FSvOKxE/07+4RQvE581N3Aj/Jt
+    //              IyaiYVdnYtyMWbSNyGEY2213WKsJlwEA )
+    const DNSKEY_RDATA: [u8; DNSKEY_RDATA_LENGTH] = hex!("01010310de481139a0e3ae1d07da2ba2c5606b73c8361290412c2746cc79a7f86d8ca533e1a63fce8815471d27cfa1dc751586147092a6d4c0e926d200");
+    const KEY_TAG: u16 = 9713;
+    const SIGNING_BUFFER: [u8; 74] = hex!("000f100200000e1055d4fc6055b94ce025f1076578616d706c6503636f6d00076578616d706c6503636f6d00000f000100000e100014000a046d61696c076578616d706c6503636f6d00");
+    const SIGNATURE: [u8; 114] = hex!("ddc3c01e49a59f17031ccca0eef142df89746e5061b86d6aa702e68c89c8f30d42301dbd164100209500d1a9b15a77649a7059e922a2c19480c4620b9ff341b4e5dfb74f868fb152bce2b113fd3bfb8450bc4e7cd4ddc08ff26d2326a261576762dc8c59b48dc86118db6d7758ab09970100");
+
+    #[test]
+    fn rfc8080_example() {
+        let compressed = CompressedEdwardsY::from_dnskey_rdata(&DNSKEY_RDATA).unwrap();
+        assert_eq!(compressed.to_dnskey_rdata(257), DNSKEY_RDATA);
+        assert_eq!(compressed.dnskey_key_tag(257), KEY_TAG);
+
+        let verifying_key = VerifyingKey::from_bytes(compressed.as_bytes()).unwrap();
+        assert!(verifying_key
+            .verify_rrsig(&SIGNING_BUFFER, &SIGNATURE)
+            .is_ok());
+
+        // The MX preference is covered by the signature
+        let mut tampered = SIGNING_BUFFER;
+        tampered[SIGNING_BUFFER.len() - 19] = 20;
+        assert_eq!(
+            verifying_key.verify_rrsig(&tampered, &SIGNATURE),
+            Err("Invalid signature")
+        );
+        assert_eq!(
+            verifying_key.verify_rrsig(&SIGNING_BUFFER, &SIGNATURE[1..]),
+            Err("Invalid signature")
+        );
+
+        // An RRSIG made with Ed25519
+        let mut ed25519 = SIGNING_BUFFER;
+        ed25519[2] = 15;
+        assert_eq!(
+            verifying_key.verify_rrsig(&ed25519, &SIGNATURE),
+            Err("Unsupported algorithm")
+        );
+    }
+
+    #[test]
+    fn dnskey_rdata_rejects_other_records() {
+        let mut rdata = DNSKEY_RDATA;
+        rdata[2] = 2;
+        assert_eq!(
+            CompressedEdwardsY::from_dnskey_rdata(&rdata),
+            Err("Invalid DNSKEY protocol")
+        );
+
+        let mut rdata = DNSKEY_RDATA;
+        rdata[3] = 15;
+        assert_eq!(
+            CompressedEdwardsY::from_dnskey_rdata(&rdata),
+            Err("Unsupported algorithm")
+        );
+
+        assert_eq!(
+            CompressedEdwardsY::from_dnskey_rdata(&DNSKEY_RDATA[..DNSKEY_RDATA_LENGTH - 1]),
+            Err("Invalid DNSKEY length")
+        );
+    }
+}
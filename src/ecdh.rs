@@ -0,0 +1,332 @@
+//! X448 Diffie-Hellman key agreement as specified in [RFC 7748](https://www.rfc-editor.org/rfc/rfc7748).
+//!
+//! An [`EphemeralSecret`] can only be used for a single key agreement, a
+//! [`ReusableSecret`] can be used for several within one session, and a
+//! [`StaticSecret`] can also be serialized and stored.
+//! Each of them produces a [`PublicKey`] to send to the peer, and combines
+//! the peer's [`PublicKey`] into a [`SharedSecret`].
+//!
+//! A peer can force the shared secret to zero by sending a low order point.
+//! Protocols that need both parties to contribute to the result should
+//! check [`SharedSecret::was_contributory`].
+use crate::curve::montgomery::{x448, MontgomeryPoint, X448_BASEPOINT_U};
+
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use rand_core::{CryptoRng, RngCore};
+use subtle::ConstantTimeEq;
+
+/// Length in bytes of X448 secrets, public keys and shared secrets
+pub const X448_KEY_LENGTH: usize = 56;
+
+/// An X448 public key
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey(pub(crate) MontgomeryPoint);
+
+impl From<[u8; X448_KEY_LENGTH]> for PublicKey {
+    fn from(bytes: [u8; X448_KEY_LENGTH]) -> Self {
+        Self(MontgomeryPoint(bytes))
+    }
+}
+
+impl From<MontgomeryPoint> for PublicKey {
+    fn from(point: MontgomeryPoint) -> Self {
+        Self(point)
+    }
+}
+
+impl From<PublicKey> for MontgomeryPoint {
+    fn from(key: PublicKey) -> Self {
+        key.0
+    }
+}
+
+impl From<&EphemeralSecret> for PublicKey {
+    fn from(secret: &EphemeralSecret) -> Self {
+        Self::from_secret(&secret.0)
+    }
+}
+
+impl From<&ReusableSecret> for PublicKey {
+    fn from(secret: &ReusableSecret) -> Self {
+        Self::from_secret(&secret.0)
+    }
+}
+
+impl From<&StaticSecret> for PublicKey {
+    fn from(secret: &StaticSecret) -> Self {
+        Self::from_secret(&secret.0)
+    }
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serdect::serde::Serialize for PublicKey {
+    fn serialize<S: serdect::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serdect::array::serialize_hex_lower_or_bin(self.as_bytes(), s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serdect::serde::Deserialize<'de> for PublicKey {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        let mut bytes = [0u8; X448_KEY_LENGTH];
+        serdect::array::deserialize_hex_or_bin(&mut bytes, d)?;
+        Ok(Self::from(bytes))
+    }
+}
+
+impl PublicKey {
+    fn from_secret(secret: &[u8; X448_KEY_LENGTH]) -> Self {
+        Self(MontgomeryPoint(x448(*secret, X448_BASEPOINT_U)))
+    }
+
+    /// The encoded public key
+    pub fn to_bytes(&self) -> [u8; X448_KEY_LENGTH] {
+        self.0 .0
+    }
+
+    /// The encoded public key
+    pub fn as_bytes(&self) -> &[u8; X448_KEY_LENGTH] {
+        self.0.as_bytes()
+    }
+}
+
+/// An X448 secret that can only be used for a single key agreement
+pub struct EphemeralSecret([u8; X448_KEY_LENGTH]);
+
+impl Debug for EphemeralSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("EphemeralSecret").finish_non_exhaustive()
+    }
+}
+
+impl EphemeralSecret {
+    /// Generate a new random secret
+    pub fn random_from_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; X448_KEY_LENGTH];
+        rng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Compute the shared secret with `their_public`, consuming this secret
+    pub fn diffie_hellman(self, their_public: &PublicKey) -> SharedSecret {
+        SharedSecret::new(&self.0, their_public)
+    }
+}
+
+/// An X448 secret that can be used for several key agreements
+///
+/// Unlike a [`StaticSecret`] it cannot be serialized, so it is not
+/// possible to accidentally store it.
+#[derive(Clone)]
+pub struct ReusableSecret([u8; X448_KEY_LENGTH]);
+
+impl Debug for ReusableSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ReusableSecret").finish_non_exhaustive()
+    }
+}
+
+impl ReusableSecret {
+    /// Generate a new random secret
+    pub fn random_from_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; X448_KEY_LENGTH];
+        rng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Compute the shared secret with `their_public`
+    pub fn diffie_hellman(&self, their_public: &PublicKey) -> SharedSecret {
+        SharedSecret::new(&self.0, their_public)
+    }
+}
+
+/// An X448 secret that can be used for any number of key agreements and stored
+#[derive(Clone)]
+pub struct StaticSecret([u8; X448_KEY_LENGTH]);
+
+impl Debug for StaticSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("StaticSecret").finish_non_exhaustive()
+    }
+}
+
+impl From<[u8; X448_KEY_LENGTH]> for StaticSecret {
+    fn from(bytes: [u8; X448_KEY_LENGTH]) -> Self {
+        Self(bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serdect::serde::Serialize for StaticSecret {
+    fn serialize<S: serdect::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serdect::array::serialize_hex_lower_or_bin(&self.0, s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serdect::serde::Deserialize<'de> for StaticSecret {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        let mut bytes = [0u8; X448_KEY_LENGTH];
+        serdect::array::deserialize_hex_or_bin(&mut bytes, d)?;
+        Ok(Self(bytes))
+    }
+}
+
+impl StaticSecret {
+    /// Generate a new random secret
+    pub fn random_from_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; X448_KEY_LENGTH];
+        rng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Compute the shared secret with `their_public`
+    pub fn diffie_hellman(&self, their_public: &PublicKey) -> SharedSecret {
+        SharedSecret::new(&self.0, their_public)
+    }
+
+    /// The bytes of this secret
+    pub fn to_bytes(&self) -> [u8; X448_KEY_LENGTH] {
+        self.0
+    }
+
+    /// The bytes of this secret
+    pub fn as_bytes(&self) -> &[u8; X448_KEY_LENGTH] {
+        &self.0
+    }
+}
+
+/// The result of an X448 key agreement.
+///
+/// This should be passed through a key derivation function before use.
+pub struct SharedSecret(MontgomeryPoint);
+
+impl Debug for SharedSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("SharedSecret").finish_non_exhaustive()
+    }
+}
+
+impl AsRef<[u8]> for SharedSecret {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl SharedSecret {
+    fn new(secret: &[u8; X448_KEY_LENGTH], their_public: &PublicKey) -> Self {
+        Self(MontgomeryPoint(x448(*secret, their_public.0 .0)))
+    }
+
+    /// The bytes of the shared secret
+    pub fn to_bytes(&self) -> [u8; X448_KEY_LENGTH] {
+        self.0 .0
+    }
+
+    /// The bytes of the shared secret
+    pub fn as_bytes(&self) -> &[u8; X448_KEY_LENGTH] {
+        self.0.as_bytes()
+    }
+
+    /// Returns false if the shared secret is all zeros, which happens exactly
+    /// when the peer's public key was a low order point.
+    ///
+    /// This check runs in constant time.
+    pub fn was_contributory(&self) -> bool {
+        !bool::from(self.0 .0.ct_eq(&[0u8; X448_KEY_LENGTH]))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+macro_rules! zeroize_on_drop {
+    ($($name:ident),+) => {
+        $(
+            impl Drop for $name {
+                fn drop(&mut self) {
+                    use zeroize::Zeroize;
+
+                    self.0.zeroize();
+                }
+            }
+
+            impl zeroize::ZeroizeOnDrop for $name {}
+        )+
+    };
+}
+
+#[cfg(feature = "zeroize")]
+zeroize_on_drop!(EphemeralSecret, ReusableSecret, StaticSecret, SharedSecret);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn rfc7748_diffie_hellman() {
+        // RFC 7748 section 6.2
+        let alice = StaticSecret::from(hex!("9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b"));
+        let bob = StaticSecret::from(hex!("1c306a7ac2a0e2e0990b294470cba339e6453772b075811d8fad0d1d6927c120bb5ee8972b0d3e21374c9c921b09d1b0366f10b65173992d"));
+
+        let alice_public = PublicKey::from(&alice);
+        let bob_public = PublicKey::from(&bob);
+        assert_eq!(alice_public.to_bytes(), hex!("9b08f7cc31b7e3e67d22d5aea121074a273bd2b83de09c63faa73d2c22c5d9bbc836647241d953d40c5b12da88120d53177f80e532c41fa0"));
+        assert_eq!(bob_public.to_bytes(), hex!("3eb7a829b0cd20f5bcfc0b599b6feccf6da4627107bdb0d4f345b43027d8b972fc3e34fb4232a13ca706dcb57aec3dae07bdc1c67bf33609"));
+
+        let expected = hex!("07fff4181ac6cc95ec1c16a94a0f74d12da232ce40a77552281d282bb60c0b56fd2464c335543936521c24403085d59a449a5037514a879d");
+        let alice_shared = alice.diffie_hellman(&bob_public);
+        let bob_shared = bob.diffie_hellman(&alice_public);
+        assert_eq!(alice_shared.to_bytes(), expected);
+        assert_eq!(bob_shared.to_bytes(), expected);
+        assert!(alice_shared.was_contributory());
+    }
+
+    #[test]
+    fn ephemeral_and_reusable_agree() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([5u8; 32]);
+
+        let alice = EphemeralSecret::random_from_rng(&mut rng);
+        let alice_public = PublicKey::from(&alice);
+        let bob = ReusableSecret::random_from_rng(&mut rng);
+        let bob_public = PublicKey::from(&bob);
+
+        let bob_shared = bob.diffie_hellman(&alice_public);
+        let alice_shared = alice.diffie_hellman(&bob_public);
+        assert_eq!(alice_shared.as_bytes(), bob_shared.as_bytes());
+    }
+
+    #[test]
+    fn low_order_public_keys_are_not_contributory() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([5u8; 32]);
+        let secret = StaticSecret::random_from_rng(&mut rng);
+
+        // Every encoding of 0, 1 and -1
+        let mut p = [0xffu8; X448_KEY_LENGTH];
+        p[28] = 0xfe;
+        let mut p_plus_one = [0xffu8; X448_KEY_LENGTH];
+        p_plus_one[..28].fill(0);
+        let mut one = [0u8; X448_KEY_LENGTH];
+        one[0] = 1;
+        let mut minus_one = p;
+        minus_one[0] = 0xfe;
+
+        for u in [[0u8; X448_KEY_LENGTH], one, minus_one, p, p_plus_one] {
+            let public = PublicKey::from(u);
+            assert!(public.0.is_low_order());
+            assert!(!secret.diffie_hellman(&public).was_contributory());
+        }
+    }
+}
@@ -1,4 +1,6 @@
+mod backend;
 mod element;
+mod safegcd;
 mod scalar;
 
 pub(crate) use element::*;
@@ -7,14 +9,28 @@ pub use scalar::{Scalar, ScalarBytes, WideScalarBytes, MODULUS_LIMBS, ORDER, WID
 use crate::curve::edwards::EdwardsPoint;
 use crate::curve::twedwards::extended::ExtendedPoint as TwExtendedPoint;
 
-use elliptic_curve::bigint::{
-    impl_modulus,
-    modular::constant_mod::{Residue, ResidueParams},
-    U448,
-};
+use elliptic_curve::bigint::{impl_modulus, U448};
 
 impl_modulus!(MODULUS, U448, "fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
-pub(crate) type ResidueType = Residue<MODULUS, { MODULUS::LIMBS }>;
+
+/// The representation behind [`FieldElement`], chosen by the target.
+///
+/// Tests built with `--cfg residue_backend` use the generic Montgomery arithmetic
+/// of `crypto-bigint` instead, so the whole suite can be checked against it.
+#[cfg(all(
+    not(all(test, residue_backend)),
+    target_pointer_width = "64",
+    not(feature = "u32_backend")
+))]
+pub(crate) type ResidueType = backend::u64::FieldElement56;
+#[cfg(all(
+    not(all(test, residue_backend)),
+    any(feature = "u32_backend", not(target_pointer_width = "64"))
+))]
+pub(crate) type ResidueType = backend::u32::FieldElement28;
+#[cfg(all(test, residue_backend))]
+pub(crate) type ResidueType =
+    elliptic_curve::bigint::modular::constant_mod::Residue<MODULUS, { U448::LIMBS }>;
 
 pub const GOLDILOCKS_BASE_POINT: EdwardsPoint = EdwardsPoint {
     X: FieldElement(ResidueType::new(&U448::from_be_hex("4f1970c66bed0ded221d15a622bf36da9e146570470f1767ea6de324a3d3a46412ae1af72ab66511433b80e18b00938e2626a82bc70cc05e"))),
@@ -0,0 +1,82 @@
+//! Native field arithmetic that makes use of the shape of the Goldilocks prime.
+//!
+//! The 64-bit backend is used on 64-bit targets, and the 32-bit backend everywhere
+//! else or when the `u32_backend` feature is enabled. Both are always built for tests.
+
+/// Differential tests of a backend against the generic Montgomery arithmetic of
+/// `crypto-bigint`, given the field element type and the bound every limb stays below
+#[cfg(test)]
+macro_rules! backend_tests {
+    ($field:ident, $limb_bound:expr) => {
+        mod tests {
+            use super::*;
+            use crate::field::MODULUS;
+            use elliptic_curve::bigint::modular::constant_mod::{Residue, ResidueParams};
+            use rand_core::{RngCore, SeedableRng};
+
+            type Reference = Residue<MODULUS, { MODULUS::LIMBS }>;
+
+            fn inputs() -> Vec<U448> {
+                let mut rng = rand_chacha::ChaCha8Rng::from_seed([12u8; 32]);
+                let mut values = vec![
+                    U448::ZERO,
+                    U448::ONE,
+                    MODULUS::MODULUS.wrapping_sub(&U448::ONE),
+                    MODULUS::MODULUS,
+                    MODULUS::MODULUS.wrapping_add(&U448::ONE),
+                    U448::MAX,
+                    U448::ONE.shl_vartime(224),
+                    U448::ONE.shl_vartime(224).wrapping_sub(&U448::ONE),
+                ];
+                for _ in 0..24 {
+                    let mut bytes = [0u8; 56];
+                    rng.fill_bytes(&mut bytes);
+                    values.push(U448::from_le_slice(&bytes));
+                }
+                values
+            }
+
+            #[test]
+            fn matches_reference_backend() {
+                let inputs = inputs();
+                for x in inputs.iter() {
+                    let a = $field::new(x);
+                    let ra = Reference::new(x);
+                    assert_eq!(a.retrieve(), ra.retrieve());
+                    assert_eq!(a.neg().retrieve(), ra.neg().retrieve());
+                    assert_eq!(a.square().retrieve(), ra.square().retrieve());
+
+                    for y in inputs.iter() {
+                        let b = $field::new(y);
+                        let rb = Reference::new(y);
+                        assert_eq!(a.add(&b).retrieve(), ra.add(&rb).retrieve());
+                        assert_eq!(a.sub(&b).retrieve(), ra.sub(&rb).retrieve());
+                        assert_eq!(a.mul(&b).retrieve(), ra.mul(&rb).retrieve());
+                        assert_eq!(bool::from(a.ct_eq(&b)), ra == rb);
+                    }
+                }
+            }
+
+            #[test]
+            fn lazy_reduction_stays_in_bounds() {
+                // Long chains of operations without a strong reduction in between
+                let inputs = inputs();
+                let mut a = $field::new(&U448::MAX);
+                let mut ra = Reference::new(&U448::MAX);
+                for x in inputs.iter().cycle().take(500) {
+                    let b = $field::new(x);
+                    let rb = Reference::new(x);
+                    a = a.sub(&b).add(&a).mul(&b.neg()).add(&a);
+                    ra = ra.sub(&rb).add(&ra).mul(&rb.neg()).add(&ra);
+                    assert!(a.0.iter().all(|limb| *limb < $limb_bound));
+                }
+                assert_eq!(a.retrieve(), ra.retrieve());
+            }
+        }
+    };
+}
+
+#[cfg(any(test, feature = "u32_backend", not(target_pointer_width = "64")))]
+pub(crate) mod u32;
+#[cfg(any(test, all(target_pointer_width = "64", not(feature = "u32_backend"))))]
+pub(crate) mod u64;
@@ -0,0 +1,227 @@
+//! Field arithmetic with sixteen 28-bit limbs, for targets with a 32-bit multiplier.
+//!
+//! This follows the `arch_32` code from libdecaf, and uses the same Karatsuba split
+//! over `φ = 2^224` as the 64-bit backend, with eight limbs in each half.
+#![allow(clippy::needless_range_loop)]
+
+use elliptic_curve::bigint::{Limb, U448};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+const MASK: u32 = (1 << 28) - 1;
+
+/// The limbs of `p`
+const P: [u32; 16] = [
+    MASK,
+    MASK,
+    MASK,
+    MASK,
+    MASK,
+    MASK,
+    MASK,
+    MASK,
+    MASK - 1,
+    MASK,
+    MASK,
+    MASK,
+    MASK,
+    MASK,
+    MASK,
+    MASK,
+];
+
+/// An element of the field modulo `p = 2^448 - 2^224 - 1`.
+///
+/// The limbs are only weakly reduced: each one is a little over 28 bits at most,
+/// and the value they represent may exceed `p`.
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct FieldElement28(pub(crate) [u32; 16]);
+
+impl ConstantTimeEq for FieldElement28 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.strong_reduce().0.ct_eq(&other.strong_reduce().0)
+    }
+}
+
+impl ConditionallySelectable for FieldElement28 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut limbs = [0u32; 16];
+        for i in 0..16 {
+            limbs[i] = u32::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        Self(limbs)
+    }
+}
+
+impl FieldElement28 {
+    /// Convert an integer below `2^448` into a field element
+    pub(crate) const fn new(value: &U448) -> Self {
+        let words = value.as_words();
+        let mut limbs = [0u32; 16];
+        // Every 7 bytes make up two limbs
+        let mut i = 0;
+        while i < 8 {
+            let mut pair = 0u64;
+            let mut j = 0;
+            while j < 7 {
+                let k = 7 * i + j;
+                let byte = (words[k / Limb::BYTES] >> (8 * (k % Limb::BYTES))) as u8;
+                pair |= (byte as u64) << (8 * j);
+                j += 1;
+            }
+            limbs[2 * i] = (pair as u32) & MASK;
+            limbs[2 * i + 1] = (pair >> 28) as u32;
+            i += 1;
+        }
+        Self(limbs)
+    }
+
+    /// Return the canonical integer for this field element
+    pub(crate) fn retrieve(&self) -> U448 {
+        let limbs = self.strong_reduce().0;
+        let mut bytes = [0u8; 56];
+        for (i, chunk) in bytes.chunks_mut(7).enumerate() {
+            let pair = (limbs[2 * i] as u64) | ((limbs[2 * i + 1] as u64) << 28);
+            for (j, byte) in chunk.iter_mut().enumerate() {
+                *byte = (pair >> (8 * j)) as u8;
+            }
+        }
+        U448::from_le_slice(&bytes)
+    }
+
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        let mut limbs = [0u32; 16];
+        for i in 0..16 {
+            limbs[i] = self.0[i] + other.0[i];
+        }
+        Self(limbs).weak_reduce()
+    }
+
+    pub(crate) fn sub(&self, other: &Self) -> Self {
+        // Add 2p first so that no limb can underflow
+        let mut limbs = [0u32; 16];
+        for i in 0..16 {
+            limbs[i] = self.0[i] + 2 * P[i] - other.0[i];
+        }
+        Self(limbs).weak_reduce()
+    }
+
+    pub(crate) fn neg(&self) -> Self {
+        Self([0; 16]).sub(self)
+    }
+
+    pub(crate) fn mul(&self, other: &Self) -> Self {
+        let a = &self.0;
+        let b = &other.0;
+
+        let mut a0 = [0u32; 8];
+        let mut a1 = [0u32; 8];
+        let mut b0 = [0u32; 8];
+        let mut b1 = [0u32; 8];
+        let mut aa = [0u32; 8];
+        let mut bb = [0u32; 8];
+        for i in 0..8 {
+            a0[i] = a[i];
+            a1[i] = a[i + 8];
+            b0[i] = b[i];
+            b1[i] = b[i + 8];
+            aa[i] = a[i] + a[i + 8];
+            bb[i] = b[i] + b[i + 8];
+        }
+
+        // The products of the halves, as polynomials in 2^28 with 15 coefficients
+        let lo = mul_8x8(&a0, &b0);
+        let hi = mul_8x8(&a1, &b1);
+        let mid = mul_8x8(&aa, &bb);
+
+        // Coefficients 8 to 14 of each product carry a factor of φ, and φ^2 = φ + 1
+        let mut wide = [0u64; 16];
+        for i in 0..8 {
+            let (lo_high, hi_high, mid_high) = if i < 7 {
+                (lo[i + 8], hi[i + 8], mid[i + 8])
+            } else {
+                (0, 0, 0)
+            };
+            wide[i] = lo[i] + hi[i] + mid_high - lo_high;
+            wide[i + 8] = hi_high + mid[i] + mid_high - lo[i];
+        }
+
+        Self::reduce_wide(wide)
+    }
+
+    pub(crate) fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// Carry the wide coefficients of a product back down to 28 bits each
+    fn reduce_wide(wide: [u64; 16]) -> Self {
+        let mut limbs = [0u32; 16];
+        let mut carry = 0u64;
+        for i in 0..16 {
+            let value = wide[i] + carry;
+            limbs[i] = (value as u32) & MASK;
+            carry = value >> 28;
+        }
+
+        // The carry out of the top limb is folded back in at limbs 0 and 8, as
+        // 2^448 = 2^224 + 1. Limbs 1 and 9 absorb what little that carries over.
+        let low = limbs[0] as u64 + carry;
+        let high = limbs[8] as u64 + carry;
+        limbs[0] = (low as u32) & MASK;
+        limbs[1] += (low >> 28) as u32;
+        limbs[8] = (high as u32) & MASK;
+        limbs[9] += (high >> 28) as u32;
+
+        Self(limbs)
+    }
+
+    /// Bring every limb back to just over 28 bits
+    fn weak_reduce(self) -> Self {
+        let mut limbs = self.0;
+        let top_carry = limbs[15] >> 28;
+        limbs[8] += top_carry;
+        for i in (1..16).rev() {
+            limbs[i] = (limbs[i] & MASK) + (limbs[i - 1] >> 28);
+        }
+        limbs[0] = (limbs[0] & MASK) + top_carry;
+        Self(limbs)
+    }
+
+    /// Reduce to the canonical representative in `[0, p)`
+    fn strong_reduce(&self) -> Self {
+        let mut limbs = self.weak_reduce().0;
+
+        // The value is now below 2p, so subtract p once
+        let mut scarry = 0i64;
+        for i in 0..16 {
+            scarry += limbs[i] as i64 - P[i] as i64;
+            limbs[i] = (scarry as u32) & MASK;
+            scarry >>= 28;
+        }
+
+        // Add p back if that went negative
+        let add_back = scarry as u32;
+        let mut carry = 0u64;
+        for i in 0..16 {
+            carry += limbs[i] as u64 + (add_back & P[i]) as u64;
+            limbs[i] = (carry as u32) & MASK;
+            carry >>= 28;
+        }
+
+        Self(limbs)
+    }
+}
+
+/// Schoolbook product of two eight limb numbers
+#[inline(always)]
+fn mul_8x8(a: &[u32; 8], b: &[u32; 8]) -> [u64; 15] {
+    let mut out = [0u64; 15];
+    for i in 0..8 {
+        for j in 0..8 {
+            out[i + j] += (a[i] as u64) * (b[j] as u64);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+backend_tests!(FieldElement28, 1 << 29);
@@ -0,0 +1,192 @@
+//! Field arithmetic with eight 56-bit limbs, for targets with a 64-bit multiplier.
+//!
+//! This follows the `arch_ref64` code from libdecaf. With `φ = 2^224` the prime is
+//! `p = φ^2 - φ - 1`, so a product of `a0 + a1 φ` and `b0 + b1 φ` reduces to
+//! `(a0 b0 + a1 b1) + ((a0 + a1)(b0 + b1) - a0 b0) φ`, which only needs three
+//! products of four limbs each.
+#![allow(clippy::needless_range_loop)]
+
+use elliptic_curve::bigint::{Limb, U448};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+const MASK: u64 = (1 << 56) - 1;
+
+/// The limbs of `p`
+const P: [u64; 8] = [MASK, MASK, MASK, MASK, MASK - 1, MASK, MASK, MASK];
+
+/// An element of the field modulo `p = 2^448 - 2^224 - 1`.
+///
+/// The limbs are only weakly reduced: each one is a little over 56 bits at most,
+/// and the value they represent may exceed `p`.
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct FieldElement56(pub(crate) [u64; 8]);
+
+impl ConstantTimeEq for FieldElement56 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.strong_reduce().0.ct_eq(&other.strong_reduce().0)
+    }
+}
+
+impl ConditionallySelectable for FieldElement56 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut limbs = [0u64; 8];
+        for i in 0..8 {
+            limbs[i] = u64::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        Self(limbs)
+    }
+}
+
+impl FieldElement56 {
+    /// Convert an integer below `2^448` into a field element
+    pub(crate) const fn new(value: &U448) -> Self {
+        let words = value.as_words();
+        let mut limbs = [0u64; 8];
+        let mut i = 0;
+        while i < 56 {
+            let byte = (words[i / Limb::BYTES] >> (8 * (i % Limb::BYTES))) as u8;
+            limbs[i / 7] |= (byte as u64) << (8 * (i % 7));
+            i += 1;
+        }
+        Self(limbs)
+    }
+
+    /// Return the canonical integer for this field element
+    pub(crate) fn retrieve(&self) -> U448 {
+        let limbs = self.strong_reduce().0;
+        let mut bytes = [0u8; 56];
+        for i in 0..56 {
+            bytes[i] = (limbs[i / 7] >> (8 * (i % 7))) as u8;
+        }
+        U448::from_le_slice(&bytes)
+    }
+
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        let mut limbs = [0u64; 8];
+        for i in 0..8 {
+            limbs[i] = self.0[i] + other.0[i];
+        }
+        Self(limbs).weak_reduce()
+    }
+
+    pub(crate) fn sub(&self, other: &Self) -> Self {
+        // Add 2p first so that no limb can underflow
+        let mut limbs = [0u64; 8];
+        for i in 0..8 {
+            limbs[i] = self.0[i] + 2 * P[i] - other.0[i];
+        }
+        Self(limbs).weak_reduce()
+    }
+
+    pub(crate) fn neg(&self) -> Self {
+        Self([0; 8]).sub(self)
+    }
+
+    pub(crate) fn mul(&self, other: &Self) -> Self {
+        let a = &self.0;
+        let b = &other.0;
+
+        let mut aa = [0u64; 4];
+        let mut bb = [0u64; 4];
+        for i in 0..4 {
+            aa[i] = a[i] + a[i + 4];
+            bb[i] = b[i] + b[i + 4];
+        }
+
+        // The products of the halves, as polynomials in 2^56 with 7 coefficients
+        let lo = mul_4x4(&[a[0], a[1], a[2], a[3]], &[b[0], b[1], b[2], b[3]]);
+        let hi = mul_4x4(&[a[4], a[5], a[6], a[7]], &[b[4], b[5], b[6], b[7]]);
+        let mid = mul_4x4(&aa, &bb);
+
+        // Coefficients 4 to 6 of each product carry a factor of φ, and φ^2 = φ + 1
+        let mut wide = [0u128; 8];
+        for i in 0..4 {
+            let (lo_high, hi_high, mid_high) = if i < 3 {
+                (lo[i + 4], hi[i + 4], mid[i + 4])
+            } else {
+                (0, 0, 0)
+            };
+            wide[i] = lo[i] + hi[i] + mid_high - lo_high;
+            wide[i + 4] = hi_high + mid[i] + mid_high - lo[i];
+        }
+
+        Self::reduce_wide(wide)
+    }
+
+    pub(crate) fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// Carry the wide coefficients of a product back down to 56 bits each
+    fn reduce_wide(wide: [u128; 8]) -> Self {
+        let mut limbs = [0u64; 8];
+        let mut carry = 0u128;
+        for i in 0..8 {
+            let value = wide[i] + carry;
+            limbs[i] = (value as u64) & MASK;
+            carry = value >> 56;
+        }
+
+        // The carry out of the top limb is folded back in at limbs 0 and 4, as
+        // 2^448 = 2^224 + 1. Limbs 1 and 5 absorb what little that carries over.
+        let low = limbs[0] as u128 + carry;
+        let high = limbs[4] as u128 + carry;
+        limbs[0] = (low as u64) & MASK;
+        limbs[1] += (low >> 56) as u64;
+        limbs[4] = (high as u64) & MASK;
+        limbs[5] += (high >> 56) as u64;
+
+        Self(limbs)
+    }
+
+    /// Bring every limb back to just over 56 bits
+    fn weak_reduce(self) -> Self {
+        let mut limbs = self.0;
+        let top_carry = limbs[7] >> 56;
+        limbs[4] += top_carry;
+        for i in (1..8).rev() {
+            limbs[i] = (limbs[i] & MASK) + (limbs[i - 1] >> 56);
+        }
+        limbs[0] = (limbs[0] & MASK) + top_carry;
+        Self(limbs)
+    }
+
+    /// Reduce to the canonical representative in `[0, p)`
+    fn strong_reduce(&self) -> Self {
+        let mut limbs = self.weak_reduce().0;
+
+        // The value is now below 2p, so subtract p once
+        let mut scarry = 0i128;
+        for i in 0..8 {
+            scarry += limbs[i] as i128 - P[i] as i128;
+            limbs[i] = (scarry as u64) & MASK;
+            scarry >>= 56;
+        }
+
+        // Add p back if that went negative
+        let add_back = scarry as u64;
+        let mut carry = 0u128;
+        for i in 0..8 {
+            carry += limbs[i] as u128 + (add_back & P[i]) as u128;
+            limbs[i] = (carry as u64) & MASK;
+            carry >>= 56;
+        }
+
+        Self(limbs)
+    }
+}
+
+/// Schoolbook product of two four limb numbers
+#[inline(always)]
+fn mul_4x4(a: &[u64; 4], b: &[u64; 4]) -> [u128; 7] {
+    let mut out = [0u128; 7];
+    for i in 0..4 {
+        for j in 0..4 {
+            out[i + j] += (a[i] as u128) * (b[j] as u128);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+backend_tests!(FieldElement56, 1 << 57);
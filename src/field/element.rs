@@ -4,6 +4,7 @@ use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use elliptic_curve::{
     bigint::{
         consts::{U84, U88},
+        modular::constant_mod::ResidueParams,
         Encoding, NonZero, U448, U704,
     },
     generic_array::GenericArray,
@@ -11,13 +12,19 @@ use elliptic_curve::{
 };
 use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
 #[cfg(feature = "zeroize")]
 use zeroize::DefaultIsZeroes;
 
-use super::ResidueType;
+use super::safegcd::Modulus;
+use super::{ResidueType, MODULUS};
 use crate::curve::twedwards::extended::ExtendedPoint as TwistedExtendedPoint;
 use crate::{AffinePoint, EdwardsPoint};
 
+/// The field modulus, prepared for inversion
+const INVERSION_MODULUS: Modulus = Modulus::new(&MODULUS::MODULUS);
+
 #[derive(Clone, Copy, Default)]
 pub(crate) struct FieldElement(pub(crate) ResidueType);
 
@@ -204,11 +211,36 @@ impl FieldElement {
         (bytes[0] & 1).into()
     }
 
-    /// Inverts a field element
-    /// Previous chain length: 462, new length 460
+    /// Inverts a field element in constant time, mapping zero to zero
     pub fn invert(&self) -> Self {
-        const INV_EXP: U448 = U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffffffffffffffffffffffffffffffffffffffffffffffffffffd");
-        Self(self.0.pow(&INV_EXP))
+        Self(ResidueType::new(
+            &INVERSION_MODULUS.invert(&self.0.retrieve()),
+        ))
+    }
+
+    /// Inverts every element in place with a single inversion, using Montgomery's trick.
+    ///
+    /// Like `invert`, this maps zero to zero, and it runs in constant time.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub(crate) fn batch_invert(elements: &mut [FieldElement]) {
+        // The products of all the non-zero elements before each one
+        let mut products = Vec::with_capacity(elements.len());
+        let mut product = FieldElement::ONE;
+        for element in elements.iter() {
+            products.push(product);
+            let is_zero = element.ct_eq(&FieldElement::ZERO);
+            product = FieldElement::conditional_select(&(product * element), &product, is_zero);
+        }
+
+        // Walk back, peeling one element at a time off the inverse of the product
+        let mut inverse = product.invert();
+        for (element, product) in elements.iter_mut().zip(products).rev() {
+            let is_zero = element.ct_eq(&FieldElement::ZERO);
+            let element_inverse = inverse * product;
+            inverse = FieldElement::conditional_select(&(inverse * *element), &inverse, is_zero);
+            *element =
+                FieldElement::conditional_select(&element_inverse, &FieldElement::ZERO, is_zero);
+        }
     }
 
     pub fn square(&self) -> Self {
@@ -230,13 +262,13 @@ impl FieldElement {
     }
 
     pub fn is_square(&self) -> Choice {
-        const IS_SQUARE_EXP: U448 = U448::from_le_hex("ffffffffffffffffffffffffffffffffffffffffffffffffffffff7fffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
-        self.0.pow(&IS_SQUARE_EXP).ct_eq(&FieldElement::ONE.0)
+        // x^((p - 1) / 2) = (x^((p - 3) / 4))^2 * x
+        (self.pow_p_minus_3_div_4().square() * self).ct_eq(&FieldElement::ONE)
     }
 
     pub fn sqrt(&self) -> FieldElement {
-        const SQRT_EXP: U448 = U448::from_be_hex("3fffffffffffffffffffffffffffffffffffffffffffffffffffffffc0000000000000000000000000000000000000000000000000000000");
-        Self(self.0.pow(&SQRT_EXP))
+        // x^((p + 1) / 4) = x^((p - 3) / 4) * x
+        self.pow_p_minus_3_div_4() * self
     }
 
     pub fn to_bytes(self) -> [u8; 56] {
@@ -257,6 +289,15 @@ impl FieldElement {
     /// Returns the result and a boolean to indicate whether self
     /// was a Quadratic residue
     pub(crate) fn inverse_square_root(&self) -> (FieldElement, Choice) {
+        let l1 = self.pow_p_minus_3_div_4();
+        let l0 = l1.square() * self;
+
+        let is_residue = l0.ct_eq(&FieldElement::ONE);
+        (l1, is_residue)
+    }
+
+    /// Computes `self^((p - 3) / 4)` with an addition chain
+    fn pow_p_minus_3_div_4(&self) -> FieldElement {
         let (mut l0, mut l1, mut l2);
 
         l1 = self.square();
@@ -282,12 +323,7 @@ impl FieldElement {
         l0 = l2.square();
         l1 = l0 * self;
         l0 = l1.square_n(223);
-        l1 = l2 * l0;
-        l2 = l1.square();
-        l0 = l2 * self;
-
-        let is_residue = l0.ct_eq(&FieldElement::ONE);
-        (l1, is_residue)
+        l2 * l0
     }
 
     /// Computes the square root ratio of two elements
@@ -308,15 +344,10 @@ impl FieldElement {
     /// if the input is non-square, the function returns a result with
     /// a defined relationship to the inputs.
     pub(crate) fn sqrt_ratio_i(u: &FieldElement, v: &FieldElement) -> (FieldElement, Choice) {
-        const P_MINUS_THREE_DIV_4: U448 = U448::from_be_hex("3fffffffffffffffffffffffffffffffffffffffffffffffffffffffbfffffffffffffffffffffffffffffffffffffffffffffffffffffff");
-        let u = u.0;
-        let v = v.0;
-
-        let r = u * (u * v).pow(&P_MINUS_THREE_DIV_4);
+        let mut r = u * (u * v).pow_p_minus_3_div_4();
         let check = v * r.square();
-        let was_square = check.ct_eq(&u);
+        let was_square = check.ct_eq(u);
 
-        let mut r = FieldElement(r);
         r.conditional_negate(r.is_negative());
         (r, was_square)
     }
@@ -464,4 +495,89 @@ mod tests {
         ]);
         assert_eq!(three, nine.sqrt());
     }
+
+    #[test]
+    fn addition_chains_match_exponentiation() {
+        use crate::field::MODULUS;
+        use elliptic_curve::bigint::modular::constant_mod::{Residue, ResidueParams};
+        use rand_core::{RngCore, SeedableRng};
+
+        type Reference = Residue<MODULUS, { MODULUS::LIMBS }>;
+        const INV_EXP: U448 = U448::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffffffffffffffffffffffffffffffffffffffffffffffffffffd");
+        const IS_SQUARE_EXP: U448 = U448::from_be_hex("7fffffffffffffffffffffffffffffffffffffffffffffffffffffff7fffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+        const SQRT_EXP: U448 = U448::from_be_hex("3fffffffffffffffffffffffffffffffffffffffffffffffffffffffc0000000000000000000000000000000000000000000000000000000");
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([13u8; 32]);
+        let mut values = vec![
+            FieldElement::ZERO,
+            FieldElement::ONE,
+            FieldElement::MINUS_ONE,
+            FieldElement::J,
+        ];
+        for _ in 0..16 {
+            let mut bytes = [0u8; 56];
+            rng.fill_bytes(&mut bytes);
+            values.push(FieldElement::from_bytes(&bytes));
+        }
+
+        for x in values.iter() {
+            let reference = Reference::new(&U448::from_le_slice(&x.to_bytes()));
+            let expected = |exp: &U448| reference.pow(exp).retrieve().to_le_bytes();
+
+            assert_eq!(x.invert().to_bytes(), expected(&INV_EXP));
+            assert_eq!(x.sqrt().to_bytes(), expected(&SQRT_EXP));
+            assert_eq!(
+                bool::from(x.is_square()),
+                expected(&IS_SQUARE_EXP) == FieldElement::ONE.to_bytes()
+            );
+        }
+    }
+
+    #[test]
+    fn invert_matches_exponentiation() {
+        use rand_core::{RngCore, SeedableRng};
+
+        // x^(p - 2) = (x^((p - 3) / 4))^4 * x, which is how `invert` used to work
+        let invert_by_exponentiation =
+            |x: &FieldElement| x.pow_p_minus_3_div_4().square().square() * x;
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([17u8; 32]);
+        let mut values = vec![
+            FieldElement::ZERO,
+            FieldElement::ONE,
+            FieldElement::MINUS_ONE,
+            FieldElement::from_bytes(&[0xff; 56]),
+        ];
+        for _ in 0..64 {
+            let mut bytes = [0u8; 56];
+            rng.fill_bytes(&mut bytes);
+            values.push(FieldElement::from_bytes(&bytes));
+        }
+
+        for x in values.iter() {
+            assert_eq!(x.invert(), invert_by_exponentiation(x));
+        }
+    }
+
+    #[test]
+    fn batch_invert_matches_invert() {
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([18u8; 32]);
+        let mut values = vec![FieldElement::ZERO, FieldElement::ONE];
+        for _ in 0..16 {
+            let mut bytes = [0u8; 56];
+            rng.fill_bytes(&mut bytes);
+            values.push(FieldElement::from_bytes(&bytes));
+        }
+        values.push(FieldElement::ZERO);
+
+        let mut inverses = values.clone();
+        FieldElement::batch_invert(&mut inverses);
+        for (x, inverse) in values.iter().zip(inverses.iter()) {
+            assert_eq!(*inverse, x.invert());
+        }
+
+        FieldElement::batch_invert(&mut []);
+    }
 }
@@ -0,0 +1,282 @@
+//! Constant-time modular inversion with the Bernstein–Yang "safegcd" algorithm.
+//!
+//! This follows the `modinv64` code from libsecp256k1: numbers are held in signed
+//! 62-bit limbs, and batches of 62 divsteps are computed on the low bits of `f` and `g`
+//! alone, then applied to the full numbers as a 2x2 transition matrix.
+//! See <https://gcd.cr.yp.to/papers.html#safegcd>.
+#![allow(clippy::needless_range_loop)]
+
+use elliptic_curve::bigint::{Limb, U448};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+/// The number of limbs, which leaves room for the sign and for `d` and `e` in `(-2M, M)`
+const LIMBS: usize = 8;
+
+const MASK: u64 = (1 << 62) - 1;
+
+/// Batches of 62 divsteps needed for 448-bit inputs.
+///
+/// With `f` and `g` below `2^448`, `⌊(49 * 448 + 80) / 17⌋ = 1296` divsteps are
+/// enough for `g` to reach zero (Theorem 11.2 of the paper), and 21 * 62 = 1302.
+const ITERATIONS: usize = 21;
+
+/// A signed number in 62-bit limbs, least significant first. The top limb carries the sign.
+type Signed62 = [i64; LIMBS];
+
+/// An odd modulus below `2^448`, prepared for inversion
+pub(crate) struct Modulus {
+    modulus: Signed62,
+    /// `modulus^-1 mod 2^62`
+    modulus_inv62: u64,
+}
+
+impl Modulus {
+    pub(crate) const fn new(modulus: &U448) -> Self {
+        let modulus = to_signed62(modulus);
+
+        // Newton's iteration doubles the number of correct low bits each time
+        let m = modulus[0] as u64;
+        let mut inverse = 1u64;
+        let mut i = 0;
+        while i < 6 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(m.wrapping_mul(inverse)));
+            i += 1;
+        }
+
+        Self {
+            modulus,
+            modulus_inv62: inverse & MASK,
+        }
+    }
+
+    /// Computes `value^-1` modulo this modulus in constant time, or zero if there is none.
+    ///
+    /// The value does not need to be reduced.
+    pub(crate) fn invert(&self, value: &U448) -> U448 {
+        let mut d = [0i64; LIMBS];
+        let mut e = [0i64; LIMBS];
+        e[0] = 1;
+        let mut f = self.modulus;
+        let mut g = to_signed62(value);
+        let mut delta = 1i64;
+
+        for _ in 0..ITERATIONS {
+            let (next_delta, t) = divsteps(delta, f[0] as u64, g[0] as u64);
+            delta = next_delta;
+            self.update_de(&mut d, &mut e, &t);
+            update_fg(&mut f, &mut g, &t);
+        }
+
+        // Now g = 0 and f = ±gcd, and d * value = f throughout
+        let sign = f[LIMBS - 1] >> 63;
+        let mut inverse = self.normalize(d, sign);
+
+        // The gcd is the modulus rather than one for multiples of the modulus
+        let mut one = [0i64; LIMBS];
+        one[0] = 1;
+        let mut minus_one = [MASK as i64; LIMBS];
+        minus_one[LIMBS - 1] = -1;
+        let invertible = f.ct_eq(&one) | f.ct_eq(&minus_one);
+        for limb in inverse.iter_mut() {
+            limb.conditional_assign(&0, !invertible);
+        }
+
+        from_signed62(&inverse)
+    }
+
+    /// Replaces `(d, e)` with `t (d, e) / 2^62`, modulo the modulus.
+    ///
+    /// Both stay in `(-2M, M)`.
+    fn update_de(&self, d: &mut Signed62, e: &mut Signed62, t: &Matrix) {
+        let Matrix { u, v, q, r } = *t;
+        let m = &self.modulus;
+
+        // Start with a multiple of the modulus that makes the result non-negative when
+        // d or e is negative, and then adjust it so that the bottom 62 bits cancel out
+        let sd = d[LIMBS - 1] >> 63;
+        let se = e[LIMBS - 1] >> 63;
+        let mut md = (u & sd) + (v & se);
+        let mut me = (q & sd) + (r & se);
+
+        let mut cd = u as i128 * d[0] as i128 + v as i128 * e[0] as i128;
+        let mut ce = q as i128 * d[0] as i128 + r as i128 * e[0] as i128;
+
+        md -= (self
+            .modulus_inv62
+            .wrapping_mul(cd as u64)
+            .wrapping_add(md as u64)
+            & MASK) as i64;
+        me -= (self
+            .modulus_inv62
+            .wrapping_mul(ce as u64)
+            .wrapping_add(me as u64)
+            & MASK) as i64;
+
+        cd += m[0] as i128 * md as i128;
+        ce += m[0] as i128 * me as i128;
+        debug_assert_eq!(cd as u64 & MASK, 0);
+        debug_assert_eq!(ce as u64 & MASK, 0);
+        cd >>= 62;
+        ce >>= 62;
+
+        for i in 1..LIMBS {
+            cd += u as i128 * d[i] as i128 + v as i128 * e[i] as i128 + m[i] as i128 * md as i128;
+            ce += q as i128 * d[i] as i128 + r as i128 * e[i] as i128 + m[i] as i128 * me as i128;
+            d[i - 1] = (cd as u64 & MASK) as i64;
+            e[i - 1] = (ce as u64 & MASK) as i64;
+            cd >>= 62;
+            ce >>= 62;
+        }
+        d[LIMBS - 1] = cd as i64;
+        e[LIMBS - 1] = ce as i64;
+    }
+
+    /// Takes `d` in `(-2M, M)` to `[0, M)`, negating it first if `sign` is all ones
+    fn normalize(&self, mut d: Signed62, sign: i64) -> Signed62 {
+        let m = &self.modulus;
+
+        // Into (-M, M)
+        let add = d[LIMBS - 1] >> 63;
+        for i in 0..LIMBS {
+            d[i] += m[i] & add;
+        }
+
+        // Negate, still in (-M, M)
+        for i in 0..LIMBS {
+            d[i] = (d[i] ^ sign) - sign;
+        }
+        carry(&mut d);
+
+        // Into [0, M)
+        let add = d[LIMBS - 1] >> 63;
+        for i in 0..LIMBS {
+            d[i] += m[i] & add;
+        }
+        carry(&mut d);
+
+        d
+    }
+}
+
+/// The transition matrix of a batch of divsteps, scaled by `2^62`
+#[derive(Copy, Clone, Debug)]
+struct Matrix {
+    u: i64,
+    v: i64,
+    q: i64,
+    r: i64,
+}
+
+/// Applies 62 divsteps to the low bits of `f` and `g` in constant time.
+///
+/// Returns the new `delta` and the matrix that takes `(f, g)` to `2^62` times their new values.
+fn divsteps(mut delta: i64, f0: u64, g0: u64) -> (i64, Matrix) {
+    let (mut f, mut g) = (f0 as i64, g0 as i64);
+    let (mut u, mut v, mut q, mut r) = (1i64, 0i64, 0i64, 1i64);
+
+    for _ in 0..62 {
+        debug_assert_eq!(f & 1, 1);
+
+        // When delta > 0 and g is odd: (delta, f, g) = (1 - delta, g, (g - f) / 2).
+        // Otherwise: (delta, f, g) = (1 + delta, f, (g + (g mod 2) f) / 2).
+        let odd = -(g & 1);
+        let swap = (delta.wrapping_neg() >> 63) & odd;
+
+        delta = (delta ^ swap) - swap + 1;
+
+        // Replace (f, g) with (g, -f) when swapping
+        let (f1, g1) = (f, g);
+        f = f1 ^ ((f1 ^ g1) & swap);
+        g = g1 ^ ((g1 ^ f1.wrapping_neg()) & swap);
+        let (u1, v1, q1, r1) = (u, v, q, r);
+        u = u1 ^ ((u1 ^ q1) & swap);
+        v = v1 ^ ((v1 ^ r1) & swap);
+        q = q1 ^ ((q1 ^ u1.wrapping_neg()) & swap);
+        r = r1 ^ ((r1 ^ v1.wrapping_neg()) & swap);
+
+        // Add f to g when g is odd, then halve g. The matrix doubles the row of f instead.
+        g = g.wrapping_add(f & odd);
+        q = q.wrapping_add(u & odd);
+        r = r.wrapping_add(v & odd);
+        g >>= 1;
+        u = u.wrapping_shl(1);
+        v = v.wrapping_shl(1);
+    }
+
+    (delta, Matrix { u, v, q, r })
+}
+
+/// Replaces `(f, g)` with `t (f, g) / 2^62`, which is exact
+fn update_fg(f: &mut Signed62, g: &mut Signed62, t: &Matrix) {
+    let Matrix { u, v, q, r } = *t;
+
+    let mut cf = u as i128 * f[0] as i128 + v as i128 * g[0] as i128;
+    let mut cg = q as i128 * f[0] as i128 + r as i128 * g[0] as i128;
+    debug_assert_eq!(cf as u64 & MASK, 0);
+    debug_assert_eq!(cg as u64 & MASK, 0);
+    cf >>= 62;
+    cg >>= 62;
+
+    for i in 1..LIMBS {
+        cf += u as i128 * f[i] as i128 + v as i128 * g[i] as i128;
+        cg += q as i128 * f[i] as i128 + r as i128 * g[i] as i128;
+        f[i - 1] = (cf as u64 & MASK) as i64;
+        g[i - 1] = (cg as u64 & MASK) as i64;
+        cf >>= 62;
+        cg >>= 62;
+    }
+    f[LIMBS - 1] = cf as i64;
+    g[LIMBS - 1] = cg as i64;
+}
+
+/// Brings every limb but the top one back into `[0, 2^62)`
+fn carry(d: &mut Signed62) {
+    for i in 0..LIMBS - 1 {
+        d[i + 1] += d[i] >> 62;
+        d[i] &= MASK as i64;
+    }
+}
+
+/// Converts an integer below `2^448` to signed limbs
+const fn to_signed62(value: &U448) -> Signed62 {
+    let words = value.as_words();
+    let mut limbs = [0i64; LIMBS];
+    let mut acc = 0u128;
+    let mut bits = 0;
+    let mut limb = 0;
+    let mut i = 0;
+    while i < U448::BYTES {
+        let byte = (words[i / Limb::BYTES] >> (8 * (i % Limb::BYTES))) as u8;
+        acc |= (byte as u128) << bits;
+        bits += 8;
+        if bits >= 62 {
+            limbs[limb] = (acc as u64 & MASK) as i64;
+            acc >>= 62;
+            bits -= 62;
+            limb += 1;
+        }
+        i += 1;
+    }
+    limbs[limb] = acc as i64;
+    limbs
+}
+
+/// Converts limbs that are all in `[0, 2^62)` back to an integer
+fn from_signed62(limbs: &Signed62) -> U448 {
+    let mut bytes = [0u8; U448::BYTES];
+    let mut acc = 0u128;
+    let mut bits = 0;
+    let mut limbs = limbs.iter();
+    for byte in bytes.iter_mut() {
+        if bits < 8 {
+            if let Some(limb) = limbs.next() {
+                acc |= (*limb as u128) << bits;
+                bits += 62;
+            }
+        }
+        *byte = acc as u8;
+        acc >>= 8;
+        bits -= 8;
+    }
+    U448::from_le_slice(&bytes)
+}
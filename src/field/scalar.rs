@@ -1,3 +1,4 @@
+use super::safegcd::Modulus;
 use crate::constants;
 use crate::*;
 
@@ -34,6 +35,9 @@ pub type ScalarBytes = GenericArray<u8, U57>;
 pub type WideScalarBytes = GenericArray<u8, U114>;
 
 pub(crate) const MODULUS: Scalar = constants::BASEPOINT_ORDER;
+
+/// The order of the scalar field, prepared for inversion
+const INVERSION_MODULUS: Modulus = Modulus::new(&ORDER);
 pub const ORDER: U448 = U448::from_be_hex("3fffffffffffffffffffffffffffffffffffffffffffffffffffffff7cca23e9c44edb49aed63690216cc2728dc58f552378c292ab5844f3");
 pub const WIDE_ORDER: U896 = U896::from_be_hex("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003fffffffffffffffffffffffffffffffffffffffffffffffffffffff7cca23e9c44edb49aed63690216cc2728dc58f552378c292ab5844f3");
 
@@ -763,6 +767,96 @@ impl Scalar {
         output
     }
 
+    /// Compute a width-`w` non-adjacent form of this scalar.
+    ///
+    /// Every nonzero digit is odd and less than `2^(w-1)` in absolute value,
+    /// and any `w` consecutive digits contain at most one nonzero digit.
+    /// This is only suitable for variable time algorithms.
+    pub(crate) fn non_adjacent_form(&self, w: usize) -> [i8; 448] {
+        debug_assert!((2..=8).contains(&w));
+
+        let mut naf = [0i8; 448];
+
+        // One extra word so that windows crossing the top word can be read
+        let mut x_u64 = [0u64; 8];
+        for (word, limbs) in x_u64.iter_mut().zip(self.0.chunks(2)) {
+            *word = (limbs[0] as u64) | ((limbs[1] as u64) << 32);
+        }
+
+        let width = 1u64 << w;
+        let window_mask = width - 1;
+
+        let mut pos = 0;
+        let mut carry = 0;
+        while pos < 448 {
+            let u64_idx = pos / 64;
+            let bit_idx = pos % 64;
+            let bit_buf = if bit_idx < 64 - w {
+                x_u64[u64_idx] >> bit_idx
+            } else {
+                (x_u64[u64_idx] >> bit_idx) | (x_u64[u64_idx + 1] << (64 - bit_idx))
+            };
+
+            let window = carry + (bit_buf & window_mask);
+
+            if window & 1 == 0 {
+                pos += 1;
+                continue;
+            }
+
+            if window < width / 2 {
+                carry = 0;
+                naf[pos] = window as i8;
+            } else {
+                carry = 1;
+                naf[pos] = (window as i8).wrapping_sub(width as i8);
+            }
+
+            pos += w;
+        }
+
+        naf
+    }
+
+    /// Write this scalar in signed radix `2^w`, for `4 <= w <= 10`.
+    ///
+    /// The first `ceil(448 / w)` digits are in `[-2^(w-1), 2^(w-1))` and the rest are zero.
+    /// The recoding itself runs in constant time.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub(crate) fn to_radix_2w(self, w: usize) -> [i16; 112] {
+        debug_assert!((4..=10).contains(&w));
+
+        let mut x_u64 = [0u64; 8];
+        for (word, limbs) in x_u64.iter_mut().zip(self.0.chunks(2)) {
+            *word = (limbs[0] as u64) | ((limbs[1] as u64) << 32);
+        }
+
+        let radix = 1i16 << w;
+        let window_mask = (radix - 1) as u64;
+        let digits_count = 448usize.div_ceil(w);
+
+        let mut digits = [0i16; 112];
+        let mut carry = 0;
+        for (i, digit) in digits.iter_mut().take(digits_count).enumerate() {
+            let bit_offset = i * w;
+            let u64_idx = bit_offset / 64;
+            let bit_idx = bit_offset % 64;
+            let bit_buf = if bit_idx <= 64 - w {
+                x_u64[u64_idx] >> bit_idx
+            } else {
+                (x_u64[u64_idx] >> bit_idx) | (x_u64[u64_idx + 1] << (64 - bit_idx))
+            };
+
+            // Recenter the window, carrying into the next digit. The scalar is
+            // below 2^446, so the top digit never needs to carry.
+            let window = carry + (bit_buf & window_mask) as i16;
+            carry = (window + radix / 2) >> w;
+            *digit = window - (carry << w);
+        }
+
+        digits
+    }
+
     // XXX: Better if this method returns an array of 448 items
     /// Returns the bits of the scalar in little-endian order.
     pub fn bits(&self) -> [bool; 448] {
@@ -820,73 +914,10 @@ impl Scalar {
         montgomery_multiply(self, self)
     }
 
-    /// Invert this scalar
+    /// Invert this scalar in constant time, mapping zero to zero
     pub fn invert(&self) -> Self {
-        let mut pre_comp = [Scalar::ZERO; 8];
-        let mut result = Scalar::ZERO;
-
-        let scalar_window_bits = 3;
-        let last = (1 << scalar_window_bits) - 1;
-
-        // precompute [a^1, a^3,,..]
-        pre_comp[0] = montgomery_multiply(self, &R2);
-
-        if last > 0 {
-            pre_comp[last] = montgomery_multiply(&pre_comp[0], &pre_comp[0]);
-        }
-
-        for i in 1..=last {
-            pre_comp[i] = montgomery_multiply(&pre_comp[i - 1], &pre_comp[last])
-        }
-
-        // Sliding window
-        let mut residue: usize = 0;
-        let mut trailing: usize = 0;
-        let mut started: usize = 0;
-
-        // XXX: This can definitely be refactored to be readable
-        let loop_start = -scalar_window_bits as isize;
-        let loop_end = 446 - 1;
-        for i in (loop_start..=loop_end).rev() {
-            if started != 0 {
-                result = result.square()
-            }
-
-            let mut w: u32;
-            if i >= 0 {
-                w = MODULUS[(i / 32) as usize];
-            } else {
-                w = 0;
-            }
-
-            if (0..32).contains(&i) {
-                w -= 2
-            }
-
-            residue = (((residue as u32) << 1) | ((w >> ((i as u32) % 32)) & 1)) as usize;
-            if residue >> scalar_window_bits != 0 {
-                trailing = residue;
-                residue = 0
-            }
-
-            if trailing > 0 && (trailing & ((1 << scalar_window_bits) - 1)) == 0 {
-                if started != 0 {
-                    result = montgomery_multiply(
-                        &result,
-                        &pre_comp[trailing >> (scalar_window_bits + 1)],
-                    )
-                } else {
-                    result = pre_comp[trailing >> (scalar_window_bits + 1)];
-                    started = 1
-                }
-                trailing = 0
-            }
-            trailing <<= 1
-        }
-
-        // de-montgomerize and return result
-
-        montgomery_multiply(&result, &Scalar::ONE)
+        let inverse = INVERSION_MODULUS.invert(&U448::from_le_bytes(self.to_bytes()));
+        Self::from_bytes(&inverse.to_le_bytes())
     }
 
     /// Return the square root of this scalar, if it is a quadratic residue.
@@ -933,7 +964,7 @@ impl Scalar {
     /// - `None` if `bytes` is not a canonical byte representation.
     pub fn from_canonical_bytes(bytes: &ScalarBytes) -> CtOption<Self> {
         // Check that the 10 high bits are not set
-        let is_valid = is_zero(bytes[56]) | is_zero(bytes[55] >> 6);
+        let is_valid = is_zero(bytes[56]) & is_zero(bytes[55] >> 6);
         let bytes: [u8; 56] = core::array::from_fn(|i| bytes[i]);
         let candidate = Scalar::from_bytes(&bytes);
 
@@ -1117,6 +1148,149 @@ fn is_zero(b: u8) -> Choice {
 mod test {
     use super::*;
     use hex_literal::hex;
+    use rand_core::SeedableRng;
+
+    /// The sliding window exponentiation to `q - 2` that `invert` used to be
+    fn invert_by_exponentiation(x: &Scalar) -> Scalar {
+        let mut pre_comp = [Scalar::ZERO; 8];
+        let mut result = Scalar::ZERO;
+
+        let scalar_window_bits = 3;
+        let last = (1 << scalar_window_bits) - 1;
+
+        // precompute [a^1, a^3,,..]
+        pre_comp[0] = montgomery_multiply(x, &R2);
+
+        if last > 0 {
+            pre_comp[last] = montgomery_multiply(&pre_comp[0], &pre_comp[0]);
+        }
+
+        for i in 1..=last {
+            pre_comp[i] = montgomery_multiply(&pre_comp[i - 1], &pre_comp[last])
+        }
+
+        // Sliding window
+        let mut residue: usize = 0;
+        let mut trailing: usize = 0;
+        let mut started: usize = 0;
+
+        // XXX: This can definitely be refactored to be readable
+        let loop_start = -scalar_window_bits as isize;
+        let loop_end = 446 - 1;
+        for i in (loop_start..=loop_end).rev() {
+            if started != 0 {
+                result = result.square()
+            }
+
+            let mut w: u32;
+            if i >= 0 {
+                w = MODULUS[(i / 32) as usize];
+            } else {
+                w = 0;
+            }
+
+            if (0..32).contains(&i) {
+                w -= 2
+            }
+
+            residue = (((residue as u32) << 1) | ((w >> ((i as u32) % 32)) & 1)) as usize;
+            if residue >> scalar_window_bits != 0 {
+                trailing = residue;
+                residue = 0
+            }
+
+            if trailing > 0 && (trailing & ((1 << scalar_window_bits) - 1)) == 0 {
+                if started != 0 {
+                    result = montgomery_multiply(
+                        &result,
+                        &pre_comp[trailing >> (scalar_window_bits + 1)],
+                    )
+                } else {
+                    result = pre_comp[trailing >> (scalar_window_bits + 1)];
+                    started = 1
+                }
+                trailing = 0
+            }
+            trailing <<= 1
+        }
+
+        // de-montgomerize and return result
+
+        montgomery_multiply(&result, &Scalar::ONE)
+    }
+
+    #[test]
+    fn test_non_adjacent_form() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([3u8; 32]);
+        let scalars = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            MODULUS,
+            Scalar::random(&mut rng),
+            Scalar::random(&mut rng),
+        ];
+        for w in 2..=8 {
+            for s in scalars.iter() {
+                let naf = s.non_adjacent_form(w);
+
+                let mut recovered = Scalar::ZERO;
+                let mut last_nonzero: Option<usize> = None;
+                for (i, digit) in naf.iter().enumerate().rev() {
+                    recovered = recovered + recovered;
+                    if *digit != 0 {
+                        assert_eq!(digit & 1, 1);
+                        assert!((*digit as i32).abs() < (1 << (w - 1)));
+                        if let Some(j) = last_nonzero {
+                            assert!(j - i >= w);
+                        }
+                        last_nonzero = Some(i);
+                        if *digit > 0 {
+                            recovered += Scalar::from(*digit as u8);
+                        } else {
+                            recovered -= Scalar::from(digit.unsigned_abs());
+                        }
+                    }
+                }
+                // The order reduces to zero
+                if *s == MODULUS {
+                    assert_eq!(recovered, Scalar::ZERO);
+                } else {
+                    assert_eq!(recovered, *s);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_radix_2w() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([4u8; 32]);
+        let scalars = [
+            Scalar::ZERO,
+            -Scalar::ONE,
+            Scalar::random(&mut rng),
+            Scalar::random(&mut rng),
+        ];
+        for w in 4..=10 {
+            let radix = Scalar::from(1u32 << w);
+            for s in scalars.iter() {
+                let digits = s.to_radix_2w(w);
+                assert!(digits[448usize.div_ceil(w)..].iter().all(|d| *d == 0));
+
+                let mut recovered = Scalar::ZERO;
+                for digit in digits.iter().rev() {
+                    assert!(*digit >= -(1 << (w - 1)) && *digit < (1 << (w - 1)));
+                    recovered *= radix;
+                    if *digit >= 0 {
+                        recovered += Scalar::from(*digit as u16);
+                    } else {
+                        recovered -= Scalar::from(digit.unsigned_abs());
+                    }
+                }
+                assert_eq!(recovered, *s);
+            }
+        }
+    }
 
     #[test]
     fn test_basic_add() {
@@ -1213,6 +1387,18 @@ mod test {
         let expected_zero = zero.invert();
         assert_eq!(expected_zero, zero)
     }
+
+    #[test]
+    fn test_invert_matches_exponentiation() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([16u8; 32]);
+        let mut scalars = vec![Scalar::ZERO, Scalar::ONE, -Scalar::ONE, MODULUS];
+        for _ in 0..32 {
+            scalars.push(Scalar::random(&mut rng));
+        }
+        for x in scalars.iter() {
+            assert_eq!(x.invert(), invert_by_exponentiation(x));
+        }
+    }
     #[test]
     fn test_serialise() {
         let scalar = Scalar([
@@ -1252,6 +1438,13 @@ mod test {
             Some(s) => assert_eq!(s, Scalar::ZERO - Scalar::ONE),
             None => panic!("should not return None"),
         };
+
+        // A small value with the top byte set should fail
+        let mut bytes = ScalarBytes::default();
+        bytes[0] = 1;
+        bytes[56] = 1;
+        let s = Scalar::from_canonical_bytes(&bytes);
+        assert!(<Choice as Into<bool>>::into(s.is_none()));
     }
 
     #[test]
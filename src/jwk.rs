@@ -0,0 +1,444 @@
+//! JSON Web Keys for Ed448 and X448 as specified in [RFC 8037](https://www.rfc-editor.org/rfc/rfc8037).
+//!
+//! Both are octet key pairs, with a `kty` of `"OKP"`. The `x` parameter holds the
+//! encoded public key and the optional `d` parameter the private key, each in
+//! unpadded base64url.
+use crate::curve::edwards::CompressedEdwardsY;
+use crate::curve::montgomery::MontgomeryPoint;
+use crate::ecdh::{PublicKey, StaticSecret, X448_KEY_LENGTH};
+use crate::sign::{SecretKeyBytes, SigningKey, VerifyingKey, PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+use base64ct::{Base64UrlUnpadded, Encoding};
+use core::fmt::{self, Debug, Formatter};
+use elliptic_curve::zeroize::Zeroizing;
+use serdect::serde::{de, ser, Deserialize, Serialize};
+use subtle::ConstantTimeEq;
+
+/// Key type (`kty`) of octet key pairs
+pub const OKP_KTY: &str = "OKP";
+
+/// The `crv` parameter of Ed448 keys
+const ED448_CRV: &str = "Ed448";
+
+/// The `crv` parameter of X448 keys
+const X448_CRV: &str = "X448";
+
+/// A JSON Web Key with a `kty` of `"OKP"`.
+///
+/// Holds either an Ed448 or an X448 public key, together with the private key
+/// when the `d` parameter is present.
+#[derive(Clone)]
+pub struct JwkOkpKey(OkpKey);
+
+#[derive(Clone)]
+enum OkpKey {
+    Ed448 {
+        x: CompressedEdwardsY,
+        d: Option<SecretKeyBytes>,
+    },
+    X448 {
+        x: MontgomeryPoint,
+        d: Option<[u8; X448_KEY_LENGTH]>,
+    },
+}
+
+impl JwkOkpKey {
+    /// The `crv` parameter of this key, either `"Ed448"` or `"X448"`
+    pub fn crv(&self) -> &'static str {
+        match self.0 {
+            OkpKey::Ed448 { .. } => ED448_CRV,
+            OkpKey::X448 { .. } => X448_CRV,
+        }
+    }
+
+    /// Does this key include the private key?
+    pub fn is_keypair(&self) -> bool {
+        match &self.0 {
+            OkpKey::Ed448 { d, .. } => d.is_some(),
+            OkpKey::X448 { d, .. } => d.is_some(),
+        }
+    }
+
+    /// Does this key contain only the public key?
+    pub fn is_public_key(&self) -> bool {
+        !self.is_keypair()
+    }
+
+    /// Create a key from its `crv`, `x` and `d` parameters
+    fn from_parameters(crv: &str, x: &str, d: Option<&str>) -> Result<Self, &'static str> {
+        match crv {
+            ED448_CRV => Ok(Self(OkpKey::Ed448 {
+                x: CompressedEdwardsY(decode_base64url::<PUBLIC_KEY_LENGTH>(x)?),
+                d: d.map(decode_base64url::<SECRET_KEY_LENGTH>).transpose()?,
+            })),
+            X448_CRV => Ok(Self(OkpKey::X448 {
+                x: MontgomeryPoint(decode_base64url::<X448_KEY_LENGTH>(x)?),
+                d: d.map(decode_base64url::<X448_KEY_LENGTH>).transpose()?,
+            })),
+            _ => Err("Unsupported crv"),
+        }
+    }
+}
+
+impl Debug for JwkOkpKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // The private key is left out
+        let mut debug = f.debug_struct("JwkOkpKey");
+        debug.field("crv", &self.crv());
+        match &self.0 {
+            OkpKey::Ed448 { x, .. } => debug.field("x", x),
+            OkpKey::X448 { x, .. } => debug.field("x", x),
+        };
+        debug.finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for JwkOkpKey {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (OkpKey::Ed448 { x: x1, d: d1 }, OkpKey::Ed448 { x: x2, d: d2 }) => {
+                x1 == x2 && ct_eq_option(d1, d2)
+            }
+            (OkpKey::X448 { x: x1, d: d1 }, OkpKey::X448 { x: x2, d: d2 }) => {
+                x1 == x2 && ct_eq_option(d1, d2)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for JwkOkpKey {}
+
+#[cfg(feature = "zeroize")]
+impl Drop for JwkOkpKey {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        match &mut self.0 {
+            OkpKey::Ed448 { d, .. } => d.zeroize(),
+            OkpKey::X448 { d, .. } => d.zeroize(),
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for JwkOkpKey {}
+
+impl From<CompressedEdwardsY> for JwkOkpKey {
+    fn from(x: CompressedEdwardsY) -> Self {
+        Self(OkpKey::Ed448 { x, d: None })
+    }
+}
+
+impl From<MontgomeryPoint> for JwkOkpKey {
+    fn from(x: MontgomeryPoint) -> Self {
+        Self(OkpKey::X448 { x, d: None })
+    }
+}
+
+impl From<&VerifyingKey> for JwkOkpKey {
+    fn from(key: &VerifyingKey) -> Self {
+        Self::from(key.compressed)
+    }
+}
+
+impl From<&SigningKey> for JwkOkpKey {
+    fn from(key: &SigningKey) -> Self {
+        Self(OkpKey::Ed448 {
+            x: key.verifying_key().compressed,
+            d: Some(key.to_bytes()),
+        })
+    }
+}
+
+impl From<&PublicKey> for JwkOkpKey {
+    fn from(key: &PublicKey) -> Self {
+        Self::from(key.0)
+    }
+}
+
+impl From<&StaticSecret> for JwkOkpKey {
+    fn from(secret: &StaticSecret) -> Self {
+        Self(OkpKey::X448 {
+            x: PublicKey::from(secret).0,
+            d: Some(secret.to_bytes()),
+        })
+    }
+}
+
+impl TryFrom<&JwkOkpKey> for CompressedEdwardsY {
+    type Error = &'static str;
+
+    fn try_from(jwk: &JwkOkpKey) -> Result<Self, Self::Error> {
+        match jwk.0 {
+            OkpKey::Ed448 { x, .. } => Ok(x),
+            OkpKey::X448 { .. } => Err("Expected an Ed448 key"),
+        }
+    }
+}
+
+impl TryFrom<&JwkOkpKey> for MontgomeryPoint {
+    type Error = &'static str;
+
+    fn try_from(jwk: &JwkOkpKey) -> Result<Self, Self::Error> {
+        match jwk.0 {
+            OkpKey::X448 { x, .. } => Ok(x),
+            OkpKey::Ed448 { .. } => Err("Expected an X448 key"),
+        }
+    }
+}
+
+impl TryFrom<&JwkOkpKey> for VerifyingKey {
+    type Error = &'static str;
+
+    fn try_from(jwk: &JwkOkpKey) -> Result<Self, Self::Error> {
+        let x = CompressedEdwardsY::try_from(jwk)?;
+        Self::from_bytes(&x.0).map_err(|_| "Invalid point")
+    }
+}
+
+impl TryFrom<&JwkOkpKey> for SigningKey {
+    type Error = &'static str;
+
+    fn try_from(jwk: &JwkOkpKey) -> Result<Self, Self::Error> {
+        match &jwk.0 {
+            OkpKey::Ed448 { x, d: Some(d) } => {
+                let key = Self::from_bytes(d);
+                if key.verifying_key().compressed != *x {
+                    return Err("Mismatched public key");
+                }
+                Ok(key)
+            }
+            OkpKey::Ed448 { d: None, .. } => Err("Missing private key"),
+            OkpKey::X448 { .. } => Err("Expected an Ed448 key"),
+        }
+    }
+}
+
+impl TryFrom<&JwkOkpKey> for PublicKey {
+    type Error = &'static str;
+
+    fn try_from(jwk: &JwkOkpKey) -> Result<Self, Self::Error> {
+        MontgomeryPoint::try_from(jwk).map(Self)
+    }
+}
+
+impl TryFrom<&JwkOkpKey> for StaticSecret {
+    type Error = &'static str;
+
+    fn try_from(jwk: &JwkOkpKey) -> Result<Self, Self::Error> {
+        match &jwk.0 {
+            OkpKey::X448 { x, d: Some(d) } => {
+                let secret = Self::from(*d);
+                if PublicKey::from(&secret).0 != *x {
+                    return Err("Mismatched public key");
+                }
+                Ok(secret)
+            }
+            OkpKey::X448 { d: None, .. } => Err("Missing private key"),
+            OkpKey::Ed448 { .. } => Err("Expected an X448 key"),
+        }
+    }
+}
+
+impl Serialize for JwkOkpKey {
+    fn serialize<S: ser::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use ser::SerializeStruct;
+
+        let (x, d) = match &self.0 {
+            OkpKey::Ed448 { x, d } => (x.as_bytes().as_slice(), d.as_ref().map(|d| &d[..])),
+            OkpKey::X448 { x, d } => (x.as_bytes().as_slice(), d.as_ref().map(|d| &d[..])),
+        };
+
+        let mut state = s.serialize_struct("JwkOkpKey", 3 + usize::from(d.is_some()))?;
+        state.serialize_field("kty", OKP_KTY)?;
+        state.serialize_field("crv", self.crv())?;
+        state.serialize_field("x", &Base64UrlUnpadded::encode_string(x))?;
+        if let Some(d) = d {
+            let d = Zeroizing::new(Base64UrlUnpadded::encode_string(d));
+            state.serialize_field("d", d.as_str())?;
+        }
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for JwkOkpKey {
+    fn deserialize<D: de::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = JwkOkpKey;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("an OKP JSON Web Key")
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<JwkOkpKey, A::Error> {
+                let mut kty: Option<String> = None;
+                let mut crv: Option<String> = None;
+                let mut x: Option<String> = None;
+                let mut d: Option<Zeroizing<String>> = None;
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "kty" if kty.is_none() => kty = Some(map.next_value()?),
+                        "crv" if crv.is_none() => crv = Some(map.next_value()?),
+                        "x" if x.is_none() => x = Some(map.next_value()?),
+                        "d" if d.is_none() => d = Some(Zeroizing::new(map.next_value()?)),
+                        "kty" => return Err(de::Error::duplicate_field("kty")),
+                        "crv" => return Err(de::Error::duplicate_field("crv")),
+                        "x" => return Err(de::Error::duplicate_field("x")),
+                        "d" => return Err(de::Error::duplicate_field("d")),
+                        // Other members such as `kid` or `use` are ignored
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                let kty = kty.ok_or_else(|| de::Error::missing_field("kty"))?;
+                if kty != OKP_KTY {
+                    return Err(de::Error::custom("Unsupported kty"));
+                }
+                let crv = crv.ok_or_else(|| de::Error::missing_field("crv"))?;
+                let x = x.ok_or_else(|| de::Error::missing_field("x"))?;
+
+                JwkOkpKey::from_parameters(&crv, &x, d.as_ref().map(|d| d.as_str()))
+                    .map_err(de::Error::custom)
+            }
+        }
+
+        d.deserialize_map(Visitor)
+    }
+}
+
+/// Decode an unpadded base64url parameter of exactly `N` bytes
+fn decode_base64url<const N: usize>(s: &str) -> Result<[u8; N], &'static str> {
+    let mut bytes = [0u8; N];
+    let decoded = Base64UrlUnpadded::decode(s, &mut bytes).map_err(|_| "Invalid base64url")?;
+    if decoded.len() != N {
+        return Err("Invalid length");
+    }
+    Ok(bytes)
+}
+
+/// Compare two optional private keys in constant time
+fn ct_eq_option<const N: usize>(a: &Option<[u8; N]>, b: &Option<[u8; N]>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.ct_eq(b).into(),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    // RFC 8037 appendix A.7
+    const X448_BOB: &str = r#"{"kty":"OKP","crv":"X448","x":"PreoKbDNIPW8_AtZm2_sz22kYnEHvbDU80W0MCfYuXL8PjT7QjKhPKcG3LV67D2uB73BxnvzNgk"}"#;
+    const X448_EPHEMERAL: &str = r#"{"kty":"OKP","crv":"X448","x":"mwj3zDG34-Z9ItWuoSEHSic70rg94Jxj-qc9LCLF2bvINmRyQdlT1AxbEtqIEg1TF3-A5TLEH6A"}"#;
+
+    // The first key of RFC 8032 section 7.4
+    const ED448_KEYPAIR: &str = r#"{"kty":"OKP","crv":"Ed448","x":"X9dEm1m0Yf0s54fsYWrUah2hNCSFpw4fig6nXYDpZ3jt8SR2m0bHBhvWeD3x5Q9s0foavq_oJWGA","d":"bIKlYsuAjRDWMr6JyFE-v2ySnzTd-oyfY8mWDvbjSKNSjIo_zC8ETjmj_FuUSS-PAy51SaIAmPlb"}"#;
+
+    #[test]
+    fn rfc8037_x448() {
+        let bob = serde_json::from_str::<JwkOkpKey>(X448_BOB).unwrap();
+        assert_eq!(bob.crv(), "X448");
+        assert!(bob.is_public_key());
+        let bob = PublicKey::try_from(&bob).unwrap();
+        assert_eq!(bob.to_bytes(), hex!("3eb7a829b0cd20f5bcfc0b599b6feccf6da4627107bdb0d4f345b43027d8b972fc3e34fb4232a13ca706dcb57aec3dae07bdc1c67bf33609"));
+
+        let ephemeral = serde_json::from_str::<JwkOkpKey>(X448_EPHEMERAL).unwrap();
+        let ephemeral = PublicKey::try_from(&ephemeral).unwrap();
+        let secret = StaticSecret::from(hex!("9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b"));
+        assert_eq!(PublicKey::from(&secret), ephemeral);
+        assert_eq!(secret.diffie_hellman(&bob).to_bytes(), hex!("07fff4181ac6cc95ec1c16a94a0f74d12da232ce40a77552281d282bb60c0b56fd2464c335543936521c24403085d59a449a5037514a879d"));
+
+        assert_eq!(
+            serde_json::to_string(&JwkOkpKey::from(&bob)).unwrap(),
+            X448_BOB
+        );
+        assert_eq!(
+            serde_json::to_string(&JwkOkpKey::from(&ephemeral)).unwrap(),
+            X448_EPHEMERAL
+        );
+
+        let jwk = JwkOkpKey::from(&secret);
+        let decoded = serde_json::from_str(&serde_json::to_string(&jwk).unwrap()).unwrap();
+        assert_eq!(jwk, decoded);
+        assert_eq!(
+            StaticSecret::try_from(&decoded).unwrap().to_bytes(),
+            secret.to_bytes()
+        );
+    }
+
+    #[test]
+    fn ed448_keypair() {
+        let jwk = serde_json::from_str::<JwkOkpKey>(ED448_KEYPAIR).unwrap();
+        assert_eq!(jwk.crv(), "Ed448");
+        assert!(jwk.is_keypair());
+
+        let signing_key = SigningKey::try_from(&jwk).unwrap();
+        assert_eq!(signing_key.to_bytes(), hex!("6c82a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3528c8a3fcc2f044e39a3fc5b94492f8f032e7549a20098f95b"));
+        assert_eq!(
+            VerifyingKey::try_from(&jwk).unwrap(),
+            signing_key.verifying_key()
+        );
+        assert_eq!(JwkOkpKey::from(&signing_key), jwk);
+        assert_eq!(serde_json::to_string(&jwk).unwrap(), ED448_KEYPAIR);
+
+        let public = JwkOkpKey::from(&signing_key.verifying_key());
+        assert!(public.is_public_key());
+        assert_eq!(SigningKey::try_from(&public), Err("Missing private key"));
+        assert_eq!(
+            CompressedEdwardsY::try_from(&public).unwrap(),
+            signing_key.verifying_key().compressed
+        );
+
+        // Unknown members are ignored
+        let with_kid = ED448_KEYPAIR.replace(r#""kty""#, r#""kid":"key-1","kty""#);
+        assert_eq!(serde_json::from_str::<JwkOkpKey>(&with_kid).unwrap(), jwk);
+    }
+
+    #[test]
+    fn invalid_keys_are_rejected() {
+        let parse = |s: &str| serde_json::from_str::<JwkOkpKey>(s);
+
+        // Other key types and curves
+        assert!(parse(&X448_BOB.replace("OKP", "EC")).is_err());
+        assert!(parse(&X448_BOB.replace("X448", "X25519")).is_err());
+        assert!(parse(&ED448_KEYPAIR.replace("Ed448", "Ed25519")).is_err());
+
+        // The crv does not match the length of the key
+        assert!(parse(&X448_BOB.replace("X448", "Ed448")).is_err());
+        assert!(parse(&ED448_KEYPAIR.replace("Ed448", "X448")).is_err());
+
+        // Padding and missing or duplicate members
+        assert!(parse(&X448_BOB.replace("Ngk\"", "Ngk=\"")).is_err());
+        assert!(parse(r#"{"kty":"OKP","crv":"X448"}"#).is_err());
+        assert!(parse(&X448_BOB.replace(r#""kty""#, r#""crv":"X448","kty""#)).is_err());
+
+        // A key for the other algorithm
+        let bob = parse(X448_BOB).unwrap();
+        assert_eq!(VerifyingKey::try_from(&bob), Err("Expected an Ed448 key"));
+        let keypair = parse(ED448_KEYPAIR).unwrap();
+        assert_eq!(PublicKey::try_from(&keypair), Err("Expected an X448 key"));
+        assert!(StaticSecret::try_from(&keypair).is_err());
+
+        // A private key that does not match the public key
+        let other = SigningKey::from_bytes(&[1u8; SECRET_KEY_LENGTH]);
+        let mismatched = JwkOkpKey(OkpKey::Ed448 {
+            x: other.verifying_key().compressed,
+            d: Some(hex!("6c82a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3528c8a3fcc2f044e39a3fc5b94492f8f032e7549a20098f95b")),
+        });
+        assert_eq!(
+            SigningKey::try_from(&mismatched),
+            Err("Mismatched public key")
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Compact JSON Web Signatures with Ed448 as specified in
+//! [RFC 8037](https://www.rfc-editor.org/rfc/rfc8037).
+//!
+//! The protected header is `{"alg":"EdDSA"}`, and the signature is a pure Ed448
+//! signature with an empty context over the ASCII signing input
+//! `BASE64URL(header) || '.' || BASE64URL(payload)`.
+use crate::sign::{Signature, SigningKey, VerifyingKey, SIGNATURE_LENGTH};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{string::String, vec::Vec};
+use base64ct::{Base64UrlUnpadded, Encoding};
+
+/// The `alg` header parameter of EdDSA signatures
+pub const JWS_ALGORITHM: &str = "EdDSA";
+
+/// The protected header of the JWS this module creates
+const PROTECTED_HEADER: &str = r#"{"alg":"EdDSA"}"#;
+
+impl SigningKey {
+    /// Sign `payload` as a compact JWS with a header of `{"alg":"EdDSA"}`
+    pub fn sign_jws(&self, payload: &[u8]) -> String {
+        let mut jws = Base64UrlUnpadded::encode_string(PROTECTED_HEADER.as_bytes());
+        jws.push('.');
+        jws.push_str(&Base64UrlUnpadded::encode_string(payload));
+
+        let signature = self.sign(jws.as_bytes());
+        jws.push('.');
+        jws.push_str(&Base64UrlUnpadded::encode_string(&signature.to_bytes()));
+        jws
+    }
+}
+
+impl VerifyingKey {
+    /// Verify a compact JWS and return its payload.
+    ///
+    /// The protected header has to have an `alg` of `"EdDSA"`, and since no
+    /// extensions are understood, it must not list any as `crit`.
+    pub fn verify_jws(&self, jws: &str) -> Result<Vec<u8>, &'static str> {
+        let mut parts = jws.split('.');
+        let (header, payload, signature) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(header), Some(payload), Some(signature)) => (header, payload, signature),
+            _ => return Err("Invalid compact JWS"),
+        };
+        if parts.next().is_some() {
+            return Err("Invalid compact JWS");
+        }
+
+        let decoded_header =
+            Base64UrlUnpadded::decode_vec(header).map_err(|_| "Invalid base64url")?;
+        let decoded_header = serde_json::from_slice::<serde_json::Value>(&decoded_header)
+            .map_err(|_| "Invalid header")?;
+        let decoded_header = decoded_header.as_object().ok_or("Invalid header")?;
+        if decoded_header.get("alg").and_then(|alg| alg.as_str()) != Some(JWS_ALGORITHM) {
+            return Err("Unsupported alg");
+        }
+        if decoded_header.contains_key("crit") {
+            return Err("Unsupported critical header");
+        }
+
+        let mut signature_bytes = [0u8; SIGNATURE_LENGTH];
+        let decoded_signature = Base64UrlUnpadded::decode(signature, &mut signature_bytes)
+            .map_err(|_| "Invalid signature")?;
+        if decoded_signature.len() != SIGNATURE_LENGTH {
+            return Err("Invalid signature");
+        }
+        let signature = Signature::from_bytes(&signature_bytes);
+
+        // The signing input is everything up to the second '.'
+        let signing_input = &jws[..header.len() + 1 + payload.len()];
+        self.verify(signing_input.as_bytes(), &signature)
+            .map_err(|_| "Invalid signature")?;
+
+        Base64UrlUnpadded::decode_vec(payload).map_err(|_| "Invalid base64url")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    // The first key of RFC 8032 section 7.4
+    const SECRET_KEY: [u8; 57] = hex!("6c82a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3528c8a3fcc2f044e39a3fc5b94492f8f032e7549a20098f95b");
+
+    const PAYLOAD: &[u8] = b"Example of Ed448 signing";
+    const JWS: &str = "eyJhbGciOiJFZERTQSJ9.RXhhbXBsZSBvZiBFZDQ0OCBzaWduaW5n.wW3QG5pxlbrl9796GM2Qj9-MQq3jDHjsK2qqqtr9Q0ihOxa0OCRBzy4zbFnaQk-s6xvjcRnRaDIAR4oP1CIeu-wQpEzyTYHE4bXP6uhQXLTkJzjEW_5OyLX3_BdsvrFcWfncU3KgI24y1ShgnvigBA4A";
+
+    #[test]
+    fn jws_known_answer() {
+        let signing_key = SigningKey::from_bytes(&SECRET_KEY);
+        assert_eq!(signing_key.sign_jws(PAYLOAD), JWS);
+        assert_eq!(
+            signing_key.verifying_key().verify_jws(JWS).unwrap(),
+            PAYLOAD
+        );
+    }
+
+    #[test]
+    fn jws_rejects_invalid_tokens() {
+        let verifying_key = SigningKey::from_bytes(&SECRET_KEY).verifying_key();
+        let other = SigningKey::from_bytes(&[1u8; 57]).verifying_key();
+        assert_eq!(other.verify_jws(JWS), Err("Invalid signature"));
+
+        let (signing_input, signature) = JWS.rsplit_once('.').unwrap();
+        let (_, payload) = signing_input.split_once('.').unwrap();
+        let with_header = |header: &str| {
+            let mut jws = Base64UrlUnpadded::encode_string(header.as_bytes());
+            jws.push('.');
+            jws.push_str(payload);
+            jws.push('.');
+            jws.push_str(signature);
+            jws
+        };
+
+        // A different alg, a critical extension and a header that is not an object
+        assert_eq!(
+            verifying_key.verify_jws(&with_header(r#"{"alg":"ES256"}"#)),
+            Err("Unsupported alg")
+        );
+        assert_eq!(
+            verifying_key.verify_jws(&with_header(
+                r#"{"alg":"EdDSA","crit":["b64"],"b64":false}"#
+            )),
+            Err("Unsupported critical header")
+        );
+        assert_eq!(
+            verifying_key.verify_jws(&with_header(r#"["EdDSA"]"#)),
+            Err("Invalid header")
+        );
+        // The header is covered by the signature
+        assert_eq!(
+            verifying_key.verify_jws(&with_header(r#"{"alg":"EdDSA","kid":"1"}"#)),
+            Err("Invalid signature")
+        );
+
+        // Missing, extra and truncated parts
+        assert!(verifying_key.verify_jws(signing_input).is_err());
+        assert!(verifying_key.verify_jws(&[JWS, "e30"].join(".")).is_err());
+        assert!(verifying_key.verify_jws(&JWS[..JWS.len() - 2]).is_err());
+    }
+}
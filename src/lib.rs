@@ -33,6 +33,22 @@
 //!
 //! [`EdwardsPoint`] implements the [`elliptic_curve::Group`] and [`elliptic_curve::group::GroupEncoding`]
 //! and [`Scalar`] implements [`elliptic_curve::Field`] and [`elliptic_curve::PrimeField`] traits.
+//!
+//! # Features
+//!
+//! - `std` (default) and `alloc` enable the types and encodings that allocate.
+//! - `serde` implements `Serialize` and `Deserialize` for points, scalars and keys.
+//! - `zeroize` zeroizes secret values.
+//! - `jose` adds JSON Web Keys (with `serde`), compact JWS and COSE_Sign1 signing.
+//! - `u32_backend` uses the 32-bit field backend on every target.
+//! - `avx2_backend` lets variable base scalar multiplication use AVX2 on x86_64.
+//!
+//! AVX2 support is detected at runtime with `std`, and is otherwise taken from the
+//! target features the crate is compiled with. The vector formulas only beat the
+//! 32-bit backend, so they are used by default with `u32_backend` and are opt in
+//! on top of the 64-bit one. Single point additions and doublings always use the
+//! serial formulas, as packing a point into vector lanes costs more than one
+//! addition saves.
 // XXX: Change this to deny later on
 #![warn(unused_attributes, unused_imports, unused_mut, unused_must_use)]
 #![allow(non_snake_case)]
@@ -57,19 +73,55 @@ pub use subtle;
 
 // As usual, we will use this file to carefully define the API/ what we expose to the user
 pub(crate) mod constants;
+#[cfg(feature = "jose")]
+pub(crate) mod cose;
 pub(crate) mod curve;
 pub(crate) mod decaf;
+pub(crate) mod dnssec;
+pub(crate) mod ecdh;
 pub(crate) mod field;
+#[cfg(all(feature = "jose", feature = "serde"))]
+pub(crate) mod jwk;
+#[cfg(feature = "jose")]
+pub(crate) mod jws;
+pub(crate) mod pkcs8;
 pub(crate) mod ristretto;
+pub(crate) mod sign;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub(crate) mod ssh;
 
 pub(crate) use field::{GOLDILOCKS_BASE_POINT, TWISTED_EDWARDS_BASE_POINT};
 
+#[cfg(feature = "jose")]
+pub use cose::COSE_ALGORITHM_EDDSA;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use curve::EdwardsBasepointTable;
 pub use curve::{
-    AffinePoint, CompressedEdwardsY, EdwardsPoint, MontgomeryPoint, ProjectiveMontgomeryPoint,
+    x448, AffinePoint, CompressedEdwardsY, EdwardsPoint, MontgomeryPoint,
+    ProjectiveMontgomeryPoint, X448_BASEPOINT_U,
 };
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use decaf::DecafBasepointTable;
 pub use decaf::{AffinePoint as DecafAffinePoint, CompressedDecaf, DecafPoint};
+pub use dnssec::{DNSKEY_RDATA_LENGTH, DNSSEC_ALGORITHM_ED448};
+pub use ecdh::{
+    EphemeralSecret, PublicKey, ReusableSecret, SharedSecret, StaticSecret, X448_KEY_LENGTH,
+};
 pub use field::{Scalar, ScalarBytes, WideScalarBytes, MODULUS_LIMBS, ORDER, WIDE_ORDER};
+#[cfg(all(feature = "jose", feature = "serde"))]
+pub use jwk::{JwkOkpKey, OKP_KTY};
+#[cfg(feature = "jose")]
+pub use jws::JWS_ALGORITHM;
 pub use ristretto::{CompressedRistretto, RistrettoPoint};
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use sign::{verify_batch, BatchError};
+pub use sign::{
+    PreHasher, PublicKeyBytes, SecretKeyBytes, Signature, SignatureBytes, SigningError, SigningKey,
+    VerificationPolicy, VerifyingKey, PREHASH_LENGTH, PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH,
+    SIGNATURE_LENGTH,
+};
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use ssh::SSH_ED448;
 
 use elliptic_curve::{
     bigint::{ArrayEncoding, ByteArray, U448},
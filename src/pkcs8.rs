@@ -0,0 +1,250 @@
+//! PKCS#8 and SubjectPublicKeyInfo encodings of Ed448 and X448 keys as specified in
+//! [RFC 8410](https://www.rfc-editor.org/rfc/rfc8410).
+//!
+//! Private keys are written as PKCS#8 v2 documents that also carry the public key.
+//! Both v1 and v2 documents are read, and an embedded public key has to match the
+//! one derived from the private key.
+use crate::ecdh::{PublicKey, StaticSecret};
+use crate::sign::{SigningKey, VerifyingKey};
+
+use elliptic_curve::pkcs8::{
+    der::{asn1::BitStringRef, asn1::OctetStringRef, Decode, Encode},
+    spki, AlgorithmIdentifierRef, Document, EncodePrivateKey, EncodePublicKey, Error,
+    ObjectIdentifier, PrivateKeyInfo, Result, SecretDocument, SubjectPublicKeyInfoRef,
+};
+use elliptic_curve::zeroize::Zeroizing;
+
+/// The `id-X448` object identifier
+pub(crate) const X448_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.111");
+
+/// The `id-Ed448` object identifier
+pub(crate) const ED448_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.113");
+
+/// Check that `algorithm` is `oid` without parameters, as RFC 8410 requires
+fn check_algorithm(
+    algorithm: &AlgorithmIdentifierRef<'_>,
+    oid: ObjectIdentifier,
+) -> spki::Result<()> {
+    algorithm.assert_algorithm_oid(oid)?;
+    if algorithm.parameters.is_some() {
+        return Err(spki::Error::KeyMalformed);
+    }
+    Ok(())
+}
+
+/// Decode the private key of a PKCS#8 document, and the public key if there is one
+fn decode_private_key<const N: usize>(
+    info: &PrivateKeyInfo<'_>,
+    oid: ObjectIdentifier,
+) -> Result<([u8; N], Option<[u8; N]>)> {
+    check_algorithm(&info.algorithm, oid)?;
+
+    // CurvePrivateKey ::= OCTET STRING, inside the privateKey OCTET STRING
+    let private_key = OctetStringRef::from_der(info.private_key)?;
+    let private_key =
+        <[u8; N]>::try_from(private_key.as_bytes()).map_err(|_| Error::KeyMalformed)?;
+    let public_key = info
+        .public_key
+        .map(|public_key| <[u8; N]>::try_from(public_key).map_err(|_| Error::KeyMalformed))
+        .transpose()?;
+
+    Ok((private_key, public_key))
+}
+
+/// Encode a PKCS#8 v2 document with both keys
+fn encode_private_key(
+    oid: ObjectIdentifier,
+    private_key: &[u8],
+    public_key: &[u8],
+) -> Result<SecretDocument> {
+    let algorithm = AlgorithmIdentifierRef {
+        oid,
+        parameters: None,
+    };
+    let private_key = Zeroizing::new(OctetStringRef::new(private_key)?.to_der()?);
+    let mut info = PrivateKeyInfo::new(algorithm, &private_key);
+    info.public_key = Some(public_key);
+    SecretDocument::try_from(info)
+}
+
+/// Decode the public key of a SubjectPublicKeyInfo document
+fn decode_public_key<const N: usize>(
+    info: &SubjectPublicKeyInfoRef<'_>,
+    oid: ObjectIdentifier,
+) -> spki::Result<[u8; N]> {
+    check_algorithm(&info.algorithm, oid)?;
+    info.subject_public_key
+        .as_bytes()
+        .and_then(|bytes| <[u8; N]>::try_from(bytes).ok())
+        .ok_or(spki::Error::KeyMalformed)
+}
+
+/// Encode a SubjectPublicKeyInfo document
+fn encode_public_key(oid: ObjectIdentifier, public_key: &[u8]) -> spki::Result<Document> {
+    let info = SubjectPublicKeyInfoRef {
+        algorithm: AlgorithmIdentifierRef {
+            oid,
+            parameters: None,
+        },
+        subject_public_key: BitStringRef::from_bytes(public_key)?,
+    };
+    Document::try_from(info)
+}
+
+impl TryFrom<PrivateKeyInfo<'_>> for SigningKey {
+    type Error = Error;
+
+    fn try_from(info: PrivateKeyInfo<'_>) -> Result<Self> {
+        let (secret, public) = decode_private_key(&info, ED448_OID)?;
+        let signing_key = Self::from_bytes(&secret);
+        if let Some(public) = public {
+            if signing_key.verifying_key().to_bytes() != public {
+                return Err(Error::KeyMalformed);
+            }
+        }
+        Ok(signing_key)
+    }
+}
+
+impl EncodePrivateKey for SigningKey {
+    fn to_pkcs8_der(&self) -> Result<SecretDocument> {
+        encode_private_key(ED448_OID, self.as_bytes(), self.verifying_key().as_bytes())
+    }
+}
+
+impl TryFrom<SubjectPublicKeyInfoRef<'_>> for VerifyingKey {
+    type Error = spki::Error;
+
+    fn try_from(info: SubjectPublicKeyInfoRef<'_>) -> spki::Result<Self> {
+        let bytes = decode_public_key(&info, ED448_OID)?;
+        Self::from_bytes(&bytes).map_err(|_| spki::Error::KeyMalformed)
+    }
+}
+
+impl EncodePublicKey for VerifyingKey {
+    fn to_public_key_der(&self) -> spki::Result<Document> {
+        encode_public_key(ED448_OID, self.as_bytes())
+    }
+}
+
+impl TryFrom<PrivateKeyInfo<'_>> for StaticSecret {
+    type Error = Error;
+
+    fn try_from(info: PrivateKeyInfo<'_>) -> Result<Self> {
+        let (secret, public) = decode_private_key(&info, X448_OID)?;
+        let secret = Self::from(secret);
+        if let Some(public) = public {
+            if PublicKey::from(&secret).to_bytes() != public {
+                return Err(Error::KeyMalformed);
+            }
+        }
+        Ok(secret)
+    }
+}
+
+impl EncodePrivateKey for StaticSecret {
+    fn to_pkcs8_der(&self) -> Result<SecretDocument> {
+        encode_private_key(X448_OID, self.as_bytes(), PublicKey::from(self).as_bytes())
+    }
+}
+
+impl TryFrom<SubjectPublicKeyInfoRef<'_>> for PublicKey {
+    type Error = spki::Error;
+
+    fn try_from(info: SubjectPublicKeyInfoRef<'_>) -> spki::Result<Self> {
+        decode_public_key(&info, X448_OID).map(Self::from)
+    }
+}
+
+impl EncodePublicKey for PublicKey {
+    fn to_public_key_der(&self) -> spki::Result<Document> {
+        encode_public_key(X448_OID, self.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elliptic_curve::pkcs8::{DecodePrivateKey, DecodePublicKey, LineEnding};
+    use hex_literal::hex;
+
+    // Generated with `openssl genpkey -algorithm ed448` and `openssl pkey -pubout`
+    const ED448_PRIVATE_PEM: &str = include_str!("../resources/keys/ed448-private.pem");
+    const ED448_PUBLIC_PEM: &str = include_str!("../resources/keys/ed448-public.pem");
+    // Generated with `openssl genpkey -algorithm x448` and `openssl pkey -pubout`
+    const X448_PRIVATE_PEM: &str = include_str!("../resources/keys/x448-private.pem");
+    const X448_PUBLIC_PEM: &str = include_str!("../resources/keys/x448-public.pem");
+
+    #[test]
+    fn ed448_openssl_keys() {
+        let signing_key = SigningKey::from_pkcs8_pem(ED448_PRIVATE_PEM).unwrap();
+        assert_eq!(signing_key.to_bytes(), hex!("456e14f789176bbdb910d8b097c18a1edcb563c65252161faf4546d849ebf78d1d02ae3554efb3aa752ddfbdffcf79a3b06d0b6ac62eced368"));
+
+        let verifying_key = VerifyingKey::from_public_key_pem(ED448_PUBLIC_PEM).unwrap();
+        assert_eq!(verifying_key.to_bytes(), hex!("91827216ce07c72de0b346b57b66d1b1853572836a9d8e069fccbd566a1efe36039284de6d7fa9ef4f2da642ffb308631778c026f982598080"));
+        assert_eq!(signing_key.verifying_key(), verifying_key);
+
+        let pem = verifying_key.to_public_key_pem(LineEnding::LF).unwrap();
+        assert_eq!(pem, ED448_PUBLIC_PEM);
+    }
+
+    #[test]
+    fn x448_openssl_keys() {
+        let secret = StaticSecret::from_pkcs8_pem(X448_PRIVATE_PEM).unwrap();
+        assert_eq!(secret.to_bytes(), hex!("48c13181782a60e7b3e31f32ea4dd29af69c9de790a1427eb483f509b2f62ab2c1211111277c1665708214c4b6ee9401fafab460256f4cd8"));
+
+        let public = PublicKey::from_public_key_pem(X448_PUBLIC_PEM).unwrap();
+        assert_eq!(public.to_bytes(), hex!("43f62ac10cdbebbc18e4e110451de5ff728f72153ba9529d73355856edc128b001d6ffb2fb75634a9f9b631977b1f2a2674bd8ad569f6bb2"));
+        assert_eq!(PublicKey::from(&secret), public);
+
+        let pem = public.to_public_key_pem(LineEnding::LF).unwrap();
+        assert_eq!(pem, X448_PUBLIC_PEM);
+    }
+
+    #[test]
+    fn private_keys_round_trip_as_v2() {
+        let signing_key = SigningKey::from_pkcs8_pem(ED448_PRIVATE_PEM).unwrap();
+        let document = signing_key.to_pkcs8_der().unwrap();
+        let info = PrivateKeyInfo::try_from(document.as_bytes()).unwrap();
+        assert_eq!(
+            info.public_key,
+            Some(&signing_key.verifying_key().to_bytes()[..])
+        );
+        assert_eq!(SigningKey::try_from(info).unwrap(), signing_key);
+
+        let secret = StaticSecret::from_pkcs8_pem(X448_PRIVATE_PEM).unwrap();
+        let document = secret.to_pkcs8_der().unwrap();
+        let info = PrivateKeyInfo::try_from(document.as_bytes()).unwrap();
+        assert_eq!(
+            info.public_key,
+            Some(&PublicKey::from(&secret).to_bytes()[..])
+        );
+        assert_eq!(
+            StaticSecret::try_from(info).unwrap().to_bytes(),
+            secret.to_bytes()
+        );
+    }
+
+    #[test]
+    fn mismatched_keys_are_rejected() {
+        // A private key for the other algorithm
+        assert!(SigningKey::from_pkcs8_pem(X448_PRIVATE_PEM).is_err());
+        assert!(StaticSecret::from_pkcs8_pem(ED448_PRIVATE_PEM).is_err());
+        assert!(VerifyingKey::from_public_key_pem(X448_PUBLIC_PEM).is_err());
+        assert!(PublicKey::from_public_key_pem(ED448_PUBLIC_PEM).is_err());
+
+        // A v2 document whose public key does not belong to the private key
+        let signing_key = SigningKey::from_pkcs8_pem(ED448_PRIVATE_PEM).unwrap();
+        let other = SigningKey::from_bytes(&[1u8; 57]);
+        let document = encode_private_key(
+            ED448_OID,
+            signing_key.as_bytes(),
+            other.verifying_key().as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(
+            SigningKey::from_pkcs8_der(document.as_bytes()),
+            Err(Error::KeyMalformed)
+        );
+    }
+}
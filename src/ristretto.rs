@@ -1,6 +1,8 @@
 // This will be the module for Ristretto over Ed448
+// Ed448 has cofactor 4, so this is a façade over the Decaf encoding
 
 pub mod constants;
+mod ops;
 pub mod points;
 
 pub use points::{CompressedRistretto, RistrettoPoint};
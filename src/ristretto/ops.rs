@@ -0,0 +1,126 @@
+use crate::{DecafPoint, Scalar};
+use core::{
+    borrow::Borrow,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use super::RistrettoPoint;
+
+/// Scalar Mul Operations
+impl Mul<&Scalar> for &RistrettoPoint {
+    type Output = RistrettoPoint;
+
+    fn mul(self, scalar: &Scalar) -> RistrettoPoint {
+        RistrettoPoint::from(DecafPoint::from(*self) * scalar)
+    }
+}
+
+define_mul_variants!(LHS = RistrettoPoint, RHS = Scalar, Output = RistrettoPoint);
+
+impl Mul<&RistrettoPoint> for &Scalar {
+    type Output = RistrettoPoint;
+
+    fn mul(self, point: &RistrettoPoint) -> RistrettoPoint {
+        point * self
+    }
+}
+
+define_mul_variants!(LHS = Scalar, RHS = RistrettoPoint, Output = RistrettoPoint);
+
+impl MulAssign<&Scalar> for RistrettoPoint {
+    fn mul_assign(&mut self, scalar: &Scalar) {
+        *self = *self * scalar;
+    }
+}
+impl MulAssign<Scalar> for RistrettoPoint {
+    fn mul_assign(&mut self, scalar: Scalar) {
+        *self = *self * scalar;
+    }
+}
+
+// Point addition
+
+impl Add<&RistrettoPoint> for &RistrettoPoint {
+    type Output = RistrettoPoint;
+
+    fn add(self, other: &RistrettoPoint) -> RistrettoPoint {
+        RistrettoPoint(self.0.to_extensible().add_extended(&other.0).to_extended())
+    }
+}
+
+define_add_variants!(
+    LHS = RistrettoPoint,
+    RHS = RistrettoPoint,
+    Output = RistrettoPoint
+);
+
+impl AddAssign<&RistrettoPoint> for RistrettoPoint {
+    fn add_assign(&mut self, other: &RistrettoPoint) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign for RistrettoPoint {
+    fn add_assign(&mut self, other: RistrettoPoint) {
+        *self = *self + other;
+    }
+}
+
+// Point Subtraction
+
+impl Sub<&RistrettoPoint> for &RistrettoPoint {
+    type Output = RistrettoPoint;
+
+    fn sub(self, other: &RistrettoPoint) -> RistrettoPoint {
+        RistrettoPoint(self.0.to_extensible().sub_extended(&other.0).to_extended())
+    }
+}
+
+define_sub_variants!(
+    LHS = RistrettoPoint,
+    RHS = RistrettoPoint,
+    Output = RistrettoPoint
+);
+
+impl SubAssign<&RistrettoPoint> for RistrettoPoint {
+    fn sub_assign(&mut self, other: &RistrettoPoint) {
+        *self = *self - other;
+    }
+}
+
+impl SubAssign for RistrettoPoint {
+    fn sub_assign(&mut self, other: RistrettoPoint) {
+        *self = *self - other;
+    }
+}
+
+// Point Negation
+
+impl Neg for &RistrettoPoint {
+    type Output = RistrettoPoint;
+
+    fn neg(self) -> RistrettoPoint {
+        RistrettoPoint(self.0.negate())
+    }
+}
+
+impl Neg for RistrettoPoint {
+    type Output = RistrettoPoint;
+
+    fn neg(self) -> RistrettoPoint {
+        (&self).neg()
+    }
+}
+
+impl<T> Sum<T> for RistrettoPoint
+where
+    T: Borrow<RistrettoPoint>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::IDENTITY, |acc, item| acc + item.borrow())
+    }
+}
@@ -1,18 +1,260 @@
 #![allow(non_snake_case)]
 
+use crate::constants::BASEPOINT_ORDER;
 use crate::curve::twedwards::extended::ExtendedPoint;
-use subtle::{Choice, ConstantTimeEq};
+use crate::decaf::points::DecafPointRepr;
+use crate::{CompressedDecaf, DecafPoint, EdwardsPoint, Scalar};
+
+use elliptic_curve::{
+    group::{cofactor::CofactorGroup, prime::PrimeGroup, GroupEncoding},
+    hash2curve::ExpandMsg,
+    ops::{LinearCombination, MulByGenerator},
+    Group,
+};
+
+use core::fmt::{Display, Formatter, LowerHex, Result as FmtResult, UpperHex};
+use rand_core::{CryptoRngCore, RngCore};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 /// The bytes representation of a compressed point.
 pub type RistrettoPointBytes = [u8; 56];
 
+/// A point in the prime-order group built on top of Ed448-Goldilocks.
+///
+/// Ristretto was designed to remove the cofactor 8 of Curve25519. Ed448 has
+/// cofactor 4, for which the simpler Decaf construction is enough, and
+/// RFC 9496 specifies decaf448 as the prime-order group for this curve.
+/// This type is therefore a façade over [`DecafPoint`] for code written
+/// against the Ristretto API:
+///
+/// - encodings are byte-for-byte identical to decaf448 and [`CompressedDecaf`],
+/// - two points are equal exactly when the corresponding `DecafPoint`s are,
+/// - hashing to the group uses the decaf448 map.
+///
+/// There is no separate "ristretto448" encoding.
 #[derive(Copy, Clone, Debug)]
 pub struct RistrettoPoint(pub(crate) ExtendedPoint);
 
+/// A compressed [`RistrettoPoint`], which uses the decaf448 encoding.
 #[derive(Copy, Clone, Debug)]
 #[repr(transparent)]
 pub struct CompressedRistretto(pub RistrettoPointBytes);
 
+impl Default for RistrettoPoint {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Display for RistrettoPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_decaf())
+    }
+}
+
+impl LowerHex for RistrettoPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:x}", self.to_decaf())
+    }
+}
+
+impl UpperHex for RistrettoPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:X}", self.to_decaf())
+    }
+}
+
+impl ConstantTimeEq for RistrettoPoint {
+    fn ct_eq(&self, other: &RistrettoPoint) -> Choice {
+        self.to_decaf().ct_eq(&other.to_decaf())
+    }
+}
+
+impl ConditionallySelectable for RistrettoPoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::from(DecafPoint::conditional_select(
+            &a.to_decaf(),
+            &b.to_decaf(),
+            choice,
+        ))
+    }
+}
+
+impl PartialEq for RistrettoPoint {
+    fn eq(&self, other: &RistrettoPoint) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for RistrettoPoint {}
+
+impl From<DecafPoint> for RistrettoPoint {
+    fn from(point: DecafPoint) -> Self {
+        Self(point.0)
+    }
+}
+
+impl From<RistrettoPoint> for DecafPoint {
+    fn from(point: RistrettoPoint) -> Self {
+        point.to_decaf()
+    }
+}
+
+impl From<EdwardsPoint> for RistrettoPoint {
+    fn from(point: EdwardsPoint) -> Self {
+        Self(point.to_twisted())
+    }
+}
+
+impl From<RistrettoPoint> for EdwardsPoint {
+    fn from(point: RistrettoPoint) -> Self {
+        point.0.to_untwisted()
+    }
+}
+
+impl From<RistrettoPoint> for RistrettoPointBytes {
+    fn from(point: RistrettoPoint) -> RistrettoPointBytes {
+        point.compress().0
+    }
+}
+
+impl TryFrom<RistrettoPointBytes> for RistrettoPoint {
+    type Error = &'static str;
+
+    fn try_from(bytes: RistrettoPointBytes) -> Result<Self, Self::Error> {
+        CompressedRistretto(bytes)
+            .decode()
+            .ok_or("Invalid point encoding")
+    }
+}
+
+impl TryFrom<&[u8]> for RistrettoPoint {
+    type Error = &'static str;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let compressed = <RistrettoPointBytes>::try_from(bytes)
+            .map_err(|_| "bytes is not the correct length")?;
+        Self::try_from(compressed)
+    }
+}
+
+impl Group for RistrettoPoint {
+    type Scalar = Scalar;
+
+    fn random(rng: impl RngCore) -> Self {
+        Self::from(<DecafPoint as Group>::random(rng))
+    }
+
+    fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    fn generator() -> Self {
+        Self::GENERATOR
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.ct_eq(&Self::IDENTITY)
+    }
+
+    fn double(&self) -> Self {
+        Self(self.0.double())
+    }
+}
+
+impl GroupEncoding for RistrettoPoint {
+    type Repr = DecafPointRepr;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        CompressedRistretto(*(bytes.as_ref())).decompress()
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        CompressedRistretto(*(bytes.as_ref())).decompress()
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.to_decaf().to_bytes()
+    }
+}
+
+impl CofactorGroup for RistrettoPoint {
+    type Subgroup = RistrettoPoint;
+
+    fn clear_cofactor(&self) -> Self::Subgroup {
+        self.double().double()
+    }
+
+    fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+        CtOption::new(self.clear_cofactor(), self.is_torsion_free())
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        (self * BASEPOINT_ORDER).ct_eq(&Self::IDENTITY)
+    }
+}
+
+impl PrimeGroup for RistrettoPoint {}
+
+impl MulByGenerator for RistrettoPoint {
+    fn mul_by_generator(scalar: &Scalar) -> Self {
+        Self(DecafPoint::mul_by_generator(scalar).0)
+    }
+}
+
+impl LinearCombination for RistrettoPoint {}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::DefaultIsZeroes for RistrettoPoint {}
+
+impl RistrettoPoint {
+    /// The generator of the Ristretto group.
+    pub const GENERATOR: RistrettoPoint = RistrettoPoint(DecafPoint::GENERATOR.0);
+    /// The identity element of the group: the point at infinity.
+    pub const IDENTITY: RistrettoPoint = RistrettoPoint(ExtendedPoint::IDENTITY);
+
+    /// Check if two points are equal
+    pub fn equals(&self, other: &RistrettoPoint) -> bool {
+        self == other
+    }
+
+    /// Encode this point, see [`Self::compress`]
+    pub fn encode(&self) -> CompressedRistretto {
+        self.compress()
+    }
+
+    /// Compress this point with the decaf448 encoding
+    pub fn compress(&self) -> CompressedRistretto {
+        CompressedRistretto(self.to_decaf().compress().0)
+    }
+
+    /// Return a `RistrettoPoint` chosen uniformly at random using a user-provided RNG.
+    pub fn random(rng: impl CryptoRngCore) -> Self {
+        Self::from(DecafPoint::random(rng))
+    }
+
+    /// Hash a message to a point using `ExpandMsg` and the decaf448 map.
+    ///
+    /// This is the same as [`DecafPoint::hash`].
+    pub fn hash<X>(msg: &[u8], dst: &[u8]) -> Self
+    where
+        X: for<'a> ExpandMsg<'a>,
+    {
+        Self::from(DecafPoint::hash::<X>(msg, dst))
+    }
+
+    /// Construct a `RistrettoPoint` from 112 bytes of data.
+    ///
+    /// This is the same as [`DecafPoint::from_uniform_bytes`].
+    pub fn from_uniform_bytes(bytes: &[u8; 112]) -> Self {
+        Self::from(DecafPoint::from_uniform_bytes(bytes))
+    }
+
+    fn to_decaf(self) -> DecafPoint {
+        DecafPoint(self.0)
+    }
+}
+
 impl Default for CompressedRistretto {
     fn default() -> Self {
         Self::IDENTITY
@@ -33,37 +275,179 @@ impl PartialEq for CompressedRistretto {
 
 impl Eq for CompressedRistretto {}
 
-impl CompressedRistretto {
-    pub const IDENTITY: Self = Self([0u8; 56]);
+impl From<CompressedDecaf> for CompressedRistretto {
+    fn from(compressed: CompressedDecaf) -> Self {
+        Self(compressed.0)
+    }
+}
 
-    pub fn as_bytes(&self) -> &[u8] {
-        &self.0
+impl From<CompressedRistretto> for CompressedDecaf {
+    fn from(compressed: CompressedRistretto) -> Self {
+        Self(compressed.0)
     }
 }
 
-impl RistrettoPoint {
-    /// The generator of the Ristretto group.
-    pub const GENERATOR: RistrettoPoint = RistrettoPoint(ExtendedPoint::GENERATOR);
-    /// The identity element of the group: the point at infinity.
-    pub const IDENTITY: RistrettoPoint = RistrettoPoint(ExtendedPoint::IDENTITY);
+impl AsRef<RistrettoPointBytes> for CompressedRistretto {
+    fn as_ref(&self) -> &RistrettoPointBytes {
+        &self.0
+    }
+}
 
-    pub fn equals(&self, other: &RistrettoPoint) -> bool {
-        let XY = self.0.X * other.0.Y;
-        let YX = self.0.Y * other.0.X;
-        XY == YX
+#[cfg(feature = "serde")]
+impl serdect::serde::Serialize for CompressedRistretto {
+    fn serialize<S: serdect::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serdect::array::serialize_hex_lower_or_bin(&self.0, s)
     }
+}
 
-    pub fn encode(&self) -> CompressedRistretto {
-        todo!()
+#[cfg(feature = "serde")]
+impl<'de> serdect::serde::Deserialize<'de> for CompressedRistretto {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        let mut bytes = [0u8; 56];
+        serdect::array::deserialize_hex_or_bin(&mut bytes, d)?;
+        Ok(Self(bytes))
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::DefaultIsZeroes for CompressedRistretto {}
+
 impl CompressedRistretto {
+    /// The compressed generator point
+    pub const GENERATOR: Self = Self(CompressedDecaf::GENERATOR.0);
+    /// The compressed identity point
+    pub const IDENTITY: Self = Self([0u8; 56]);
+
+    /// Get the bytes of this compressed point
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// The compressed identity point
     pub fn identity() -> CompressedRistretto {
-        CompressedRistretto([0; 56])
+        Self::IDENTITY
     }
 
+    /// Decode a point, see [`Self::decompress`]
     pub fn decode(&self) -> Option<RistrettoPoint> {
-        todo!()
+        self.decompress().into()
+    }
+
+    /// Decompress a point if it is valid.
+    ///
+    /// Like decaf448, this rejects encodings that are not reduced modulo
+    /// \\(p\\) or whose field element is negative.
+    pub fn decompress(&self) -> CtOption<RistrettoPoint> {
+        CompressedDecaf(self.0)
+            .decompress()
+            .map(RistrettoPoint::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TWISTED_EDWARDS_BASE_POINT;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn test_generator_and_identity() {
+        assert_eq!(
+            RistrettoPoint::GENERATOR.compress(),
+            CompressedRistretto::GENERATOR
+        );
+        assert_eq!(
+            RistrettoPoint::IDENTITY.compress(),
+            CompressedRistretto::IDENTITY
+        );
+        assert_eq!(
+            RistrettoPoint(ExtendedPoint::GENERATOR),
+            RistrettoPoint(TWISTED_EDWARDS_BASE_POINT)
+        );
+        assert_eq!(
+            CompressedRistretto::IDENTITY.decode(),
+            Some(RistrettoPoint::IDENTITY)
+        );
+    }
+
+    #[test]
+    fn test_round_trip_matches_decaf() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([8u8; 32]);
+        let mut point = RistrettoPoint::IDENTITY;
+        let mut decaf = DecafPoint::IDENTITY;
+
+        for _ in 0..16 {
+            let compressed = point.encode();
+            assert_eq!(compressed.0, decaf.compress().0);
+            assert_eq!(compressed.decode(), Some(point));
+            assert_eq!(
+                RistrettoPoint::from_bytes(&point.to_bytes()).unwrap(),
+                point
+            );
+
+            point += RistrettoPoint::GENERATOR;
+            decaf += DecafPoint::GENERATOR;
+        }
+
+        let scalar = Scalar::random(&mut rng);
+        let point = RistrettoPoint::GENERATOR * scalar;
+        assert_eq!(
+            point.compress().0,
+            (DecafPoint::GENERATOR * scalar).compress().0
+        );
+        assert_eq!(point, RistrettoPoint::mul_by_generator(&scalar));
+        assert_eq!(point - point, RistrettoPoint::IDENTITY);
+        assert_eq!(point + (-point), RistrettoPoint::IDENTITY);
+        assert_eq!(point.double(), point + point);
+        assert!(bool::from(point.is_torsion_free()));
+
+        let hashed = RistrettoPoint::hash::<elliptic_curve::hash2curve::ExpandMsgXof<sha3::Shake256>>(
+            b"test",
+            b"ristretto test",
+        );
+        let expected = DecafPoint::hash::<elliptic_curve::hash2curve::ExpandMsgXof<sha3::Shake256>>(
+            b"test",
+            b"ristretto test",
+        );
+        assert_eq!(hashed.compress().0, expected.compress().0);
+    }
+
+    #[test]
+    fn test_torsion_is_invisible() {
+        // Adding a 2-torsion point does not change the encoding
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([8u8; 32]);
+        let point = RistrettoPoint::GENERATOR * Scalar::random(&mut rng);
+        let torsion = RistrettoPoint(ExtendedPoint {
+            X: crate::field::FieldElement::ZERO,
+            Y: crate::field::FieldElement::MINUS_ONE,
+            Z: crate::field::FieldElement::ONE,
+            T: crate::field::FieldElement::ZERO,
+        });
+        assert_eq!(point + torsion, point);
+        assert_eq!((point + torsion).compress(), point.compress());
+    }
+
+    #[test]
+    fn test_reject_bad_encodings() {
+        // Negative field element
+        let mut bytes = [0u8; 56];
+        bytes[0] = 1;
+        assert_eq!(CompressedRistretto(bytes).decode(), None);
+
+        // Not reduced modulo p
+        let bytes = [0xffu8; 56];
+        assert_eq!(CompressedRistretto(bytes).decode(), None);
+        assert!(RistrettoPoint::try_from(bytes).is_err());
+
+        // p itself encodes zero, but is not canonical
+        let mut p = [0xffu8; 56];
+        p[28] = 0xfe;
+        assert_eq!(CompressedRistretto(p).decode(), None);
+
+        // Wrong length
+        assert!(RistrettoPoint::try_from(&[0u8; 55][..]).is_err());
     }
 }
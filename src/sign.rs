@@ -0,0 +1,68 @@
+//! Ed448 signatures as specified in [RFC 8032](https://www.rfc-editor.org/rfc/rfc8032).
+//!
+//! A [`SigningKey`] is created from a 57 byte secret seed, and produces
+//! 114 byte [`Signature`]s that can be checked with the matching [`VerifyingKey`].
+//! Every Ed448 signature is bound to a context string of at most 255 bytes,
+//! which is empty unless one is supplied explicitly.
+//!
+//! Ed448ph signs the 64 byte SHAKE256 prehash of the message instead, which is
+//! computed incrementally with a [`PreHasher`] so large inputs can be streamed.
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod batch;
+mod error;
+mod policy;
+mod prehash;
+mod signature;
+mod signing_key;
+mod verifying_key;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use batch::{verify_batch, BatchError};
+pub use error::SigningError;
+pub use policy::VerificationPolicy;
+pub use prehash::{PreHasher, PREHASH_LENGTH};
+pub use signature::Signature;
+pub use signing_key::SigningKey;
+pub use verifying_key::VerifyingKey;
+
+use crate::field::{Scalar, WideScalarBytes};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+/// Length in bytes of an Ed448 secret key
+pub const SECRET_KEY_LENGTH: usize = 57;
+/// Length in bytes of an Ed448 public key
+pub const PUBLIC_KEY_LENGTH: usize = 57;
+/// Length in bytes of an Ed448 signature
+pub const SIGNATURE_LENGTH: usize = 114;
+
+/// The bytes of an Ed448 secret key
+pub type SecretKeyBytes = [u8; SECRET_KEY_LENGTH];
+/// The bytes of an Ed448 public key
+pub type PublicKeyBytes = [u8; PUBLIC_KEY_LENGTH];
+/// The bytes of an Ed448 signature
+pub type SignatureBytes = [u8; SIGNATURE_LENGTH];
+
+/// The maximum length of a context string
+pub(crate) const MAX_CONTEXT_LENGTH: usize = 255;
+
+/// Start a SHAKE256 instance that has absorbed `dom4(phflag, context)`
+pub(crate) fn dom4(phflag: u8, context: &[u8]) -> Result<Shake256, SigningError> {
+    if context.len() > MAX_CONTEXT_LENGTH {
+        return Err(SigningError::ContextTooLong);
+    }
+    let mut hasher = Shake256::default();
+    hasher.update(b"SigEd448");
+    hasher.update(&[phflag, context.len() as u8]);
+    hasher.update(context);
+    Ok(hasher)
+}
+
+/// Finalize a SHAKE256 instance into 114 bytes and reduce them modulo ℓ
+pub(crate) fn hash_to_scalar(hasher: Shake256) -> Scalar {
+    let mut bytes = WideScalarBytes::default();
+    hasher.finalize_xof().read(&mut bytes);
+    Scalar::from_bytes_mod_order_wide(&bytes)
+}
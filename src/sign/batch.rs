@@ -0,0 +1,177 @@
+use super::{Signature, SigningError, VerificationPolicy, VerifyingKey};
+use crate::curve::scalar_mul::vartime_multiscalar_mul;
+use crate::field::Scalar;
+use crate::TWISTED_EDWARDS_BASE_POINT;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use elliptic_curve::Group;
+use rand_core::{CryptoRng, RngCore};
+
+/// The signatures that failed batch verification
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchError {
+    failures: Vec<(usize, SigningError)>,
+}
+
+impl Display for BatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} signature(s) in the batch are invalid",
+            self.failures.len()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
+
+impl BatchError {
+    /// The position in the batch of each invalid signature and the reason it was rejected
+    pub fn failures(&self) -> &[(usize, SigningError)] {
+        &self.failures
+    }
+
+    /// The positions in the batch of the invalid signatures
+    pub fn invalid_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.failures.iter().map(|(index, _)| *index)
+    }
+}
+
+/// Verify a batch of `(message, signature, public key)` triples signed with an empty context.
+///
+/// Each equation is scaled by a random 128-bit coefficient and the sum is checked
+/// with one cofactored multiscalar multiplication
+/// `[4](-(Σ z_i S_i)B + Σ z_i R_i + Σ (z_i k_i) A_i) = 0`.
+/// This accepts exactly the signatures that [`VerifyingKey::verify`] accepts,
+/// except with probability 2^-128.
+///
+/// If the batch does not verify, every signature is checked individually
+/// to report which ones are invalid.
+pub fn verify_batch<R: RngCore + CryptoRng>(
+    items: &[(&[u8], &Signature, &VerifyingKey)],
+    rng: &mut R,
+) -> Result<(), BatchError> {
+    let mut failures = Vec::new();
+    let mut candidates = Vec::with_capacity(items.len());
+    let mut scalars = Vec::with_capacity(2 * items.len() + 1);
+    let mut points = Vec::with_capacity(2 * items.len() + 1);
+    let mut basepoint_scalar = Scalar::ZERO;
+
+    for (index, (message, signature, verifying_key)) in items.iter().enumerate() {
+        match verifying_key.challenge(VerificationPolicy::Cofactored, 0, &[], message, signature) {
+            Ok((R, S, k)) => {
+                let mut z = [0u8; 16];
+                rng.fill_bytes(&mut z);
+                let z = Scalar::from(u128::from_le_bytes(z));
+
+                basepoint_scalar -= z * S;
+                scalars.push(z);
+                points.push(R.to_twisted());
+                scalars.push(z * k);
+                points.push(verifying_key.point.to_twisted());
+                candidates.push(index);
+            }
+            Err(error) => failures.push((index, error)),
+        }
+    }
+    scalars.push(basepoint_scalar);
+    points.push(TWISTED_EDWARDS_BASE_POINT);
+
+    // The sum is computed on the twisted curve; mapping it back with the dual
+    // isogeny multiplies it by 4, which gives the cofactored check for free.
+    let sum = vartime_multiscalar_mul(&scalars, &points).to_untwisted();
+    if !bool::from(sum.is_identity()) {
+        for index in candidates {
+            let (message, signature, verifying_key) = items[index];
+            if let Err(error) = verifying_key.verify(message, signature) {
+                failures.push((index, error));
+            }
+        }
+        failures.sort_unstable_by_key(|(index, _)| *index);
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(BatchError { failures })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sign::SigningKey;
+    use rand_core::SeedableRng;
+
+    fn signed_messages(count: usize) -> Vec<([u8; 8], Signature, VerifyingKey)> {
+        (0..count)
+            .map(|i| {
+                let signing_key = SigningKey::from_bytes(&[i as u8; 57]);
+                let message = (i as u64).to_le_bytes();
+                let signature = signing_key.sign(&message);
+                (message, signature, signing_key.verifying_key())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn batch_verify_valid() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([3u8; 32]);
+        assert_eq!(verify_batch(&[], &mut rng), Ok(()));
+
+        let signed = signed_messages(16);
+        let items: Vec<_> = signed.iter().map(|(m, s, k)| (&m[..], s, k)).collect();
+        assert_eq!(verify_batch(&items, &mut rng), Ok(()));
+    }
+
+    #[test]
+    fn batch_verify_identifies_bad_signatures() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([3u8; 32]);
+        let mut signed = signed_messages(12);
+
+        // Wrong message
+        signed[2].0[0] ^= 1;
+        // Signature from another key
+        signed[5].1 = signed[6].1;
+        // Non-canonical S
+        signed[9].1.s[56] = 1;
+
+        let items: Vec<_> = signed.iter().map(|(m, s, k)| (&m[..], s, k)).collect();
+        let error = verify_batch(&items, &mut rng).unwrap_err();
+        assert_eq!(
+            error.failures(),
+            &[
+                (2, SigningError::Verify),
+                (5, SigningError::Verify),
+                (9, SigningError::InvalidSignatureS)
+            ]
+        );
+        assert_eq!(error.invalid_indices().collect::<Vec<_>>(), vec![2, 5, 9]);
+
+        // A malformed signature alone is reported without failing the equation
+        let mut signed = signed_messages(4);
+        signed[1].1.s[56] = 1;
+        let items: Vec<_> = signed.iter().map(|(m, s, k)| (&m[..], s, k)).collect();
+        let error = verify_batch(&items, &mut rng).unwrap_err();
+        assert_eq!(error.failures(), &[(1, SigningError::InvalidSignatureS)]);
+    }
+
+    #[test]
+    fn batch_verify_matches_single_verification() {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([3u8; 32]);
+        let signed = signed_messages(6);
+        for i in 0..signed.len() {
+            for j in 0..signed.len() {
+                let (message, _, key) = &signed[i];
+                let (_, signature, _) = &signed[j];
+                let expected = key.verify(message, signature).is_ok();
+                let got = verify_batch(&[(&message[..], signature, key)], &mut rng).is_ok();
+                assert_eq!(expected, got);
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Errors that can occur when signing or verifying
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SigningError {
+    /// The context is longer than 255 bytes
+    ContextTooLong,
+    /// The input has the wrong length
+    InvalidLength,
+    /// The public key is not a valid point encoding
+    InvalidPublicKey,
+    /// The signature R component is not a valid point encoding
+    InvalidSignatureR,
+    /// The signature S component is not a canonical scalar
+    InvalidSignatureS,
+    /// The signature is not valid for this key and message
+    Verify,
+}
+
+impl Display for SigningError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::ContextTooLong => write!(f, "context must be at most 255 bytes"),
+            Self::InvalidLength => write!(f, "invalid length"),
+            Self::InvalidPublicKey => write!(f, "invalid public key"),
+            Self::InvalidSignatureR => write!(f, "invalid signature R component"),
+            Self::InvalidSignatureS => write!(f, "invalid signature S component"),
+            Self::Verify => write!(f, "signature verification failed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SigningError {}
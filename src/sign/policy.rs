@@ -0,0 +1,279 @@
+use crate::curve::edwards::{CompressedEdwardsY, EdwardsPoint};
+
+/// The rules used to decide whether an Ed448 signature is valid.
+///
+/// RFC 8032 leaves some freedom in how signatures are validated, and
+/// implementations disagree on the edge cases. Systems that must reach the
+/// same decision on every signature should all use the same policy.
+///
+/// | | `Strict` | `Cofactored` | `Permissive` |
+/// |---|---|---|---|
+/// | Non-canonical `A` or `R` encoding | reject | reject | accept |
+/// | Small order `A` or `R` | reject | accept | accept |
+/// | `S >= ℓ` | reject | reject | reject |
+/// | Equation | `[S]B = R + [k]A` | `[4][S]B = [4]R + [4][k]A` | `[4][S]B = [4]R + [4][k]A` |
+///
+/// A non-canonical encoding is one where the \\(y\\)-coordinate is not reduced
+/// modulo \\(p\\), any of the low 7 bits of the final byte are set, or the sign
+/// bit is set when \\(x = 0\\). The challenge `k` is always computed over the
+/// encodings of `R` and `A` exactly as they were given.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum VerificationPolicy {
+    /// Canonical encodings, no small order points, and the cofactorless equation
+    Strict,
+    /// The RFC 8032 section 5.2.7 checks with the cofactored equation
+    #[default]
+    Cofactored,
+    /// Accept any encoding of a curve point and use the cofactored equation,
+    /// in the style of ZIP-215
+    Permissive,
+}
+
+impl VerificationPolicy {
+    /// Decode a point that is acceptable as `A` or `R` under this policy
+    pub(crate) fn decode_point(self, compressed: &CompressedEdwardsY) -> Option<EdwardsPoint> {
+        let point = match self {
+            Self::Permissive => compressed.decompress_unchecked(),
+            Self::Cofactored | Self::Strict => compressed.decompress_canonical(),
+        };
+        Option::<EdwardsPoint>::from(point).filter(|point| self.accepts_point(compressed, point))
+    }
+
+    /// Whether an already decoded point is acceptable as `A` or `R` under this policy
+    pub(crate) fn accepts_point(
+        self,
+        compressed: &CompressedEdwardsY,
+        point: &EdwardsPoint,
+    ) -> bool {
+        match self {
+            Self::Permissive => true,
+            Self::Cofactored => compressed.is_canonical().into(),
+            Self::Strict => (compressed.is_canonical() & !point.is_small_order()).into(),
+        }
+    }
+
+    /// Whether the group equation is multiplied by the cofactor
+    pub(crate) fn is_cofactored(self) -> bool {
+        !matches!(self, Self::Strict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::edwards::AffinePoint;
+    use crate::field::{FieldElement, Scalar};
+    use crate::sign::{dom4, hash_to_scalar, Signature, SigningError, VerifyingKey};
+    use crate::GOLDILOCKS_BASE_POINT;
+    use elliptic_curve::bigint::{Encoding, U448};
+    use rand_core::SeedableRng;
+    use sha3::digest::Update;
+
+    const POLICIES: [VerificationPolicy; 3] = [
+        VerificationPolicy::Strict,
+        VerificationPolicy::Cofactored,
+        VerificationPolicy::Permissive,
+    ];
+
+    const MESSAGE: &[u8] = b"edge case";
+
+    /// (0, 1) encoded with y = p + 1
+    const IDENTITY_NON_CANONICAL_Y: [u8; 57] = {
+        let mut bytes = [0u8; 57];
+        let mut i = 28;
+        while i < 56 {
+            bytes[i] = 0xff;
+            i += 1;
+        }
+        bytes
+    };
+
+    /// (0, 1) encoded with the sign bit set
+    const IDENTITY_NEGATIVE_ZERO: [u8; 57] = {
+        let mut bytes = [0u8; 57];
+        bytes[0] = 1;
+        bytes[56] = 0x80;
+        bytes
+    };
+
+    fn identity() -> [u8; 57] {
+        EdwardsPoint::IDENTITY.compress().0
+    }
+
+    /// (0, -1), of order 2
+    fn order_two() -> EdwardsPoint {
+        AffinePoint {
+            x: FieldElement::ZERO,
+            y: FieldElement::MINUS_ONE,
+        }
+        .to_edwards()
+    }
+
+    fn challenge(r: &[u8; 57], a: &[u8; 57]) -> Scalar {
+        let mut hasher = dom4(0, &[]).unwrap();
+        hasher.update(r);
+        hasher.update(a);
+        hasher.update(MESSAGE);
+        hash_to_scalar(hasher)
+    }
+
+    fn signature(r: [u8; 57], s: Scalar) -> Signature {
+        Signature::from_components(r, s.to_bytes_rfc_8032().into())
+    }
+
+    /// Check the outcome of verifying under every policy, in the order of `POLICIES`
+    fn check(a: &[u8; 57], signature: &Signature, expected: [Result<(), SigningError>; 3]) {
+        for (policy, expected) in POLICIES.iter().zip(expected) {
+            let result = VerifyingKey::from_bytes_with_policy(a, *policy)
+                .and_then(|key| key.verify_with_policy(*policy, &[], MESSAGE, signature));
+            assert_eq!(result, expected, "{:?}", policy);
+
+            // A key decoded permissively is held to the policy at verification time
+            let key =
+                VerifyingKey::from_bytes_with_policy(a, VerificationPolicy::Permissive).unwrap();
+            assert_eq!(
+                key.verify_with_policy(*policy, &[], MESSAGE, signature),
+                expected,
+                "{:?}",
+                policy
+            );
+        }
+    }
+
+    fn keypair() -> (Scalar, [u8; 57]) {
+        let mut rng = rand_chacha::ChaCha8Rng::from_seed([4u8; 32]);
+        let a = Scalar::random(&mut rng);
+        (a, (GOLDILOCKS_BASE_POINT * a).compress().0)
+    }
+
+    #[test]
+    fn valid_signature() {
+        let (a, a_bytes) = keypair();
+        let r = Scalar::from(12345u32);
+        let r_bytes = (GOLDILOCKS_BASE_POINT * r).compress().0;
+        let s = r + challenge(&r_bytes, &a_bytes) * a;
+        check(&a_bytes, &signature(r_bytes, s), [Ok(()), Ok(()), Ok(())]);
+    }
+
+    #[test]
+    fn non_canonical_s() {
+        let (a, a_bytes) = keypair();
+        let r = Scalar::from(12345u32);
+        let r_bytes = (GOLDILOCKS_BASE_POINT * r).compress().0;
+        let s = r + challenge(&r_bytes, &a_bytes) * a;
+
+        let mut sig = signature(r_bytes, s);
+        let s_plus_order = U448::from_le_slice(&sig.s[..56]).wrapping_add(&crate::ORDER);
+        sig.s[..56].copy_from_slice(&s_plus_order.to_le_bytes());
+        let err = Err(SigningError::InvalidSignatureS);
+        check(&a_bytes, &sig, [err, err, err]);
+
+        let mut sig = signature(r_bytes, s);
+        sig.s[56] = 1;
+        check(&a_bytes, &sig, [err, err, err]);
+    }
+
+    #[test]
+    fn small_order_public_key() {
+        // With A and R both the identity, S = 0 satisfies even the cofactorless equation
+        let sig = signature(identity(), Scalar::ZERO);
+        let err = Err(SigningError::InvalidPublicKey);
+        check(&identity(), &sig, [err, Ok(()), Ok(())]);
+
+        // A of order 2 only fails the cofactorless equation when k is odd
+        let a_bytes = order_two().compress().0;
+        let sig = signature(identity(), Scalar::ZERO);
+        check(&a_bytes, &sig, [err, Ok(()), Ok(())]);
+    }
+
+    #[test]
+    fn small_order_r() {
+        // R of order 2 and S = [k]a, so [S]B - R - [k]A = -R is a torsion point
+        let (a, a_bytes) = keypair();
+        let r_bytes = order_two().compress().0;
+        let s = challenge(&r_bytes, &a_bytes) * a;
+        check(
+            &a_bytes,
+            &signature(r_bytes, s),
+            [Err(SigningError::InvalidSignatureR), Ok(()), Ok(())],
+        );
+    }
+
+    #[test]
+    fn mixed_order_r() {
+        // R = [r]B + T with T of order 2 only passes the cofactored equation
+        let (a, a_bytes) = keypair();
+        let r = Scalar::from(12345u32);
+        let r_bytes = (GOLDILOCKS_BASE_POINT * r + order_two()).compress().0;
+        let s = r + challenge(&r_bytes, &a_bytes) * a;
+        check(
+            &a_bytes,
+            &signature(r_bytes, s),
+            [Err(SigningError::Verify), Ok(()), Ok(())],
+        );
+    }
+
+    #[test]
+    fn non_canonical_r() {
+        let (a, a_bytes) = keypair();
+        let err = Err(SigningError::InvalidSignatureR);
+
+        // Unused bits of the final byte set
+        let r = Scalar::from(12345u32);
+        let mut r_bytes = (GOLDILOCKS_BASE_POINT * r).compress().0;
+        r_bytes[56] |= 0x01;
+        let s = r + challenge(&r_bytes, &a_bytes) * a;
+        check(&a_bytes, &signature(r_bytes, s), [err, err, Ok(())]);
+
+        // Unreduced y-coordinate
+        let r_bytes = IDENTITY_NON_CANONICAL_Y;
+        let s = challenge(&r_bytes, &a_bytes) * a;
+        check(&a_bytes, &signature(r_bytes, s), [err, err, Ok(())]);
+
+        // Sign bit set with x = 0
+        let r_bytes = IDENTITY_NEGATIVE_ZERO;
+        let s = challenge(&r_bytes, &a_bytes) * a;
+        check(&a_bytes, &signature(r_bytes, s), [err, err, Ok(())]);
+    }
+
+    #[test]
+    fn non_canonical_public_key() {
+        let err = Err(SigningError::InvalidPublicKey);
+        for a_bytes in [IDENTITY_NON_CANONICAL_Y, IDENTITY_NEGATIVE_ZERO] {
+            let sig = signature(identity(), Scalar::ZERO);
+            check(&a_bytes, &sig, [err, err, Ok(())]);
+        }
+
+        let (a, mut a_bytes) = keypair();
+        a_bytes[56] |= 0x40;
+        let r = Scalar::from(12345u32);
+        let r_bytes = (GOLDILOCKS_BASE_POINT * r).compress().0;
+        let s = r + challenge(&r_bytes, &a_bytes) * a;
+        check(&a_bytes, &signature(r_bytes, s), [err, err, Ok(())]);
+    }
+
+    #[test]
+    fn decode_points() {
+        let torsion = CompressedEdwardsY(order_two().compress().0);
+        assert!(bool::from(torsion.decompress_unchecked().is_some()));
+        assert!(bool::from(torsion.decompress_canonical().is_some()));
+        assert!(bool::from(torsion.decompress().is_none()));
+        assert!(VerificationPolicy::Strict.decode_point(&torsion).is_none());
+        assert!(VerificationPolicy::Cofactored
+            .decode_point(&torsion)
+            .is_some());
+
+        let non_canonical = CompressedEdwardsY(IDENTITY_NON_CANONICAL_Y);
+        let point = non_canonical.decompress_unchecked().unwrap();
+        assert_eq!(point, EdwardsPoint::IDENTITY);
+        assert!(bool::from(non_canonical.decompress_canonical().is_none()));
+        assert!(bool::from(non_canonical.decompress().is_some()));
+        assert!(VerificationPolicy::Cofactored
+            .decode_point(&non_canonical)
+            .is_none());
+        assert_eq!(
+            VerificationPolicy::Permissive.decode_point(&non_canonical),
+            Some(EdwardsPoint::IDENTITY)
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+/// The length in bytes of the Ed448ph message prehash
+pub const PREHASH_LENGTH: usize = 64;
+
+/// An incremental SHAKE256 state for computing the Ed448ph prehash `PH(M)`.
+///
+/// Feed the message in as many pieces as needed, then pass this to
+/// [`SigningKey::sign_prehashed`](crate::SigningKey::sign_prehashed) or
+/// [`VerifyingKey::verify_prehashed`](crate::VerifyingKey::verify_prehashed).
+#[derive(Clone, Debug, Default)]
+pub struct PreHasher(Shake256);
+
+impl From<Shake256> for PreHasher {
+    fn from(hasher: Shake256) -> Self {
+        Self(hasher)
+    }
+}
+
+impl Update for PreHasher {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for PreHasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl PreHasher {
+    /// Create a new empty prehash state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compute the 64 byte prehash of everything absorbed so far
+    pub fn finalize(self) -> [u8; PREHASH_LENGTH] {
+        let mut output = [0u8; PREHASH_LENGTH];
+        self.0.finalize_xof().read(&mut output);
+        output
+    }
+}
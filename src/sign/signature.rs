@@ -0,0 +1,167 @@
+use super::{SignatureBytes, SigningError, SIGNATURE_LENGTH};
+use crate::curve::edwards::extended::PointBytes;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::fmt::{Display, Formatter, LowerHex, Result as FmtResult, UpperHex};
+
+/// An Ed448 signature, the encoded point R followed by the scalar S
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Signature {
+    pub(crate) r: PointBytes,
+    pub(crate) s: [u8; 57],
+}
+
+impl Default for Signature {
+    fn default() -> Self {
+        Self {
+            r: [0u8; 57],
+            s: [0u8; 57],
+        }
+    }
+}
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:x}", self)
+    }
+}
+
+impl LowerHex for Signature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for b in self.r.iter().chain(self.s.iter()) {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl UpperHex for Signature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for b in self.r.iter().chain(self.s.iter()) {
+            write!(f, "{:02X}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&SignatureBytes> for Signature {
+    fn from(bytes: &SignatureBytes) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<SignatureBytes> for Signature {
+    fn from(bytes: SignatureBytes) -> Self {
+        Self::from_bytes(&bytes)
+    }
+}
+
+impl From<Signature> for SignatureBytes {
+    fn from(signature: Signature) -> Self {
+        signature.to_bytes()
+    }
+}
+
+impl From<&Signature> for SignatureBytes {
+    fn from(signature: &Signature) -> Self {
+        signature.to_bytes()
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = SigningError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = <&SignatureBytes>::try_from(bytes).map_err(|_| SigningError::InvalidLength)?;
+        Ok(Self::from_bytes(bytes))
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl From<Signature> for Vec<u8> {
+    fn from(signature: Signature) -> Self {
+        signature.to_bytes().to_vec()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl From<&Signature> for Vec<u8> {
+    fn from(signature: &Signature) -> Self {
+        signature.to_bytes().to_vec()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl TryFrom<Vec<u8>> for Signature {
+    type Error = SigningError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl TryFrom<&Vec<u8>> for Signature {
+    type Error = SigningError;
+
+    fn try_from(bytes: &Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serdect::serde::Serialize for Signature {
+    fn serialize<S: serdect::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serdect::array::serialize_hex_lower_or_bin(&self.to_bytes(), s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serdect::serde::Deserialize<'de> for Signature {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        let mut bytes = [0u8; SIGNATURE_LENGTH];
+        serdect::array::deserialize_hex_or_bin(&mut bytes, d)?;
+        Ok(Self::from_bytes(&bytes))
+    }
+}
+
+impl Signature {
+    /// Create a signature from its byte representation.
+    ///
+    /// No checks are done here, the components are validated during verification.
+    pub fn from_bytes(bytes: &SignatureBytes) -> Self {
+        let mut r = [0u8; 57];
+        let mut s = [0u8; 57];
+        r.copy_from_slice(&bytes[..57]);
+        s.copy_from_slice(&bytes[57..]);
+        Self { r, s }
+    }
+
+    /// Create a signature from the encoded R and S components
+    pub fn from_components(r: PointBytes, s: [u8; 57]) -> Self {
+        Self { r, s }
+    }
+
+    /// The signature as bytes
+    pub fn to_bytes(&self) -> SignatureBytes {
+        let mut bytes = [0u8; SIGNATURE_LENGTH];
+        bytes[..57].copy_from_slice(&self.r);
+        bytes[57..].copy_from_slice(&self.s);
+        bytes
+    }
+
+    /// The encoded R component
+    pub fn r_bytes(&self) -> &PointBytes {
+        &self.r
+    }
+
+    /// The encoded S component
+    pub fn s_bytes(&self) -> &[u8; 57] {
+        &self.s
+    }
+}
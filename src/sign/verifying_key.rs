@@ -0,0 +1,275 @@
+use super::{
+    dom4, hash_to_scalar, PreHasher, PublicKeyBytes, Signature, SigningError, VerificationPolicy,
+};
+use crate::curve::edwards::{CompressedEdwardsY, EdwardsPoint};
+use crate::field::{Scalar, ScalarBytes};
+
+use core::fmt::{Display, Formatter, LowerHex, Result as FmtResult, UpperHex};
+use core::hash::{Hash, Hasher};
+use elliptic_curve::Group;
+use sha3::digest::Update;
+
+/// An Ed448 public key
+#[derive(Copy, Clone, Debug)]
+pub struct VerifyingKey {
+    pub(crate) compressed: CompressedEdwardsY,
+    pub(crate) point: EdwardsPoint,
+}
+
+impl PartialEq for VerifyingKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.compressed == other.compressed
+    }
+}
+
+impl Eq for VerifyingKey {}
+
+impl Hash for VerifyingKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.compressed.as_bytes().hash(state)
+    }
+}
+
+impl Display for VerifyingKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.compressed)
+    }
+}
+
+impl LowerHex for VerifyingKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:x}", self.compressed)
+    }
+}
+
+impl UpperHex for VerifyingKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:X}", self.compressed)
+    }
+}
+
+impl AsRef<[u8]> for VerifyingKey {
+    fn as_ref(&self) -> &[u8] {
+        self.compressed.as_bytes()
+    }
+}
+
+impl From<VerifyingKey> for PublicKeyBytes {
+    fn from(key: VerifyingKey) -> Self {
+        key.to_bytes()
+    }
+}
+
+impl From<&VerifyingKey> for PublicKeyBytes {
+    fn from(key: &VerifyingKey) -> Self {
+        key.to_bytes()
+    }
+}
+
+impl From<VerifyingKey> for EdwardsPoint {
+    fn from(key: VerifyingKey) -> Self {
+        key.point
+    }
+}
+
+impl From<&VerifyingKey> for EdwardsPoint {
+    fn from(key: &VerifyingKey) -> Self {
+        key.point
+    }
+}
+
+impl TryFrom<&PublicKeyBytes> for VerifyingKey {
+    type Error = SigningError;
+
+    fn try_from(bytes: &PublicKeyBytes) -> Result<Self, Self::Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<PublicKeyBytes> for VerifyingKey {
+    type Error = SigningError;
+
+    fn try_from(bytes: PublicKeyBytes) -> Result<Self, Self::Error> {
+        Self::from_bytes(&bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for VerifyingKey {
+    type Error = SigningError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = <&PublicKeyBytes>::try_from(bytes).map_err(|_| SigningError::InvalidLength)?;
+        Self::from_bytes(bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serdect::serde::Serialize for VerifyingKey {
+    fn serialize<S: serdect::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        self.compressed.serialize(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serdect::serde::Deserialize<'de> for VerifyingKey {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        let mut bytes = [0u8; super::PUBLIC_KEY_LENGTH];
+        serdect::array::deserialize_hex_or_bin(&mut bytes, d)?;
+        Self::from_bytes(&bytes).map_err(serdect::serde::de::Error::custom)
+    }
+}
+
+impl VerifyingKey {
+    /// Decode a public key from its RFC 8032 encoding.
+    ///
+    /// Fails if the bytes are not the canonical encoding of a curve point.
+    pub fn from_bytes(bytes: &PublicKeyBytes) -> Result<Self, SigningError> {
+        Self::from_bytes_with_policy(bytes, VerificationPolicy::default())
+    }
+
+    /// Decode a public key that is acceptable under `policy`.
+    ///
+    /// The original bytes are kept, so a key accepted here is hashed into the
+    /// challenge exactly as it was encoded.
+    pub fn from_bytes_with_policy(
+        bytes: &PublicKeyBytes,
+        policy: VerificationPolicy,
+    ) -> Result<Self, SigningError> {
+        let compressed = CompressedEdwardsY(*bytes);
+        let point = policy
+            .decode_point(&compressed)
+            .ok_or(SigningError::InvalidPublicKey)?;
+        Ok(Self { compressed, point })
+    }
+
+    /// The encoded public key
+    pub fn to_bytes(&self) -> PublicKeyBytes {
+        self.compressed.to_bytes()
+    }
+
+    /// The encoded public key
+    pub fn as_bytes(&self) -> &PublicKeyBytes {
+        self.compressed.as_bytes()
+    }
+
+    /// The public key as a compressed point
+    pub fn to_compressed(&self) -> CompressedEdwardsY {
+        self.compressed
+    }
+
+    /// The public key as a curve point
+    pub fn to_edwards(&self) -> EdwardsPoint {
+        self.point
+    }
+
+    /// Verify a signature over `message` with an empty context
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> Result<(), SigningError> {
+        self.verify_raw(VerificationPolicy::default(), 0, &[], message, signature)
+    }
+
+    /// Verify a signature over `message` made with `context`
+    pub fn verify_ctx(
+        &self,
+        context: &[u8],
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), SigningError> {
+        self.verify_raw(
+            VerificationPolicy::default(),
+            0,
+            context,
+            message,
+            signature,
+        )
+    }
+
+    /// Verify a signature over `message` made with `context`, using the rules of `policy`.
+    ///
+    /// The public key is checked against `policy` as well, even if it was
+    /// decoded under a different one.
+    pub fn verify_with_policy(
+        &self,
+        policy: VerificationPolicy,
+        context: &[u8],
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), SigningError> {
+        self.verify_raw(policy, 0, context, message, signature)
+    }
+
+    /// Verify an Ed448ph signature, given the SHAKE256 state that absorbed the message.
+    ///
+    /// Accepts either a [`PreHasher`] or a [`sha3::Shake256`] directly.
+    pub fn verify_prehashed<P: Into<PreHasher>>(
+        &self,
+        context: &[u8],
+        prehashed_message: P,
+        signature: &Signature,
+    ) -> Result<(), SigningError> {
+        let prehash = prehashed_message.into().finalize();
+        self.verify_raw(
+            VerificationPolicy::default(),
+            1,
+            context,
+            &prehash,
+            signature,
+        )
+    }
+
+    /// Check the group equation `[S]B = R + [k]A`, multiplied by the cofactor if `policy` requires
+    pub(crate) fn verify_raw(
+        &self,
+        policy: VerificationPolicy,
+        phflag: u8,
+        context: &[u8],
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), SigningError> {
+        let (R, S, k) = self.challenge(policy, phflag, context, message, signature)?;
+
+        // [S]B - [k]A - R, computed in variable time since every input is public
+        let mut difference =
+            EdwardsPoint::vartime_double_scalar_mul_basepoint(&-k, &self.point, &S) - R;
+        if policy.is_cofactored() {
+            difference = difference.double().double();
+        }
+        if bool::from(difference.is_identity()) {
+            Ok(())
+        } else {
+            Err(SigningError::Verify)
+        }
+    }
+
+    /// Decode `R` and `S` from the signature and compute the challenge `k`,
+    /// checking that the public key and `R` are acceptable under `policy`
+    pub(crate) fn challenge(
+        &self,
+        policy: VerificationPolicy,
+        phflag: u8,
+        context: &[u8],
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(EdwardsPoint, Scalar, Scalar), SigningError> {
+        let mut hasher = dom4(phflag, context)?;
+        if !policy.accepts_point(&self.compressed, &self.point) {
+            return Err(SigningError::InvalidPublicKey);
+        }
+        let R = policy
+            .decode_point(&CompressedEdwardsY(signature.r))
+            .ok_or(SigningError::InvalidSignatureR)?;
+        let mut s_bytes = ScalarBytes::default();
+        s_bytes.copy_from_slice(&signature.s);
+        let S = Option::<Scalar>::from(Scalar::from_canonical_bytes(&s_bytes))
+            .ok_or(SigningError::InvalidSignatureS)?;
+
+        hasher.update(&signature.r);
+        hasher.update(self.compressed.as_bytes());
+        hasher.update(message);
+        let k = hash_to_scalar(hasher);
+
+        Ok((R, S, k))
+    }
+}